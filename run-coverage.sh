@@ -8,7 +8,7 @@ else
     echo "cargo-llvm-cov already installed"
 fi
 
-COMMON_ARGS="--ignore-filename-regex=sdl_display\.rs|main\.rs --all-features --workspace --show-missing-lines"
+COMMON_ARGS="--ignore-filename-regex=sdl_display\.rs|main\.rs|samtris_sim\.rs --all-features --workspace --show-missing-lines"
 
 cargo llvm-cov $COMMON_ARGS \
   --codecov \
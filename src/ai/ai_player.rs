@@ -0,0 +1,242 @@
+use crate::ai::{evaluate_grid, HeuristicWeights};
+use crate::common::Position;
+use crate::game_logic::{find_placements_from, PlayfieldGrid};
+use crate::graphics::PlayfieldView;
+use crate::gui::GameInput;
+use crate::tetromino::TetrominoInstance;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Computer player that only sees what is drawn on the screen (the playfield view) and plays by
+/// emitting game inputs, just like a human player.
+pub struct AiPlayer {
+    weights: HeuristicWeights,
+    input_interval: Duration,
+    time_until_next_input: Duration,
+    planned_inputs: VecDeque<GameInput>,
+    last_position: Option<Position>,
+}
+
+impl AiPlayer {
+    /// Creates an AI that scores placements with the given weights and sends at most one input
+    /// per input_interval.
+    pub fn new(weights: HeuristicWeights, input_interval: Duration) -> Self {
+        Self {
+            weights,
+            input_interval,
+            time_until_next_input: input_interval,
+            planned_inputs: VecDeque::new(),
+            last_position: None,
+        }
+    }
+
+    /// Returns the next input to send to the game, if it is time for one.
+    pub fn update(
+        &mut self,
+        delta_time: Duration,
+        playfield_view: &PlayfieldView,
+    ) -> Option<GameInput> {
+        let tetromino = playfield_view.current_tetrominoes.first()?;
+        self.plan_for_new_tetromino(playfield_view.grid, tetromino);
+
+        if delta_time < self.time_until_next_input {
+            self.time_until_next_input -= delta_time;
+            return None;
+        }
+        self.time_until_next_input = self.input_interval;
+
+        self.planned_inputs.pop_front()
+    }
+
+    /// Plans the inputs for the best placement when a new tetromino has appeared. A new tetromino
+    /// is recognised by an empty plan or by a position higher up than the previous tetromino,
+    /// which happens when gravity locked the previous one before the plan was finished.
+    fn plan_for_new_tetromino(&mut self, grid: &PlayfieldGrid, tetromino: &TetrominoInstance) {
+        let position = tetromino.get_position();
+        let is_new_tetromino = self
+            .last_position
+            .is_some_and(|last_position| position.y < last_position.y);
+        self.last_position = Some(position);
+
+        if self.planned_inputs.is_empty() || is_new_tetromino {
+            self.planned_inputs = find_best_inputs(grid, tetromino, &self.weights).into();
+        }
+    }
+}
+
+/// Returns the inputs that bring the tetromino to the reachable placement with the highest
+/// score.
+pub fn find_best_inputs(
+    grid: &PlayfieldGrid,
+    tetromino: &TetrominoInstance,
+    weights: &HeuristicWeights,
+) -> Vec<GameInput> {
+    let mut best_inputs = vec![GameInput::Drop];
+    let mut best_score = f64::MIN;
+
+    for placement in find_placements_from(grid, tetromino) {
+        let score = evaluate_placement(grid, &placement.tetromino, weights);
+        if score > best_score {
+            best_score = score;
+            best_inputs = placement.inputs;
+        }
+    }
+
+    best_inputs
+}
+
+/// Locks the placed tetromino in a copy of the grid and scores the result.
+fn evaluate_placement(
+    grid: &PlayfieldGrid,
+    tetromino: &TetrominoInstance,
+    weights: &HeuristicWeights,
+) -> f64 {
+    let mut locked_grid = grid.clone();
+    for position in tetromino.get_world_blocks() {
+        locked_grid.set(position, Some(tetromino.get_type()));
+    }
+    let full_lines = locked_grid.get_full_lines();
+    locked_grid.remove_lines(&full_lines);
+
+    evaluate_grid(&locked_grid, full_lines.len() as u32, weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::create_grid_from_rows;
+    use crate::tetromino::{TetrominoDefinitions, TetrominoType};
+
+    const INPUT_INTERVAL: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn find_best_inputs_fills_gap_that_completes_line() {
+        // Arrange
+        let grid = create_grid_from_rows(&["....", "....", "....", "#..#"]);
+        let tetromino = create_tetromino(TetrominoType::O, Position::new(-1, 0));
+
+        // Act
+        let result = find_best_inputs(&grid, &tetromino, &HeuristicWeights::default());
+
+        // Assert
+        assert_eq!(result, vec![GameInput::MoveRight, GameInput::Drop]);
+    }
+
+    #[test]
+    fn find_best_inputs_with_other_weights_chooses_other_placement() {
+        // Arrange
+        let grid = create_grid_from_rows(&["....", "....", "....", "#..#"]);
+        let tetromino = create_tetromino(TetrominoType::O, Position::new(-1, 0));
+        let weights_avoiding_line_clears = HeuristicWeights::new(0.0, 0.0, 0.0, -1.0);
+
+        // Act
+        let result = find_best_inputs(&grid, &tetromino, &weights_avoiding_line_clears);
+
+        // Assert
+        assert_ne!(result, vec![GameInput::MoveRight, GameInput::Drop]);
+        let placement = find_placements_from(&grid, &tetromino)
+            .into_iter()
+            .find(|placement| placement.inputs == result)
+            .unwrap();
+        assert!(placement
+            .tetromino
+            .get_world_blocks()
+            .iter()
+            .all(|position| position.y < 3));
+    }
+
+    #[test]
+    fn find_best_inputs_rotates_i_to_fill_well() {
+        // Arrange
+        let grid = create_grid_from_rows(&[
+            "....", "....", "....", "....", "###.", "###.", "###.", "###.",
+        ]);
+        let tetromino = create_tetromino(TetrominoType::I, Position::new(0, 0));
+
+        // Act
+        let result = find_best_inputs(&grid, &tetromino, &HeuristicWeights::default());
+
+        // Assert
+        let placement = find_placements_from(&grid, &tetromino)
+            .into_iter()
+            .find(|placement| placement.inputs == result)
+            .unwrap();
+        assert!(placement
+            .tetromino
+            .get_world_blocks()
+            .iter()
+            .all(|position| position.x == 3));
+    }
+
+    #[test]
+    fn update_without_tetromino_returns_no_input() {
+        // Arrange
+        let mut sut = AiPlayer::new(HeuristicWeights::default(), INPUT_INTERVAL);
+        let grid = create_grid_from_rows(&["....", "...."]);
+        let playfield_view = create_playfield_view(&grid, vec![]);
+
+        // Act
+        let result = sut.update(INPUT_INTERVAL, &playfield_view);
+
+        // Assert
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn update_waits_for_input_interval() {
+        // Arrange
+        let mut sut = AiPlayer::new(HeuristicWeights::default(), INPUT_INTERVAL);
+        let grid = create_grid_from_rows(&["....", "....", "....", "#..#"]);
+        let tetromino = create_tetromino(TetrominoType::O, Position::new(-1, 0));
+        let playfield_view = create_playfield_view(&grid, vec![&tetromino]);
+
+        // Act
+        let early_result = sut.update(INPUT_INTERVAL / 2, &playfield_view);
+        let result = sut.update(INPUT_INTERVAL / 2, &playfield_view);
+
+        // Assert
+        assert_eq!(early_result, None);
+        assert_eq!(result, Some(GameInput::MoveRight));
+    }
+
+    #[test]
+    fn update_replans_when_tetromino_appears_higher_up() {
+        // Arrange
+        let mut sut = AiPlayer::new(HeuristicWeights::default(), INPUT_INTERVAL);
+        let grid = create_grid_from_rows(&["....", "....", "....", "#..#"]);
+        let fallen_tetromino = create_tetromino(TetrominoType::O, Position::new(-1, 0));
+        let new_tetromino = create_tetromino(TetrominoType::O, Position::new(-1, -1));
+        sut.update(
+            Duration::ZERO,
+            &create_playfield_view(&grid, vec![&fallen_tetromino]),
+        );
+        sut.planned_inputs = VecDeque::from([GameInput::MoveLeft]);
+
+        // Act
+        let result = sut.update(
+            INPUT_INTERVAL,
+            &create_playfield_view(&grid, vec![&new_tetromino]),
+        );
+
+        // Assert
+        assert_eq!(result, Some(GameInput::MoveRight));
+    }
+
+    fn create_playfield_view<'a>(
+        grid: &'a PlayfieldGrid,
+        current_tetrominoes: Vec<&'a TetrominoInstance>,
+    ) -> PlayfieldView<'a> {
+        PlayfieldView {
+            dimensions: grid.get_dimensions(),
+            grid,
+            current_tetrominoes,
+            next_tetromino_type: TetrominoType::O,
+            full_lines: vec![],
+            show_blinking_lines: false,
+        }
+    }
+
+    fn create_tetromino(tetromino_type: TetrominoType, position: Position) -> TetrominoInstance {
+        TetrominoInstance::new(tetromino_type, position, &TetrominoDefinitions::new())
+    }
+}
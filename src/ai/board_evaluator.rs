@@ -0,0 +1,134 @@
+use crate::ai::HeuristicWeights;
+use crate::game_logic::PlayfieldGrid;
+
+/// Returns the height of every column, measured from the bottom of the grid to the highest
+/// occupied cell of that column.
+pub fn get_column_heights(grid: &PlayfieldGrid) -> Vec<u32> {
+    let dimensions = grid.get_dimensions();
+    (0..dimensions.width as i32)
+        .map(|x| {
+            (0..dimensions.height as i32)
+                .find(|&y| grid.is_xy_occupied(x, y))
+                .map_or(0, |y| dimensions.height - y as u32)
+        })
+        .collect()
+}
+
+pub fn get_aggregate_height(column_heights: &[u32]) -> u32 {
+    column_heights.iter().sum()
+}
+
+/// Returns the sum of the height differences between neighbouring columns.
+pub fn get_bumpiness(column_heights: &[u32]) -> u32 {
+    column_heights
+        .windows(2)
+        .map(|pair| pair[0].abs_diff(pair[1]))
+        .sum()
+}
+
+/// Returns the number of empty cells that have an occupied cell somewhere above them.
+pub fn count_holes(grid: &PlayfieldGrid) -> u32 {
+    let dimensions = grid.get_dimensions();
+    let mut nr_holes = 0;
+    for x in 0..dimensions.width as i32 {
+        let mut is_covered = false;
+        for y in 0..dimensions.height as i32 {
+            if grid.is_xy_occupied(x, y) {
+                is_covered = true;
+            } else if is_covered {
+                nr_holes += 1;
+            }
+        }
+    }
+    nr_holes
+}
+
+/// Scores a grid after a placement that cleared lines_cleared lines, higher is better.
+pub fn evaluate_grid(grid: &PlayfieldGrid, lines_cleared: u32, weights: &HeuristicWeights) -> f64 {
+    let column_heights = get_column_heights(grid);
+
+    weights.aggregate_height * get_aggregate_height(&column_heights) as f64
+        + weights.holes * count_holes(grid) as f64
+        + weights.bumpiness * get_bumpiness(&column_heights) as f64
+        + weights.lines_cleared * lines_cleared as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::create_grid_from_rows;
+    use rstest::rstest;
+
+    #[test]
+    fn get_column_heights_measures_from_bottom_to_highest_block() {
+        // Arrange
+        let grid = create_grid_from_rows(&["....", ".#..", ".#.#", "##.#"]);
+
+        // Act
+        let result = get_column_heights(&grid);
+
+        // Assert
+        assert_eq!(result, vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn get_aggregate_height_sums_column_heights() {
+        // Act
+        let result = get_aggregate_height(&[1, 3, 0, 2]);
+
+        // Assert
+        assert_eq!(result, 6);
+    }
+
+    #[rstest]
+    #[case(&[1, 3, 0, 2], 7)]
+    #[case(&[2, 2, 2, 2], 0)]
+    #[case(&[], 0)]
+    fn get_bumpiness_sums_height_differences(#[case] heights: &[u32], #[case] expected: u32) {
+        // Act
+        let result = get_bumpiness(heights);
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn count_holes_counts_covered_empty_cells() {
+        // Arrange
+        let grid = create_grid_from_rows(&["....", ".#..", "...#", "##.."]);
+
+        // Act
+        let result = count_holes(&grid);
+
+        // Assert
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn evaluate_grid_prefers_flat_board_without_holes() {
+        // Arrange
+        let weights = HeuristicWeights::default();
+        let flat_grid = create_grid_from_rows(&["....", "....", "....", "####"]);
+        let holey_grid = create_grid_from_rows(&["....", "....", "####", "#.#."]);
+
+        // Act
+        let flat_score = evaluate_grid(&flat_grid, 0, &weights);
+        let holey_score = evaluate_grid(&holey_grid, 0, &weights);
+
+        // Assert
+        assert!(flat_score > holey_score);
+    }
+
+    #[test]
+    fn evaluate_grid_rewards_cleared_lines() {
+        // Arrange
+        let weights = HeuristicWeights::new(0.0, 0.0, 0.0, 1.0);
+        let grid = create_grid_from_rows(&["....", "...."]);
+
+        // Act
+        let result = evaluate_grid(&grid, 2, &weights);
+
+        // Assert
+        assert_eq!(result, 2.0);
+    }
+}
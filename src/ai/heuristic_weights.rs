@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+/// Weights of the board features the AI uses to score a placement. Features that make the board
+/// worse should get a negative weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeuristicWeights {
+    pub aggregate_height: f64,
+    pub holes: f64,
+    pub bumpiness: f64,
+    pub lines_cleared: f64,
+}
+
+impl HeuristicWeights {
+    pub fn new(aggregate_height: f64, holes: f64, bumpiness: f64, lines_cleared: f64) -> Self {
+        Self {
+            aggregate_height,
+            holes,
+            bumpiness,
+            lines_cleared,
+        }
+    }
+}
+
+impl Default for HeuristicWeights {
+    /// Weights that are known to play well without any lookahead.
+    fn default() -> Self {
+        Self::new(-0.510066, -0.35663, -0.184483, 0.760666)
+    }
+}
+
+/// Parses the weights as four comma separated numbers in the order of the fields, for example
+/// -0.51,-0.36,-0.18,0.76.
+impl FromStr for HeuristicWeights {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let weights: Vec<f64> = text
+            .split(',')
+            .map(|weight| weight.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("Invalid heuristic weights: '{text}'"))?;
+        match weights[..] {
+            [aggregate_height, holes, bumpiness, lines_cleared] => {
+                Ok(Self::new(aggregate_height, holes, bumpiness, lines_cleared))
+            }
+            _ => Err(format!("Invalid heuristic weights: '{text}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn from_str_parses_weights_in_order_of_fields() {
+        // Act
+        let result = HeuristicWeights::from_str("-0.5, -0.25,-1,2");
+
+        // Assert
+        assert_eq!(result, Ok(HeuristicWeights::new(-0.5, -0.25, -1.0, 2.0)));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("-0.5,-0.25,-1")]
+    #[case("-0.5,-0.25,-1,x")]
+    fn from_str_rejects_invalid_weights(#[case] text: &str) {
+        // Act
+        let result = HeuristicWeights::from_str(text);
+
+        // Assert
+        assert_eq!(result, Err(format!("Invalid heuristic weights: '{text}'")));
+    }
+}
@@ -0,0 +1,7 @@
+mod ai_player;
+mod board_evaluator;
+mod heuristic_weights;
+
+pub use ai_player::AiPlayer;
+pub use board_evaluator::*;
+pub use heuristic_weights::HeuristicWeights;
@@ -0,0 +1,106 @@
+use samtrisrust::ai::HeuristicWeights;
+use samtrisrust::constants::*;
+use samtrisrust::high_scores::{FileHighScoresRepository, HighScoresRepository};
+use samtrisrust::replay::{verify_high_score, FileReplayRepository};
+use samtrisrust::simulation::{
+    simulate_game, InputScript, InputSource, SimulationSettings, SimulationSummary,
+};
+use std::time::Instant;
+
+const DEFAULT_NR_GAMES: u32 = 100;
+const DEFAULT_MAX_PIECES: u32 = 1000;
+
+const USAGE: &str = "Usage: samtris-sim [--games N] [--seed N] [--level N] [--max-pieces N] \
+[--script FILE]
+                   [--weights HEIGHT,HOLES,BUMPINESS,LINES]
+       samtris-sim --verify-high-scores";
+
+/// Runs many games without a window and prints a summary. The AI plays, with the given weights
+/// or the default ones, unless a script with inputs is given.
+fn main() -> Result<(), String> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("--verify-high-scores") {
+        return verify_high_scores();
+    }
+
+    let mut nr_games = DEFAULT_NR_GAMES;
+    let mut seed: u64 = 0;
+    let mut settings = SimulationSettings {
+        start_level: 0,
+        max_pieces: DEFAULT_MAX_PIECES,
+    };
+    let mut input_source = InputSource::Ai(HeuristicWeights::default());
+
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        let value = arg_iter
+            .next()
+            .ok_or_else(|| format!("Missing value for {arg}\n{USAGE}"))?;
+        match arg.as_str() {
+            "--games" => nr_games = parse_number(arg, value)?,
+            "--seed" => seed = parse_number(arg, value)?,
+            "--level" => settings.start_level = parse_number(arg, value)?,
+            "--max-pieces" => settings.max_pieces = parse_number(arg, value)?,
+            "--weights" => input_source = InputSource::Ai(value.parse()?),
+            "--script" => {
+                let text = std::fs::read_to_string(value)
+                    .map_err(|e| format!("Failed to read {value}: {e}"))?;
+                input_source = InputSource::Script(InputScript::parse(&text)?);
+            }
+            _ => return Err(format!("Unknown argument {arg}\n{USAGE}")),
+        }
+    }
+
+    let start_time = Instant::now();
+    let mut summary = SimulationSummary::new();
+    for game_index in 0..nr_games {
+        let game_summary = simulate_game(seed + game_index as u64, &input_source, &settings);
+        summary.add(&game_summary);
+    }
+
+    println!("{summary}");
+    println!("Time:       {:.2}s", start_time.elapsed().as_secs_f64());
+    Ok(())
+}
+
+/// Re-simulates the replay of every high score in every table and reports the ones that don't
+/// match.
+fn verify_high_scores() -> Result<(), String> {
+    let high_score_tables = FileHighScoresRepository::new(
+        HIGH_SCORES_FILE.to_string(),
+        HIGH_SCORES_SECRET_FILE.to_string(),
+    )
+    .load()
+    .map_err(|e| e.to_string())?;
+    let replay_repository = FileReplayRepository::new(REPLAYS_DIR);
+
+    let mut nr_rejected = 0;
+    for (table_key, high_scores) in high_score_tables.iter() {
+        for high_score in high_scores.get_scores() {
+            let verdict = match verify_high_score(high_score, &replay_repository) {
+                Ok(()) => "OK".to_string(),
+                Err(e) => {
+                    nr_rejected += 1;
+                    format!("REJECTED: {e}")
+                }
+            };
+            println!(
+                "{:12} {:8} {:>7}  {verdict}",
+                table_key.get_title(),
+                high_score.name,
+                high_score.game_result.score
+            );
+        }
+    }
+
+    match nr_rejected {
+        0 => Ok(()),
+        _ => Err(format!("{nr_rejected} high score(s) could not be verified")),
+    }
+}
+
+fn parse_number<N: std::str::FromStr>(arg: &str, value: &str) -> Result<N, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {arg}: {value}\n{USAGE}"))
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// A UTC date and time with second precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTime {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl DateTime {
+    pub fn now() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Self::from_unix_timestamp(seconds)
+    }
+
+    pub fn from_unix_timestamp(seconds: u64) -> Self {
+        let (year, month, day) = civil_from_days(seconds / SECONDS_PER_DAY);
+        let seconds_of_day = (seconds % SECONDS_PER_DAY) as u32;
+        Self {
+            year,
+            month,
+            day,
+            hour: seconds_of_day / 3600,
+            minute: seconds_of_day / 60 % 60,
+            second: seconds_of_day % 60,
+        }
+    }
+
+    /// Formats the date and time so it can be used in a file name and still sorts in time order.
+    pub fn to_file_name(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}_{:02}-{:02}-{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// Parses the date and time as it's displayed, for example 2026-10-18 12:43:05.
+impl FromStr for DateTime {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<u32> = text
+            .split(['-', ' ', ':'])
+            .map(|number| number.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("Invalid date and time: '{text}'"))?;
+        match numbers[..] {
+            [year, month, day, hour, minute, second] => Ok(Self {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            }),
+            _ => Err(format!("Invalid date and time: '{text}'")),
+        }
+    }
+}
+
+/// Converts days since 1970-01-01 to year, month and day, see
+/// https://howardhinnant.github.io/date_algorithms.html#civil_from_days.
+fn civil_from_days(days: u64) -> (u32, u32, u32) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year as u32, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, "1970-01-01 00:00:00")]
+    #[case(951_782_400, "2000-02-29 00:00:00")]
+    #[case(1_792_327_385, "2026-10-18 12:43:05")]
+    fn from_unix_timestamp_converts_to_utc(#[case] seconds: u64, #[case] expected: &str) {
+        // Act
+        let result = DateTime::from_unix_timestamp(seconds);
+
+        // Assert
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[test]
+    fn from_str_parses_displayed_date_and_time() {
+        // Arrange
+        let date_time = DateTime::from_unix_timestamp(1_792_327_385);
+
+        // Act
+        let result = DateTime::from_str(&date_time.to_string());
+
+        // Assert
+        assert_eq!(result, Ok(date_time));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("2026-10-18")]
+    #[case("2026-10-18 12:43:xx")]
+    fn from_str_rejects_invalid_date_and_time(#[case] text: &str) {
+        // Act
+        let result = DateTime::from_str(text);
+
+        // Assert
+        assert_eq!(result, Err(format!("Invalid date and time: '{text}'")));
+    }
+
+    #[test]
+    fn to_file_name_has_no_spaces_or_colons() {
+        // Arrange
+        let sut = DateTime::from_unix_timestamp(1_792_327_385);
+
+        // Act
+        let result = sut.to_file_name();
+
+        // Assert
+        assert_eq!(result, "2026-10-18_12-43-05");
+    }
+}
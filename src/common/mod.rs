@@ -1,7 +1,11 @@
+mod date_time;
 mod dimensions;
 mod position;
 mod rotation_index;
+mod seeded_rng;
 
+pub use date_time::DateTime;
 pub use dimensions::Dimensions;
 pub use position::Position;
 pub use rotation_index::RotationIndex;
+pub use seeded_rng::{RngAlgorithm, SeededRng};
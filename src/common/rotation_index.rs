@@ -19,6 +19,11 @@ impl RotationIndex {
     pub fn rotate_counterclockwise(&mut self) {
         self.index = (self.index + self.nr_rotations - 1) % self.nr_rotations
     }
+
+    pub fn rotate_180(&mut self) {
+        self.rotate_clockwise();
+        self.rotate_clockwise();
+    }
 }
 
 impl From<RotationIndex> for usize {
@@ -67,6 +72,30 @@ mod tests {
         assert_eq!(usize::from(rotation), 1);
     }
 
+    #[test]
+    fn rotation_index_rotate_180_advances_index_by_two() {
+        // Arrange
+        let mut rotation = RotationIndex::new(3, 4);
+
+        // Act
+        rotation.rotate_180();
+
+        // Assert
+        assert_eq!(usize::from(rotation), 1);
+    }
+
+    #[test]
+    fn rotation_index_rotate_180_with_two_rotations_keeps_index() {
+        // Arrange
+        let mut rotation = RotationIndex::new(1, 2);
+
+        // Act
+        rotation.rotate_180();
+
+        // Assert
+        assert_eq!(usize::from(rotation), 1);
+    }
+
     #[test]
     fn rotation_index_rotate_counterclockwise_wraps_at_zero() {
         // Arrange
@@ -8,23 +8,62 @@ pub const WINDOW_HEIGHT_IN_BLOCKS: u32 = 25;
 pub const PLAYFIELD_OFFSET_X: u32 = (WINDOW_WIDTH_IN_BLOCKS - PLAYFIELD_WIDTH) * BLOCK_SIZE / 2;
 pub const PLAYFIELD_OFFSET_Y: u32 = (WINDOW_HEIGHT_IN_BLOCKS - PLAYFIELD_HEIGHT) * BLOCK_SIZE / 2;
 pub const PLAYFIELD_BORDER_WIDTH: u32 = 1;
-pub const FILLED_LINES_ANIMATION_DURATION_MS: u64 = 1000;
+pub const DEFAULT_ENTRY_DELAY_MS: u64 = 0;
+pub const DEFAULT_LINE_CLEAR_DELAY_MS: u64 = 1000;
 pub const NEXT_TETROMINO_OFFSET_X: u32 =
     PLAYFIELD_OFFSET_X + (PLAYFIELD_WIDTH * BLOCK_SIZE) + (2 * BLOCK_SIZE);
 pub const NEXT_TETROMINO_OFFSET_Y: u32 = PLAYFIELD_OFFSET_Y + (2 * BLOCK_SIZE);
 pub const NEXT_TETROMINO_AREA_WIDTH: u32 = 5 * BLOCK_SIZE;
 pub const NEXT_TETROMINO_AREA_HEIGHT: u32 = 4 * BLOCK_SIZE;
-pub const SCORE_OFFSET_X: u32 = 20;
-pub const SCORE_OFFSET_Y: u32 = NEXT_TETROMINO_OFFSET_Y;
-pub const LINES_OFFSET_X: u32 = 20;
-pub const LINES_OFFSET_Y: u32 = NEXT_TETROMINO_OFFSET_Y + 20;
-pub const LEVEL_OFFSET_X: u32 = 20;
-pub const LEVEL_OFFSET_Y: u32 = NEXT_TETROMINO_OFFSET_Y + 40;
+pub const HUD_TEXT_OFFSET_X: u32 = 20;
+pub const HUD_TEXT_OFFSET_Y: u32 = NEXT_TETROMINO_OFFSET_Y;
+pub const HUD_TEXT_LINE_HEIGHT: u32 = 20;
+pub const STATISTICS_BAR_MAX_WIDTH: u32 = 5 * BLOCK_SIZE; // Bar of the most spawned tetromino
+pub const VERSUS_LEFT_PLAYFIELD_OFFSET_X: u32 = BLOCK_SIZE;
+pub const VERSUS_RIGHT_PLAYFIELD_OFFSET_X: u32 =
+    (WINDOW_WIDTH_IN_BLOCKS - PLAYFIELD_WIDTH - 1) * BLOCK_SIZE;
+pub const VERSUS_LEFT_NEXT_TETROMINO_OFFSET_X: u32 =
+    VERSUS_LEFT_PLAYFIELD_OFFSET_X + (PLAYFIELD_WIDTH * BLOCK_SIZE) + (2 * BLOCK_SIZE);
+pub const VERSUS_RIGHT_NEXT_TETROMINO_OFFSET_X: u32 =
+    VERSUS_RIGHT_PLAYFIELD_OFFSET_X - (2 * BLOCK_SIZE) - NEXT_TETROMINO_AREA_WIDTH;
+pub const VERSUS_LEFT_HUD_TEXT_OFFSET_X: u32 = VERSUS_LEFT_NEXT_TETROMINO_OFFSET_X;
+pub const VERSUS_LEFT_HUD_TEXT_OFFSET_Y: u32 =
+    NEXT_TETROMINO_OFFSET_Y + NEXT_TETROMINO_AREA_HEIGHT + (2 * BLOCK_SIZE);
+pub const VERSUS_RIGHT_HUD_TEXT_OFFSET_X: u32 =
+    VERSUS_RIGHT_NEXT_TETROMINO_OFFSET_X - 6 * BLOCK_SIZE;
+pub const VERSUS_RIGHT_HUD_TEXT_OFFSET_Y: u32 = VERSUS_LEFT_HUD_TEXT_OFFSET_Y + 5 * BLOCK_SIZE;
+pub const VERSUS_RESULT_OFFSET_Y: u32 = PLAYFIELD_OFFSET_Y + (PLAYFIELD_HEIGHT - 2) * BLOCK_SIZE;
+pub const COOP_PLAYFIELD_WIDTH: u32 = 16; // In blocks
+pub const COOP_SPAWN_X: [i32; 2] = [2, 10]; // In blocks within playfield, one per player
+pub const COOP_PLAYFIELD_OFFSET_X: u32 =
+    (WINDOW_WIDTH_IN_BLOCKS - COOP_PLAYFIELD_WIDTH) * BLOCK_SIZE / 2;
+pub const COOP_NEXT_TETROMINO_OFFSET_X: u32 =
+    COOP_PLAYFIELD_OFFSET_X + (COOP_PLAYFIELD_WIDTH * BLOCK_SIZE) + (2 * BLOCK_SIZE);
 pub const GAME_OVER_WIDTH: u32 = (PLAYFIELD_WIDTH + 2) * BLOCK_SIZE;
 pub const GAME_OVER_HEIGHT: u32 = 50;
 pub const CHAR_WIDTH: u32 = 16;
 pub const CHAR_HEIGHT: u32 = 16;
 pub const HIGH_SCORES_FILE: &str = "high_scores.dat";
+pub const HIGH_SCORES_SECRET_FILE: &str = "high_scores.key"; // Signs HIGH_SCORES_FILE, per install
 pub const HIGH_SCORES_X: u32 = 90;
 pub const HIGH_SCORES_Y: u32 = 70;
 pub const HIGH_SCORES_LINE_HEIGHT: u32 = 22;
+pub const NETWORK_DEFAULT_PORT: u16 = 7272;
+pub const AI_INPUT_INTERVAL_MS: u64 = 80;
+pub const GAME_TICK_MS: u64 = 16; // Fixed game update step, one frame at ~60 FPS
+pub const REPLAYS_DIR: &str = "replays"; // Next to HIGH_SCORES_FILE
+pub const REPLAY_LIST_VISIBLE_ITEMS: usize = 11;
+pub const REPLAY_SEEK_TICKS: u64 = 5000 / GAME_TICK_MS; // Five seconds
+pub const STATISTICS_FILE: &str = "statistics.dat"; // Totals and bests of all games
+pub const GAME_HISTORY_FILE: &str = "game_history.dat"; // Every game played to the end
+pub const GAME_HISTORY_CSV_FILE: &str = "game_history.csv";
+pub const GAME_HISTORY_X: u32 = 20; // The rows are wider than the high scores
+pub const GAME_HISTORY_VISIBLE_ITEMS: usize = 11;
+pub const SAVED_GAME_FILE: &str = "saved_game.dat"; // Game quit with Escape, to be continued
+pub const MENU_VISIBLE_ITEMS: usize = 6; // Items that fit below the title
+pub const PUZZLES_DIR: &str = "puzzles"; // Puzzle files that can be edited by hand
+pub const SOLVED_PUZZLES_FILE: &str = "solved_puzzles.dat";
+pub const EXPORTED_FUMENS_FILE: &str = "exported_fumens.txt"; // Boards exported with F in a game
+pub const PUZZLE_LIST_VISIBLE_ITEMS: usize = 11;
+pub const PUZZLE_SOLVED_X: u32 = HIGH_SCORES_X + 22 * CHAR_WIDTH;
+pub const EDITED_PUZZLE_NAME: &str = "edited"; // Puzzle the board editor saves and loads
@@ -1,5 +1,12 @@
+use crate::tetromino::TetrominoType;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Event {
     LevelStarted(u32), // Level number (0-based)
     LinesCleared(u32), // Number of lines cleared
+    TSpin(u32),        // Number of lines cleared with it
+    PerfectClear,
+    FinesseFault(u32), // Number of inputs more than needed to place the tetromino
+    TetrominoSpawned(TetrominoType),
+    TetrominoLocked(TetrominoType),
 }
@@ -0,0 +1,64 @@
+use crate::fumen::{Fumen, FumenRepository};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Appends every exported board to a text file, one fumen per line.
+pub struct FileFumenRepository {
+    file_path: PathBuf,
+}
+
+impl FileFumenRepository {
+    pub fn new(file_path: impl Into<PathBuf>) -> Self {
+        Self {
+            file_path: file_path.into(),
+        }
+    }
+}
+
+impl FumenRepository for FileFumenRepository {
+    fn append(&self, fumen: &Fumen) -> Result<(), String> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file_path)
+            .map_err(|e| format!("Failed to open exported fumens: {e}"))?;
+        writeln!(file, "{}", fumen.encode()).map_err(|e| format!("Failed to write fumen: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Dimensions;
+    use crate::game_logic::PlayfieldGrid;
+    use std::fs;
+
+    #[test]
+    fn append_writes_one_fumen_per_line() {
+        // Arrange
+        let test_file = std::env::temp_dir().join("samtris_test_exported_fumens.txt");
+        let _ = fs::remove_file(&test_file);
+        let sut = FileFumenRepository::new(&test_file);
+        let fumen = Fumen {
+            grid: PlayfieldGrid::new(Dimensions::new(10, 20)),
+            current_tetromino: None,
+            queue: Vec::new(),
+        };
+
+        // Act
+        sut.append(&fumen).unwrap();
+        sut.append(&fumen).unwrap();
+
+        // Assert
+        let text = fs::read_to_string(&test_file).unwrap();
+        let expected_line = fumen.encode();
+        assert_eq!(
+            text.lines().collect::<Vec<_>>(),
+            vec![expected_line.as_str(), expected_line.as_str()]
+        );
+
+        // Cleanup
+        let _ = fs::remove_file(&test_file);
+    }
+}
@@ -0,0 +1,103 @@
+const ENCODE_TABLE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const VALUES_PER_DIGIT: u32 = 64;
+
+/// Fumen data as base 64 digits. Values are written with a fixed number of digits, the least
+/// significant digit first.
+pub struct FumenBuffer {
+    digits: Vec<u32>,
+    read_index: usize,
+}
+
+impl FumenBuffer {
+    pub fn new() -> Self {
+        Self {
+            digits: Vec::new(),
+            read_index: 0,
+        }
+    }
+
+    pub fn decode(data: &str) -> Result<Self, String> {
+        let digits = data
+            .chars()
+            .map(|ch| {
+                ENCODE_TABLE
+                    .iter()
+                    .position(|&digit| digit as char == ch)
+                    .map(|value| value as u32)
+                    .ok_or(format!("Invalid character in fumen: '{ch}'"))
+            })
+            .collect::<Result<Vec<u32>, String>>()?;
+        Ok(Self {
+            digits,
+            read_index: 0,
+        })
+    }
+
+    pub fn encode(&self) -> String {
+        self.digits
+            .iter()
+            .map(|&value| ENCODE_TABLE[value as usize] as char)
+            .collect()
+    }
+
+    pub fn push(&mut self, value: u32, nr_digits: usize) {
+        let mut value = value;
+        for _ in 0..nr_digits {
+            self.digits.push(value % VALUES_PER_DIGIT);
+            value /= VALUES_PER_DIGIT;
+        }
+    }
+
+    pub fn poll(&mut self, nr_digits: usize) -> Result<u32, String> {
+        if self.read_index + nr_digits > self.digits.len() {
+            return Err("Fumen data ends unexpectedly".to_string());
+        }
+
+        let digits = &self.digits[self.read_index..self.read_index + nr_digits];
+        self.read_index += nr_digits;
+        Ok(digits
+            .iter()
+            .rev()
+            .fold(0, |value, &digit| value * VALUES_PER_DIGIT + digit))
+    }
+}
+
+impl Default for FumenBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pushed_values_are_polled_in_order() {
+        // Arrange
+        let mut sut = FumenBuffer::new();
+        sut.push(2159, 2);
+        sut.push(30720, 3);
+
+        // Act
+        let mut result = FumenBuffer::decode(&sut.encode()).unwrap();
+
+        // Assert
+        assert_eq!(sut.encode(), "vhAgH");
+        assert_eq!(result.poll(2), Ok(2159));
+        assert_eq!(result.poll(3), Ok(30720));
+        assert!(result.poll(1).is_err());
+    }
+
+    #[test]
+    fn decode_with_invalid_character_fails() {
+        // Act
+        let result = FumenBuffer::decode("vh!");
+
+        // Assert
+        assert_eq!(
+            result.err(),
+            Some("Invalid character in fumen: '!'".to_string())
+        );
+    }
+}
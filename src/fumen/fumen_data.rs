@@ -0,0 +1,519 @@
+use crate::common::{Dimensions, Position};
+use crate::constants::{PLAYFIELD_HEIGHT, PLAYFIELD_WIDTH};
+use crate::fumen::fumen_buffer::FumenBuffer;
+use crate::fumen::fumen_piece::{
+    decode_cell, encode_cell, FumenPiece, FumenRotation, FIELD_TOP, FIELD_WIDTH,
+};
+use crate::game_logic::PlayfieldGrid;
+use crate::graphics::PlayfieldView;
+use crate::tetromino::{TetrominoInstance, TetrominoType};
+
+const FUMEN_PREFIX: &str = "v115@";
+/// Cells of a fumen board, including the garbage row below it.
+const FIELD_BLOCKS: u32 = ((FIELD_TOP + 1) * FIELD_WIDTH) as u32;
+const EMPTY_DIFF: u32 = 8;
+const NR_PIECE_TYPES: u32 = 8;
+const NR_ROTATIONS: u32 = 4;
+const FIRST_LINE_LENGTH: usize = 42;
+const LINE_LENGTH: usize = 47;
+const COMMENT_TABLE: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+const COMMENT_CHAR_VALUES: u32 = 96;
+const MAX_COMMENT_LENGTH: usize = 4095;
+const QUIZ_PREFIX: &str = "#Q=";
+
+/// A board with the falling tetromino and the tetrominoes that come after it, as shared in the
+/// fumen format. Only the first page of fumen data is read, the queue is kept in its comment
+/// the way fumen quizzes do, e.g. "#Q=[](T)IOS".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fumen {
+    pub grid: PlayfieldGrid,
+    pub current_tetromino: Option<TetrominoInstance>,
+    pub queue: Vec<TetrominoType>,
+}
+
+impl Fumen {
+    /// Captures the board, falling tetromino and next tetromino of a playfield.
+    pub fn from_view(view: &PlayfieldView) -> Self {
+        Self {
+            grid: view.grid.clone(),
+            current_tetromino: view.current_tetrominoes.first().copied().cloned(),
+            queue: vec![view.next_tetromino_type],
+        }
+    }
+
+    /// Returns the tetrominoes to deal in order, starting with the falling one.
+    pub fn get_tetromino_types(&self) -> Vec<TetrominoType> {
+        self.current_tetromino
+            .iter()
+            .map(TetrominoInstance::get_type)
+            .chain(self.queue.iter().copied())
+            .collect()
+    }
+
+    pub fn encode(&self) -> String {
+        let mut buffer = FumenBuffer::new();
+        self.encode_field(&mut buffer);
+
+        let piece = self
+            .current_tetromino
+            .as_ref()
+            .and_then(|tetromino| FumenPiece::from_tetromino(tetromino, self.get_height()));
+        let comment = self.get_comment();
+        buffer.push(encode_action(piece.as_ref(), !comment.is_empty()), 3);
+        if !comment.is_empty() {
+            encode_comment(&comment, &mut buffer);
+        }
+
+        format!("{FUMEN_PREFIX}{}", split_into_lines(&buffer.encode()))
+    }
+
+    /// Decodes the first page of fumen data, a fumen url can be given as well.
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let data_start = text
+            .find(FUMEN_PREFIX)
+            .ok_or("Fumen doesn't start with 'v115@'")?
+            + FUMEN_PREFIX.len();
+        let data: String = text[data_start..]
+            .chars()
+            .filter(|&ch| ch != '?' && !ch.is_whitespace())
+            .collect();
+        let mut buffer = FumenBuffer::decode(&data)?;
+
+        let grid = decode_field(&mut buffer)?;
+        let action = buffer.poll(3)?;
+        let piece = decode_action(action);
+        let comment = if has_comment(action) {
+            decode_comment(&mut buffer)?
+        } else {
+            String::new()
+        };
+
+        let current_tetromino = piece
+            .map(|piece| {
+                piece
+                    .to_tetromino(PLAYFIELD_HEIGHT as i32)
+                    .ok_or("Invalid piece in fumen")
+            })
+            .transpose()?;
+        let (quiz_current, mut queue) = decode_quiz(&comment)?;
+        if let (None, Some(quiz_current)) = (&current_tetromino, quiz_current) {
+            queue.insert(0, quiz_current); // Without a falling tetromino it comes first
+        }
+
+        Ok(Self {
+            grid,
+            current_tetromino,
+            queue,
+        })
+    }
+
+    fn get_height(&self) -> i32 {
+        self.grid.get_dimensions().height as i32
+    }
+
+    fn encode_field(&self, buffer: &mut FumenBuffer) {
+        // Cells are listed from the top left, fumen boards are higher than the playfield and
+        // have a garbage row below it
+        let cells: Vec<u32> = (-1..FIELD_TOP)
+            .rev()
+            .flat_map(|y| (0..FIELD_WIDTH).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let position = Position::new(x, self.get_height() - 1 - y);
+                encode_cell(self.grid.get(position).copied())
+            })
+            .collect();
+
+        let mut runs: Vec<(u32, u32)> = Vec::new();
+        for cell in cells {
+            let diff = cell + EMPTY_DIFF;
+            match runs.last_mut() {
+                Some((run_diff, length)) if *run_diff == diff => *length += 1,
+                _ => runs.push((diff, 1)),
+            }
+        }
+        for &(diff, length) in &runs {
+            buffer.push(diff * FIELD_BLOCKS + length - 1, 2);
+        }
+        if runs.len() == 1 && runs[0].0 == EMPTY_DIFF {
+            buffer.push(0, 1); // No pages with the same empty board follow
+        }
+    }
+
+    fn get_comment(&self) -> String {
+        let tetromino_types = self.get_tetromino_types();
+        let Some((current, next)) = tetromino_types.split_first() else {
+            return String::new();
+        };
+        let next: String = next.iter().map(|t| t.to_char()).collect();
+        format!("{QUIZ_PREFIX}[]({}){next}", current.to_char())
+    }
+}
+
+fn decode_field(buffer: &mut FumenBuffer) -> Result<PlayfieldGrid, String> {
+    let mut cells: Vec<Option<TetrominoType>> = Vec::new();
+    let mut is_empty_field = true;
+    while cells.len() < FIELD_BLOCKS as usize {
+        let value = buffer.poll(2)?;
+        let diff = value / FIELD_BLOCKS;
+        let length = value % FIELD_BLOCKS + 1;
+        let cell = diff
+            .checked_sub(EMPTY_DIFF)
+            .ok_or("Invalid board in fumen".to_string())
+            .and_then(decode_cell)?;
+        is_empty_field &= diff == EMPTY_DIFF && length == FIELD_BLOCKS;
+        cells.extend((0..length).map(|_| cell));
+    }
+    if cells.len() > FIELD_BLOCKS as usize {
+        return Err("Invalid board in fumen".to_string());
+    }
+    if is_empty_field {
+        buffer.poll(1)?; // Number of pages with the same empty board that follow
+    }
+
+    let mut grid = PlayfieldGrid::new(Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT));
+    for (index, cell) in cells.into_iter().enumerate() {
+        let y = FIELD_TOP - 1 - index as i32 / FIELD_WIDTH;
+        let x = index as i32 % FIELD_WIDTH;
+        if cell.is_none() || y < 0 {
+            continue; // The garbage row is not part of the board
+        }
+        if y >= PLAYFIELD_HEIGHT as i32 {
+            return Err("Fumen board is higher than the playfield".to_string());
+        }
+        grid.set(Position::new(x, PLAYFIELD_HEIGHT as i32 - 1 - y), cell);
+    }
+    Ok(grid)
+}
+
+/// Packs the piece and the flags of a page, the page is always colored and locks its piece.
+fn encode_action(piece: Option<&FumenPiece>, has_comment: bool) -> u32 {
+    let is_colored = true;
+    let mut value = u32::from(has_comment);
+    value = value * 2 + u32::from(is_colored);
+    value *= 2 * 2; // Not mirrored and no rising garbage
+    match piece {
+        Some(piece) => {
+            value = value * FIELD_BLOCKS + piece.encode_position();
+            value = value * NR_ROTATIONS + piece.rotation.encode();
+            value * NR_PIECE_TYPES + encode_cell(Some(piece.tetromino_type))
+        }
+        None => value * FIELD_BLOCKS * NR_ROTATIONS * NR_PIECE_TYPES,
+    }
+}
+
+fn decode_action(action: u32) -> Option<FumenPiece> {
+    let tetromino_type = decode_cell(action % NR_PIECE_TYPES).ok().flatten()?;
+    let rotation = FumenRotation::decode(action / NR_PIECE_TYPES);
+    let position = action / NR_PIECE_TYPES / NR_ROTATIONS % FIELD_BLOCKS;
+    Some(FumenPiece::decode_position(
+        tetromino_type,
+        rotation,
+        position,
+    ))
+}
+
+fn has_comment(action: u32) -> bool {
+    let flags = action / NR_PIECE_TYPES / NR_ROTATIONS / FIELD_BLOCKS;
+    (flags >> 3) & 1 == 1
+}
+
+fn encode_comment(comment: &str, buffer: &mut FumenBuffer) {
+    let escaped: Vec<u32> = escape(comment)
+        .chars()
+        .take(MAX_COMMENT_LENGTH)
+        .map(|ch| COMMENT_TABLE.find(ch).unwrap_or(0) as u32)
+        .collect();
+    buffer.push(escaped.len() as u32, 2);
+    for chunk in escaped.chunks(4) {
+        let value = chunk
+            .iter()
+            .rev()
+            .fold(0, |value, &ch| value * COMMENT_CHAR_VALUES + ch);
+        buffer.push(value, 5);
+    }
+}
+
+fn decode_comment(buffer: &mut FumenBuffer) -> Result<String, String> {
+    let length = buffer.poll(2)? as usize;
+    let mut escaped = String::new();
+    while escaped.len() < length {
+        let mut value = buffer.poll(5)?;
+        for _ in 0..4 {
+            let index = (value % COMMENT_CHAR_VALUES) as usize;
+            escaped.push(COMMENT_TABLE.chars().nth(index).unwrap_or(' '));
+            value /= COMMENT_CHAR_VALUES;
+        }
+    }
+    escaped.truncate(length);
+    Ok(unescape(&escaped))
+}
+
+/// Returns the current and next pieces of a quiz comment like "#Q=[](T)IOS", the piece on hold
+/// is left out. Other comments have no pieces.
+fn decode_quiz(comment: &str) -> Result<(Option<TetrominoType>, Vec<TetrominoType>), String> {
+    let Some(quiz) = comment.strip_prefix(QUIZ_PREFIX) else {
+        return Ok((None, Vec::new()));
+    };
+    let after_hold = quiz.split_once(']').map_or(quiz, |(_, rest)| rest);
+    let (current, next) = match after_hold
+        .strip_prefix('(')
+        .and_then(|rest| rest.split_once(')'))
+    {
+        Some((current, next)) => (current, next),
+        None => ("", after_hold),
+    };
+
+    let current = decode_quiz_pieces(current)?.first().copied();
+    Ok((current, decode_quiz_pieces(next)?))
+}
+
+fn decode_quiz_pieces(text: &str) -> Result<Vec<TetrominoType>, String> {
+    text.chars()
+        .map(|ch| {
+            TetrominoType::from_char(ch).ok_or(format!("Invalid piece in fumen queue: '{ch}'"))
+        })
+        .collect()
+}
+
+/// Escapes text the way JavaScript's `escape` does, which fumen uses for its comments.
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for ch in text.chars() {
+        if ch.is_ascii_alphanumeric() || "@*_+-./".contains(ch) {
+            escaped.push(ch);
+        } else if (ch as u32) < 0x100 {
+            escaped.push_str(&format!("%{:02X}", ch as u32));
+        } else {
+            for unit in ch.encode_utf16(&mut [0; 2]) {
+                escaped.push_str(&format!("%u{:04X}", unit));
+            }
+        }
+    }
+    escaped
+}
+
+fn unescape(text: &str) -> String {
+    let mut units: Vec<u16> = Vec::new();
+    let mut rest = text;
+    while let Some(ch) = rest.chars().next() {
+        let code = if let Some(hex) = rest.strip_prefix("%u").and_then(|r| r.get(..4)) {
+            u16::from_str_radix(hex, 16).ok().map(|code| (code, 6))
+        } else if let Some(hex) = rest.strip_prefix('%').and_then(|r| r.get(..2)) {
+            u16::from_str_radix(hex, 16).ok().map(|code| (code, 3))
+        } else {
+            None
+        };
+        match code {
+            Some((code, length)) => {
+                units.push(code);
+                rest = &rest[length..];
+            }
+            None => {
+                units.push(ch as u16);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+    String::from_utf16_lossy(&units)
+}
+
+/// Fumen data is split into lines by question marks, the first line is shorter to make room
+/// for the prefix.
+fn split_into_lines(data: &str) -> String {
+    let mut result = String::new();
+    for (index, ch) in data.chars().enumerate() {
+        let is_line_start = index == FIRST_LINE_LENGTH
+            || (index > FIRST_LINE_LENGTH
+                && (index - FIRST_LINE_LENGTH).is_multiple_of(LINE_LENGTH));
+        if is_line_start {
+            result.push('?');
+        }
+        result.push(ch);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::GARBAGE_TETROMINO_TYPE;
+    use crate::tetromino::TetrominoDefinitions;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("v115@vhAAgH")]
+    #[case("v115@bhzhPeAgH")]
+    #[case("v115@vhAAgWVAFLDmClcJSAVDEHBEooRBUoAVBzAAAA")]
+    fn encode_returns_decoded_fumen(#[case] text: &str) {
+        // Arrange
+        let sut = Fumen::decode(text).unwrap();
+
+        // Act
+        let result = sut.encode();
+
+        // Assert
+        assert_eq!(result, text);
+    }
+
+    #[test]
+    fn decode_reads_board_and_quiz_queue() {
+        // Act
+        let result = Fumen::decode("v115@bhzhPeAgWVAFLDmClcJSAVDEHBEooRBUoAVBzAAAA").unwrap();
+
+        // Assert
+        let mut expected_grid = create_empty_grid();
+        for x in 0..4 {
+            expected_grid.set(Position::new(x, 19), Some(TetrominoType::I));
+        }
+        assert_eq!(result.grid, expected_grid);
+        assert_eq!(result.current_tetromino, None);
+        assert_eq!(result.queue, vec![TetrominoType::T, TetrominoType::S]);
+    }
+
+    #[test]
+    fn decode_returns_encoded_board_with_falling_tetromino() {
+        // Arrange
+        let mut grid =
+            create_bottom_grid(&["IIII.LLLZZ", "JJJ..SSLTZ", "OOJ.SSLTTT", "OOJ.IIIIZZ"]);
+        grid.set(Position::new(3, 19), Some(TetrominoType::T));
+        let mut tetromino = TetrominoInstance::new(
+            TetrominoType::J,
+            Position::new(5, 3),
+            &TetrominoDefinitions::new(),
+        );
+        tetromino.rotate_counterclockwise();
+        let fumen = Fumen {
+            grid,
+            current_tetromino: Some(tetromino),
+            queue: vec![TetrominoType::S, TetrominoType::Z, TetrominoType::L],
+        };
+
+        // Act
+        let text = fumen.encode();
+        let result = Fumen::decode(&text);
+
+        // Assert
+        assert!(text.contains('?'), "Long fumen data is split into lines");
+        assert_eq!(result, Ok(fumen));
+    }
+
+    #[test]
+    fn decode_without_falling_tetromino_or_queue_has_no_comment() {
+        // Arrange
+        let fumen = Fumen {
+            grid: create_empty_grid(),
+            current_tetromino: None,
+            queue: Vec::new(),
+        };
+
+        // Act
+        let result = fumen.encode();
+
+        // Assert
+        assert_eq!(result, "v115@vhAAgH");
+    }
+
+    #[test]
+    fn decode_accepts_fumen_url() {
+        // Act
+        let result = Fumen::decode("https://fumen.zui.jp/?v115@bhzhPeAgH");
+
+        // Assert
+        assert_eq!(result, Fumen::decode("v115@bhzhPeAgH"));
+    }
+
+    #[test]
+    fn decode_turns_gray_cells_into_garbage() {
+        // Act
+        let result = Fumen::decode("v115@bhJ8JeAgH").unwrap();
+
+        // Assert
+        for x in 0..10 {
+            let cell = result.grid.get(Position::new(x, 19));
+            assert_eq!(cell, Some(&GARBAGE_TETROMINO_TYPE));
+        }
+    }
+
+    #[rstest]
+    #[case("vhAAgH", "Fumen doesn't start with 'v115@'")]
+    #[case("v115@whuhAgH", "Fumen board is higher than the playfield")]
+    #[case("v115@vhA", "Fumen data ends unexpectedly")]
+    fn decode_invalid_fumen_fails(#[case] text: &str, #[case] expected: &str) {
+        // Act
+        let result = Fumen::decode(text);
+
+        // Assert
+        assert_eq!(result, Err(expected.to_string()));
+    }
+
+    #[test]
+    fn get_tetromino_types_starts_with_falling_tetromino() {
+        // Arrange
+        let tetromino = TetrominoInstance::new(
+            TetrominoType::I,
+            Position::new(3, 0),
+            &TetrominoDefinitions::new(),
+        );
+        let sut = Fumen {
+            grid: create_empty_grid(),
+            current_tetromino: Some(tetromino),
+            queue: vec![TetrominoType::O],
+        };
+
+        // Act
+        let result = sut.get_tetromino_types();
+
+        // Assert
+        assert_eq!(result, vec![TetrominoType::I, TetrominoType::O]);
+    }
+
+    #[test]
+    fn from_view_captures_board_and_tetrominoes() {
+        // Arrange
+        let grid = create_bottom_grid(&["ZZ..SSSS.."]);
+        let tetromino = TetrominoInstance::new(
+            TetrominoType::L,
+            Position::new(3, 0),
+            &TetrominoDefinitions::new(),
+        );
+        let view = PlayfieldView {
+            dimensions: grid.get_dimensions(),
+            grid: &grid,
+            current_tetrominoes: vec![&tetromino],
+            next_tetromino_type: TetrominoType::J,
+            full_lines: Vec::new(),
+            show_blinking_lines: false,
+        };
+
+        // Act
+        let result = Fumen::from_view(&view);
+
+        // Assert
+        assert_eq!(result.grid, grid);
+        assert_eq!(result.current_tetromino, Some(tetromino));
+        assert_eq!(result.queue, vec![TetrominoType::J]);
+    }
+
+    #[test]
+    fn escape_returns_unescaped_text() {
+        // Arrange
+        let text = "#Q=[](T)IO é";
+
+        // Act
+        let escaped = escape(text);
+
+        // Assert
+        assert_eq!(escaped, "%23Q%3D%5B%5D%28T%29IO%20%E9");
+        assert_eq!(unescape(&escaped), text);
+    }
+
+    fn create_empty_grid() -> PlayfieldGrid {
+        PlayfieldGrid::new(Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT))
+    }
+
+    fn create_bottom_grid(bottom_rows: &[&str]) -> PlayfieldGrid {
+        let mut rows = vec![".........."; PLAYFIELD_HEIGHT as usize - bottom_rows.len()];
+        rows.extend_from_slice(bottom_rows);
+        PlayfieldGrid::from_rows(&rows).unwrap()
+    }
+}
@@ -0,0 +1,305 @@
+use crate::common::Position;
+use crate::game_logic::GARBAGE_TETROMINO_TYPE;
+use crate::tetromino::{TetrominoDefinitions, TetrominoInstance, TetrominoType};
+
+/// Number of rows of a fumen board above its garbage row.
+pub const FIELD_TOP: i32 = 23;
+pub const FIELD_WIDTH: i32 = 10;
+
+const GRAY_CELL: u32 = 8;
+
+/// Returns the number fumen uses for a cell or the type of a piece, 0 is empty.
+pub fn encode_cell(cell: Option<TetrominoType>) -> u32 {
+    match cell {
+        None => 0,
+        Some(TetrominoType::I) => 1,
+        Some(TetrominoType::L) => 2,
+        Some(TetrominoType::O) => 3,
+        Some(TetrominoType::Z) => 4,
+        Some(TetrominoType::T) => 5,
+        Some(TetrominoType::J) => 6,
+        Some(TetrominoType::S) => 7,
+    }
+}
+
+/// Gray cells become garbage blocks, since there are no gray blocks.
+pub fn decode_cell(value: u32) -> Result<Option<TetrominoType>, String> {
+    match value {
+        0 => Ok(None),
+        1 => Ok(Some(TetrominoType::I)),
+        2 => Ok(Some(TetrominoType::L)),
+        3 => Ok(Some(TetrominoType::O)),
+        4 => Ok(Some(TetrominoType::Z)),
+        5 => Ok(Some(TetrominoType::T)),
+        6 => Ok(Some(TetrominoType::J)),
+        7 => Ok(Some(TetrominoType::S)),
+        GRAY_CELL => Ok(Some(GARBAGE_TETROMINO_TYPE)),
+        _ => Err(format!("Invalid cell in fumen: {value}")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FumenRotation {
+    Spawn,
+    Right,
+    Reverse,
+    Left,
+}
+
+impl FumenRotation {
+    const ALL: [FumenRotation; 4] = [
+        FumenRotation::Spawn,
+        FumenRotation::Right,
+        FumenRotation::Reverse,
+        FumenRotation::Left,
+    ];
+
+    pub fn encode(self) -> u32 {
+        match self {
+            FumenRotation::Reverse => 0,
+            FumenRotation::Right => 1,
+            FumenRotation::Spawn => 2,
+            FumenRotation::Left => 3,
+        }
+    }
+
+    pub fn decode(value: u32) -> Self {
+        match value % 4 {
+            0 => FumenRotation::Reverse,
+            1 => FumenRotation::Right,
+            2 => FumenRotation::Spawn,
+            _ => FumenRotation::Left,
+        }
+    }
+
+    fn rotate(self, x: i32, y: i32) -> Position {
+        match self {
+            FumenRotation::Spawn => Position::new(x, y),
+            FumenRotation::Right => Position::new(y, -x),
+            FumenRotation::Reverse => Position::new(-x, -y),
+            FumenRotation::Left => Position::new(-y, x),
+        }
+    }
+}
+
+/// A piece placed the way fumen places it: rotated around a center block, with y counting up
+/// from the bottom row of the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FumenPiece {
+    pub tetromino_type: TetrominoType,
+    pub rotation: FumenRotation,
+    pub x: i32,
+    pub y: i32,
+}
+
+impl FumenPiece {
+    /// Finds the fumen placement that covers the same blocks as the tetromino. Rotations that
+    /// look the same are encoded as the first of them.
+    pub fn from_tetromino(tetromino: &TetrominoInstance, playfield_height: i32) -> Option<Self> {
+        let blocks = get_sorted_blocks(
+            tetromino
+                .get_world_blocks()
+                .into_iter()
+                .map(|block| Position::new(block.x, playfield_height - 1 - block.y)),
+        );
+        FumenRotation::ALL.into_iter().find_map(|rotation| {
+            let mut piece = Self {
+                tetromino_type: tetromino.get_type(),
+                rotation,
+                x: 0,
+                y: 0,
+            };
+            let offset = get_offset_if_same_shape(&blocks, &get_sorted_blocks(piece.get_blocks()))?;
+            piece.x = offset.x;
+            piece.y = offset.y;
+            Some(piece)
+        })
+    }
+
+    /// Returns the tetromino that covers the same blocks as this piece.
+    pub fn to_tetromino(self, playfield_height: i32) -> Option<TetrominoInstance> {
+        let blocks = get_sorted_blocks(
+            self.get_blocks()
+                .into_iter()
+                .map(|block| Position::new(block.x, playfield_height - 1 - block.y)),
+        );
+        let tetromino_definitions = TetrominoDefinitions::new();
+        let mut tetromino = TetrominoInstance::new(
+            self.tetromino_type,
+            Position::origin(),
+            &tetromino_definitions,
+        );
+        for nr_rotations in 0..4 {
+            let local_blocks = get_sorted_blocks(tetromino.get_world_blocks());
+            if let Some(offset) = get_offset_if_same_shape(&blocks, &local_blocks) {
+                let mut result =
+                    TetrominoInstance::new(self.tetromino_type, offset, &tetromino_definitions);
+                for _ in 0..nr_rotations {
+                    result.rotate_clockwise();
+                }
+                return Some(result);
+            }
+            tetromino.rotate_clockwise();
+        }
+        None
+    }
+
+    /// Returns the position on the board as stored in fumen data, which uses another center
+    /// for some rotations of the O, I, S and Z pieces.
+    pub fn encode_position(&self) -> u32 {
+        let (dx, dy) = get_position_adjustment(self.tetromino_type, self.rotation);
+        let row = FIELD_TOP - (self.y + dy) - 1;
+        (row * FIELD_WIDTH + self.x + dx) as u32
+    }
+
+    pub fn decode_position(
+        tetromino_type: TetrominoType,
+        rotation: FumenRotation,
+        position: u32,
+    ) -> Self {
+        let (dx, dy) = get_position_adjustment(tetromino_type, rotation);
+        let row = position as i32 / FIELD_WIDTH;
+        Self {
+            tetromino_type,
+            rotation,
+            x: position as i32 % FIELD_WIDTH - dx,
+            y: FIELD_TOP - row - 1 - dy,
+        }
+    }
+
+    fn get_blocks(&self) -> Vec<Position> {
+        let shape: [(i32, i32); 4] = match self.tetromino_type {
+            TetrominoType::I => [(0, 0), (-1, 0), (1, 0), (2, 0)],
+            TetrominoType::T => [(0, 0), (-1, 0), (1, 0), (0, 1)],
+            TetrominoType::O => [(0, 0), (1, 0), (0, 1), (1, 1)],
+            TetrominoType::L => [(0, 0), (-1, 0), (1, 0), (1, 1)],
+            TetrominoType::J => [(0, 0), (-1, 0), (1, 0), (-1, 1)],
+            TetrominoType::S => [(0, 0), (-1, 0), (0, 1), (1, 1)],
+            TetrominoType::Z => [(0, 0), (1, 0), (0, 1), (-1, 1)],
+        };
+        shape
+            .iter()
+            .map(|&(x, y)| self.rotation.rotate(x, y).translate(self.x, self.y))
+            .collect()
+    }
+}
+
+fn get_position_adjustment(tetromino_type: TetrominoType, rotation: FumenRotation) -> (i32, i32) {
+    match (tetromino_type, rotation) {
+        (TetrominoType::O, FumenRotation::Left) => (1, -1),
+        (TetrominoType::O, FumenRotation::Reverse) => (1, 0),
+        (TetrominoType::O, FumenRotation::Spawn) => (0, -1),
+        (TetrominoType::I, FumenRotation::Reverse) => (1, 0),
+        (TetrominoType::I, FumenRotation::Left) => (0, -1),
+        (TetrominoType::S, FumenRotation::Spawn) => (0, -1),
+        (TetrominoType::S, FumenRotation::Right) => (-1, 0),
+        (TetrominoType::Z, FumenRotation::Spawn) => (0, -1),
+        (TetrominoType::Z, FumenRotation::Left) => (1, 0),
+        _ => (0, 0),
+    }
+}
+
+fn get_sorted_blocks(blocks: impl IntoIterator<Item = Position>) -> Vec<Position> {
+    let mut blocks: Vec<Position> = blocks.into_iter().collect();
+    blocks.sort_by_key(|block| (block.x, block.y));
+    blocks
+}
+
+/// Returns how far the shape has to be moved to cover the blocks, if it has the same shape.
+fn get_offset_if_same_shape(blocks: &[Position], shape: &[Position]) -> Option<Position> {
+    let offset = blocks[0] - shape[0];
+    blocks
+        .iter()
+        .zip(shape)
+        .all(|(&block, &shape_block)| block == shape_block + offset)
+        .then_some(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn decode_cell_returns_encoded_cell() {
+        for tetromino_type in TetrominoType::iter() {
+            // Act
+            let result = decode_cell(encode_cell(Some(tetromino_type)));
+
+            // Assert
+            assert_eq!(result, Ok(Some(tetromino_type)));
+        }
+    }
+
+    #[rstest]
+    #[case(FumenRotation::Spawn)]
+    #[case(FumenRotation::Right)]
+    #[case(FumenRotation::Reverse)]
+    #[case(FumenRotation::Left)]
+    fn decode_position_returns_encoded_piece(#[case] rotation: FumenRotation) {
+        for tetromino_type in TetrominoType::iter() {
+            // Arrange
+            let piece = FumenPiece {
+                tetromino_type,
+                rotation,
+                x: 4,
+                y: 5,
+            };
+
+            // Act
+            let result = FumenPiece::decode_position(
+                tetromino_type,
+                FumenRotation::decode(rotation.encode()),
+                piece.encode_position(),
+            );
+
+            // Assert
+            assert_eq!(result, piece);
+        }
+    }
+
+    #[test]
+    fn to_tetromino_covers_same_blocks_as_piece() {
+        // Arrange
+        let sut = FumenPiece {
+            tetromino_type: TetrominoType::T,
+            rotation: FumenRotation::Right,
+            x: 1,
+            y: 1,
+        };
+
+        // Act
+        let result = sut.to_tetromino(20).unwrap();
+
+        // Assert
+        let blocks = get_sorted_blocks(result.get_world_blocks());
+        let expected = vec![
+            Position::new(1, 17),
+            Position::new(1, 18),
+            Position::new(1, 19),
+            Position::new(2, 18),
+        ];
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn from_tetromino_returns_piece_that_covers_same_blocks() {
+        // Arrange
+        let tetromino_definitions = TetrominoDefinitions::new();
+        for tetromino_type in TetrominoType::iter() {
+            let mut tetromino = TetrominoInstance::new(
+                tetromino_type,
+                Position::new(3, 10),
+                &tetromino_definitions,
+            );
+            tetromino.rotate_clockwise();
+
+            // Act
+            let result = FumenPiece::from_tetromino(&tetromino, 20).unwrap();
+
+            // Assert
+            assert_eq!(result.to_tetromino(20), Some(tetromino));
+        }
+    }
+}
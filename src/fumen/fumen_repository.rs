@@ -0,0 +1,6 @@
+use crate::fumen::Fumen;
+
+/// Keeps the boards exported from games, so they can be shared.
+pub trait FumenRepository {
+    fn append(&self, fumen: &Fumen) -> Result<(), String>;
+}
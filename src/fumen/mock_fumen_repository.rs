@@ -0,0 +1,28 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::fumen::{Fumen, FumenRepository};
+
+/// Keeps exported boards in memory, clone `fumens` before boxing to inspect them.
+#[derive(Default)]
+pub struct MockFumenRepository {
+    pub fumens: Rc<RefCell<Vec<Fumen>>>,
+    pub fail_on_append: bool,
+}
+
+impl MockFumenRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FumenRepository for MockFumenRepository {
+    fn append(&self, fumen: &Fumen) -> Result<(), String> {
+        if self.fail_on_append {
+            return Err("Mock append failure".to_string());
+        }
+
+        self.fumens.borrow_mut().push(fumen.clone());
+        Ok(())
+    }
+}
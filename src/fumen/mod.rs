@@ -0,0 +1,13 @@
+mod file_fumen_repository;
+mod fumen_buffer;
+mod fumen_data;
+mod fumen_piece;
+mod fumen_repository;
+#[cfg(test)]
+mod mock_fumen_repository;
+
+pub use file_fumen_repository::FileFumenRepository;
+pub use fumen_data::Fumen;
+pub use fumen_repository::FumenRepository;
+#[cfg(test)]
+pub use mock_fumen_repository::MockFumenRepository;
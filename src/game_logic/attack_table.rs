@@ -0,0 +1,62 @@
+use crate::events::Event;
+
+/// Returns the number of garbage lines sent to the opponent for clearing nr_lines lines with a
+/// single tetromino.
+pub fn get_garbage_lines_for_lines_cleared(nr_lines: u32) -> u32 {
+    match nr_lines {
+        0 | 1 => 0,
+        2 => 1,
+        3 => 2,
+        _ => 4,
+    }
+}
+
+/// Returns the number of garbage lines sent to the opponent for the line clears among the events.
+pub fn get_garbage_lines_for_events(events: &[Event]) -> u32 {
+    events
+        .iter()
+        .map(|event| match event {
+            Event::LinesCleared(nr_lines) => get_garbage_lines_for_lines_cleared(*nr_lines),
+            _ => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 0)]
+    #[case(2, 1)]
+    #[case(3, 2)]
+    #[case(4, 4)]
+    fn get_garbage_lines_for_lines_cleared_follows_attack_table(
+        #[case] nr_lines: u32,
+        #[case] expected: u32,
+    ) {
+        // Act
+        let result = get_garbage_lines_for_lines_cleared(nr_lines);
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn get_garbage_lines_for_events_adds_up_line_clears() {
+        // Arrange
+        let events = [
+            Event::LinesCleared(4),
+            Event::PerfectClear,
+            Event::LinesCleared(2),
+        ];
+
+        // Act
+        let result = get_garbage_lines_for_events(&events);
+
+        // Assert
+        assert_eq!(result, 5);
+    }
+}
@@ -1,13 +1,26 @@
 use crate::events::{Event, EventQueue};
-use crate::game_logic::{GameState, LevelManager};
-use crate::game_logic::{Playfield, PlayfieldState};
-use crate::graphics::{Display, HudRenderer, HudView, PlayfieldRenderer};
+use crate::game_logic::{GameResult, GameRules, GameState, GameStatistics, LevelManager};
+use crate::game_logic::{Playfield, PlayfieldGrid, PlayfieldSnapshot, PlayfieldState};
+use crate::graphics::{Display, HudRenderer, HudView, PlayfieldRenderer, PlayfieldView};
 use crate::gui::GameInput;
 use crate::high_scores::HighScoreManager;
 use crate::tetromino::TetrominoGenerator;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// Everything needed to resume a single player game exactly where it was, except the tetromino
+/// generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameSnapshot {
+    pub playfield: PlayfieldSnapshot,
+    pub level: u32,
+    pub total_lines_cleared: u32,
+    pub score: u32,
+    pub tick: u64,
+    pub statistics: GameStatistics,
+    pub start_level: u32,
+}
+
 pub struct Game<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> {
     event_queue: Arc<EventQueue>,
     playfield: Playfield<T>,
@@ -16,6 +29,9 @@ pub struct Game<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> {
     game_state: GameState,
     level_manager: LevelManager,
     high_score_manager: HighScoreManager,
+    statistics: GameStatistics,
+    tick: u64,
+    start_level: u32,
 }
 
 impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> Game<R, H, T> {
@@ -36,6 +52,9 @@ impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> Game<R, H, T>
             game_state: GameState::Playing,
             level_manager,
             high_score_manager,
+            statistics: GameStatistics::new(),
+            tick: 0,
+            start_level: 0,
         }
     }
 
@@ -49,6 +68,16 @@ impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> Game<R, H, T>
         &mut self.playfield
     }
 
+    /// Replaces the blocks on the playfield, to start from a prepared board.
+    pub fn set_grid(&mut self, grid: PlayfieldGrid) {
+        self.playfield.set_grid(grid);
+    }
+
+    #[cfg(test)]
+    pub fn get_playfield_renderer(&self) -> &R {
+        &self.playfield_renderer
+    }
+
     pub fn get_game_state(&self) -> &GameState {
         &self.game_state
     }
@@ -63,11 +92,40 @@ impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> Game<R, H, T>
         true
     }
 
+    /// Set the inputs that are currently held down, see Playfield::set_held_inputs.
+    pub fn set_held_inputs(&mut self, held_inputs: &[GameInput]) {
+        self.set_player_held_inputs(0, held_inputs);
+    }
+
+    pub fn set_player_held_inputs(&mut self, player: usize, held_inputs: &[GameInput]) {
+        self.playfield.set_player_held_inputs(player, held_inputs);
+    }
+
+    /// Receive garbage lines from an opponent, see Playfield::add_pending_garbage.
+    pub fn receive_garbage(&mut self, nr_lines: u32, hole_column: u32) {
+        self.playfield.add_pending_garbage(nr_lines, hole_column);
+    }
+
+    /// Cancels pending garbage with lines sent to the opponent, see
+    /// Playfield::cancel_pending_garbage.
+    pub fn cancel_garbage(&mut self, nr_lines: u32) -> u32 {
+        self.playfield.cancel_pending_garbage(nr_lines)
+    }
+
     /// Handle game input, returns new game state.
     pub fn handle_input(&mut self, input: GameInput) -> GameState {
+        self.handle_player_input(0, input)
+    }
+
+    /// Handle game input of one of the players sharing the playfield, returns new game state.
+    pub fn handle_player_input(&mut self, player: usize, input: GameInput) -> GameState {
         match self.game_state {
             GameState::Playing => {
-                if self.playfield.handle_input(input) == PlayfieldState::GameOver {
+                if input != GameInput::StartGame {
+                    self.statistics.handle_input();
+                }
+                let playfield_state = self.playfield.handle_player_input(player, input);
+                if playfield_state == PlayfieldState::GameOver {
                     GameState::GameOver
                 } else {
                     GameState::Playing
@@ -95,34 +153,46 @@ impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> Game<R, H, T>
 
     pub fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
         display.clear()?;
+        self.draw_board(display)?;
+        display.present()?;
 
-        let playfield_view = self.playfield.get_view();
+        Ok(())
+    }
 
+    /// Draws the playfield and HUD without clearing or presenting the display, so several games
+    /// can share one display.
+    pub fn draw_board(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        let playfield_view = self.playfield.get_view();
         self.playfield_renderer.draw(&playfield_view, display)?;
 
         let hud_view = self.get_hud_view();
-        self.hud_renderer.draw(&hud_view, display)?;
-
-        display.present()?;
-
-        Ok(())
+        self.hud_renderer.draw(&hud_view, display)
     }
 
-    pub fn update(&mut self, delta_time: Duration) {
-        self.process_event_queue();
+    /// Advances the game by one tick. Returns the events that were handled, so the mode the game
+    /// is played in can react to them.
+    pub fn update(&mut self, delta_time: Duration) -> Vec<Event> {
+        let mut events = self.process_event_queue();
+        self.tick += 1;
 
         if let GameState::Playing = self.game_state {
+            self.statistics.update(delta_time);
             if self.playfield.update(delta_time) == PlayfieldState::GameOver {
                 self.game_state = GameState::GameOver;
+                // The events of the last tetromino, so the results of the game are final
+                events.extend(self.process_event_queue());
             }
         }
+        events
     }
 
-    fn process_event_queue(&mut self) {
+    /// Handles the events published since the last update right away, and returns them.
+    pub fn process_event_queue(&mut self) -> Vec<Event> {
         let events = self.event_queue.drain();
-        for event in events {
-            self.handle_event(event);
+        for event in &events {
+            self.handle_event(event.clone());
         }
+        events
     }
 
     #[cfg(test)]
@@ -130,7 +200,57 @@ impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> Game<R, H, T>
         self.game_state = GameState::GameOver;
     }
 
+    pub fn get_tetromino_generator(&self) -> &T {
+        self.playfield.get_tetromino_generator()
+    }
+
+    /// Returns the number of updates so far.
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Replaces the tetromino generator, to go back to an earlier point of the game together
+    /// with restore_snapshot.
+    pub fn set_tetromino_generator(&mut self, tetromino_generator: T) {
+        self.playfield.set_tetromino_generator(tetromino_generator);
+    }
+
+    pub fn get_snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            playfield: self.playfield.get_snapshot(),
+            level: self.level_manager.get_current_level(),
+            total_lines_cleared: self.level_manager.get_total_lines_cleared(),
+            score: self.level_manager.get_score(),
+            tick: self.tick,
+            statistics: self.statistics.clone(),
+            start_level: self.start_level,
+        }
+    }
+
+    /// Continues the game from the snapshot. The playfield must have been created with the
+    /// tetromino generator state that was saved with the snapshot. Events that weren't handled
+    /// yet are dropped, they belong to the state that is left.
+    pub fn restore_snapshot(&mut self, snapshot: GameSnapshot) {
+        self.event_queue.drain();
+        self.level_manager
+            .restore(snapshot.level, snapshot.total_lines_cleared, snapshot.score);
+        self.playfield.restore_snapshot(snapshot.playfield);
+        self.tick = snapshot.tick;
+        self.statistics = snapshot.statistics;
+        self.start_level = snapshot.start_level;
+        self.game_state = GameState::Playing;
+    }
+
+    pub fn get_rules(&self) -> GameRules {
+        self.playfield.get_rules()
+    }
+
+    pub fn set_rules(&mut self, rules: GameRules) {
+        self.playfield.set_rules(rules);
+    }
+
     pub fn start_level(&mut self, level: u32) {
+        self.start_level = level;
         self.level_manager.start_level(level);
         self.playfield.start_level(level);
     }
@@ -139,29 +259,76 @@ impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> Game<R, H, T>
         match event {
             Event::LinesCleared(nr_lines) => {
                 self.level_manager.handle_lines_cleared(nr_lines);
+                self.statistics.handle_lines_cleared(nr_lines);
             }
             Event::LevelStarted(level) => {
                 self.playfield.start_level(level);
             }
+            Event::TSpin(_) | Event::PerfectClear => {}
+            Event::FinesseFault(_) => {
+                self.statistics.handle_finesse_fault();
+            }
+            Event::TetrominoSpawned(tetromino_type) => {
+                self.statistics.handle_tetromino_spawned(tetromino_type);
+            }
+            Event::TetrominoLocked(_) => {
+                self.statistics.handle_tetromino_locked();
+            }
         }
     }
 
-    fn get_hud_view(&self) -> crate::graphics::HudView {
+    pub fn get_score(&self) -> u32 {
+        self.level_manager.get_score()
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.level_manager.get_current_level()
+    }
+
+    pub fn get_statistics(&self) -> &GameStatistics {
+        &self.statistics
+    }
+
+    pub fn get_total_lines_cleared(&self) -> u32 {
+        self.level_manager.get_total_lines_cleared()
+    }
+
+    pub fn get_nr_locked_tetrominoes(&self) -> u32 {
+        self.playfield.get_nr_locked_tetrominoes()
+    }
+
+    pub fn get_playfield_view(&self) -> PlayfieldView<'_> {
+        self.playfield.get_view()
+    }
+
+    pub fn get_hud_view(&self) -> HudView {
         let playfield_view = self.playfield.get_view();
         HudView {
             next_tetromino_type: playfield_view.next_tetromino_type,
+            hold_tetromino_type: self.playfield.get_hold_tetromino_type(),
             current_level: self.level_manager.get_current_level(),
             total_lines_cleared: self.level_manager.get_total_lines_cleared(),
             score: self.level_manager.get_score(),
+            statistics: self.statistics.clone(),
             show_game_over: self.game_state == GameState::GameOver,
         }
     }
 
+    pub fn get_game_result(&self) -> GameResult {
+        GameResult {
+            level: self.level_manager.get_current_level(),
+            score: self.level_manager.get_score(),
+            time: self.statistics.play_time,
+            lines: self.level_manager.get_total_lines_cleared(),
+            start_level: self.start_level,
+        }
+    }
+
     pub fn is_current_score_high_score(&self) -> bool {
-        let current_score = self.level_manager.get_score();
+        let game_result = self.get_game_result();
 
-        if current_score > 0 {
-            self.high_score_manager.is_high_score(current_score)
+        if game_result.score > 0 {
+            self.high_score_manager.is_high_score(&game_result)
         } else {
             false
         }
@@ -173,7 +340,7 @@ mod tests {
     use super::*;
     use crate::common::{Dimensions, Position};
     use crate::constants::*;
-    use crate::game_logic::GameResult;
+    use crate::game_logic::GameRules;
     use crate::graphics::{MockDisplay, MockHudRenderer, MockPlayfieldRenderer};
     use crate::gui::GameInput;
     use crate::high_scores::{HighScore, HighScores, MockHighScoresRepository};
@@ -519,6 +686,60 @@ mod tests {
         assert_eq!(sut.playfield.get_gravity_timer().get_level(), 3);
     }
 
+    #[test]
+    fn update_returns_handled_events() {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let playfield = create_test_playfield_with_event_queue(event_queue.clone());
+        let mut sut = Game::new(
+            playfield,
+            MockPlayfieldRenderer::new(),
+            MockHudRenderer::new(),
+            event_queue.clone(),
+            create_test_high_score_manager(),
+        );
+        event_queue.push_back(Event::LinesCleared(4));
+        event_queue.push_back(Event::PerfectClear);
+
+        // Act
+        let result = sut.update(Duration::from_millis(1));
+
+        // Assert
+        assert_eq!(result, vec![Event::LinesCleared(4), Event::PerfectClear]);
+        assert!(sut.update(Duration::from_millis(1)).is_empty());
+    }
+
+    #[test]
+    fn draw_board_does_not_clear_or_present_display() {
+        // Arrange
+        let mut sut = create_standard_test_game();
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw_board(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(!display.cleared);
+        assert!(!display.presented);
+        assert_eq!(sut.playfield_renderer.get_draw_calls().len(), 1);
+        assert_eq!(sut.hud_renderer.get_draw_calls().len(), 1);
+    }
+
+    #[test]
+    fn cancel_garbage_cancels_received_garbage() {
+        // Arrange
+        let mut sut = create_standard_test_game();
+        sut.receive_garbage(3, 7);
+
+        // Act
+        let result = sut.cancel_garbage(4);
+
+        // Assert
+        assert_eq!(result, 1);
+        assert!(sut.playfield.get_pending_garbage().is_empty());
+    }
+
     #[test]
     fn draw_calls_hud_renderer() {
         // Arrange
@@ -546,6 +767,29 @@ mod tests {
         assert_eq!(sut.get_playfield().get_gravity_timer().get_level(), 5);
     }
 
+    #[test]
+    fn get_game_result_returns_progress_and_start_level() {
+        // Arrange
+        let mut sut = create_standard_test_game();
+        sut.start_level(2);
+        sut.level_manager.handle_lines_cleared(4);
+
+        // Act
+        let result = sut.get_game_result();
+
+        // Assert
+        assert_eq!(
+            result,
+            GameResult {
+                level: 2,
+                score: 3600,
+                time: Duration::ZERO,
+                lines: 4,
+                start_level: 2,
+            }
+        );
+    }
+
     #[test]
     fn is_current_score_high_score_returns_true_for_qualifying_score() {
         // Arrange
@@ -588,7 +832,61 @@ mod tests {
 
         // Assert
         assert_eq!(sut.game_state, GameState::GameOver);
-        assert_eq!(sut.high_score_manager.get_high_scores().len(), 10);
+        assert_eq!(sut.high_score_manager.get_high_scores().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn update_counts_tetrominoes_with_finesse_faults() {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let mut sut = Game::new(
+            create_test_playfield_with_event_queue(event_queue.clone()),
+            MockPlayfieldRenderer::new(),
+            MockHudRenderer::new(),
+            event_queue,
+            create_test_high_score_manager(),
+        );
+        sut.spawn_tetromino();
+        for _ in 0..2 {
+            sut.handle_input(GameInput::MoveLeft);
+            sut.handle_input(GameInput::MoveRight);
+            sut.handle_input(GameInput::Drop);
+        }
+
+        // Act
+        sut.update(Duration::ZERO);
+
+        // Assert
+        assert_eq!(sut.get_statistics().nr_finesse_faults, 2);
+        assert_eq!(sut.get_hud_view().statistics.nr_finesse_faults, 2);
+    }
+
+    #[test]
+    fn update_collects_statistics_of_spawned_and_locked_tetrominoes() {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let mut sut = Game::new(
+            create_test_playfield_with_event_queue(event_queue.clone()),
+            MockPlayfieldRenderer::new(),
+            MockHudRenderer::new(),
+            event_queue,
+            create_test_high_score_manager(),
+        );
+        sut.set_rules(GameRules::without_delays());
+        sut.spawn_tetromino();
+        sut.handle_input(GameInput::MoveLeft);
+        sut.handle_input(GameInput::Drop);
+        sut.handle_input(GameInput::Drop);
+
+        // Act
+        sut.update(Duration::from_millis(500));
+
+        // Assert
+        let statistics = sut.get_hud_view().statistics;
+        assert_eq!(statistics.nr_pieces, 2);
+        assert_eq!(statistics.nr_inputs, 3);
+        assert_eq!(statistics.get_nr_spawned(TetrominoType::O), 3);
+        assert_eq!(statistics.play_time, Duration::from_millis(500));
     }
 
     fn create_game_with_empty_high_scores() -> TestGame {
@@ -612,6 +910,7 @@ mod tests {
                 GameResult {
                     score: i * 1000,
                     level: 1,
+                    ..Default::default()
                 },
             ));
         }
@@ -0,0 +1,39 @@
+/// The kinds of games that are kept apart in the lifetime statistics, stored by their lowercase
+/// name.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    strum::EnumString,
+    strum::Display,
+    strum::EnumIter,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum GameMode {
+    Marathon,
+    Coop,
+    Puzzle,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn from_str_returns_mode_of_to_string() {
+        for game_mode in GameMode::iter() {
+            // Act
+            let result = GameMode::from_str(&game_mode.to_string());
+
+            // Assert
+            assert_eq!(result, Ok(game_mode));
+        }
+    }
+}
@@ -1,5 +1,12 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct GameResult {
     pub level: u32,
     pub score: u32,
+    /// How long the game was played, timed modes rank on it.
+    pub time: Duration,
+    pub lines: u32,
+    /// The level the game was started at.
+    pub start_level: u32,
 }
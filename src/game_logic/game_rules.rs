@@ -0,0 +1,65 @@
+use crate::constants::*;
+use std::time::Duration;
+
+/// Rule parameters that can differ between game modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GameRules {
+    /// Delay between locking a tetromino and spawning the next one (ARE).
+    pub entry_delay: Duration,
+    /// Delay between completing lines and removing them, during which they blink.
+    pub line_clear_delay: Duration,
+}
+
+impl GameRules {
+    pub fn new(entry_delay: Duration, line_clear_delay: Duration) -> Self {
+        Self {
+            entry_delay,
+            line_clear_delay,
+        }
+    }
+
+    /// Rules without any entry or line clear delay, as used in competitive play.
+    pub fn without_delays() -> Self {
+        Self::new(Duration::ZERO, Duration::ZERO)
+    }
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self::new(
+            Duration::from_millis(DEFAULT_ENTRY_DELAY_MS),
+            Duration::from_millis(DEFAULT_LINE_CLEAR_DELAY_MS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_uses_default_delays() {
+        // Act
+        let sut = GameRules::default();
+
+        // Assert
+        assert_eq!(
+            sut.entry_delay,
+            Duration::from_millis(DEFAULT_ENTRY_DELAY_MS)
+        );
+        assert_eq!(
+            sut.line_clear_delay,
+            Duration::from_millis(DEFAULT_LINE_CLEAR_DELAY_MS)
+        );
+    }
+
+    #[test]
+    fn without_delays_has_zero_delays() {
+        // Act
+        let sut = GameRules::without_delays();
+
+        // Assert
+        assert_eq!(sut.entry_delay, Duration::ZERO);
+        assert_eq!(sut.line_clear_delay, Duration::ZERO);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::tetromino::TetrominoType;
+use std::time::Duration;
+
+const NR_TETROMINO_TYPES: usize = 7;
+
+/// The statistics of the current game, collected from the events of the playfield and the inputs
+/// of the players.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameStatistics {
+    pub play_time: Duration,
+    pub nr_pieces: u32,
+    pub nr_inputs: u32,
+    pub nr_line_clears: [u32; 4], // Singles, doubles, triples and tetrises
+    pub nr_spawned: [u32; NR_TETROMINO_TYPES], // Indexed by TetrominoType
+    /// Line clears by consecutive pieces so far, and the longest such combo in the game.
+    pub combo: u32,
+    pub max_combo: u32,
+    /// The number of the last piece that cleared lines, to know whether the combo continues.
+    pub last_clearing_piece: u32,
+    /// Tetrises without other line clears in between so far, and the longest such streak.
+    pub tetris_streak: u32,
+    pub max_tetris_streak: u32,
+    /// Pieces placed with more inputs than needed.
+    pub nr_finesse_faults: u32,
+}
+
+impl GameStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        self.play_time += delta_time;
+    }
+
+    pub fn handle_input(&mut self) {
+        self.nr_inputs += 1;
+    }
+
+    pub fn handle_tetromino_spawned(&mut self, tetromino_type: TetrominoType) {
+        self.nr_spawned[tetromino_type as usize] += 1;
+    }
+
+    pub fn handle_tetromino_locked(&mut self) {
+        self.nr_pieces += 1;
+    }
+
+    pub fn handle_finesse_fault(&mut self) {
+        self.nr_finesse_faults += 1;
+    }
+
+    /// Handles the lines cleared by the last locked piece.
+    pub fn handle_lines_cleared(&mut self, nr_lines: u32) {
+        if !(1..=4).contains(&nr_lines) {
+            return;
+        }
+
+        self.nr_line_clears[nr_lines as usize - 1] += 1;
+
+        let is_combo = self.combo > 0 && self.last_clearing_piece + 1 == self.nr_pieces;
+        self.combo = if is_combo { self.combo + 1 } else { 1 };
+        self.max_combo = self.max_combo.max(self.combo);
+        self.last_clearing_piece = self.nr_pieces;
+
+        self.tetris_streak = if nr_lines == 4 {
+            self.tetris_streak + 1
+        } else {
+            0
+        };
+        self.max_tetris_streak = self.max_tetris_streak.max(self.tetris_streak);
+    }
+
+    pub fn get_pieces_per_second(&self) -> f32 {
+        let seconds = self.play_time.as_secs_f32();
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        self.nr_pieces as f32 / seconds
+    }
+
+    pub fn get_inputs_per_piece(&self) -> f32 {
+        if self.nr_pieces == 0 {
+            return 0.0;
+        }
+        self.nr_inputs as f32 / self.nr_pieces as f32
+    }
+
+    /// Returns the number of times exactly nr_lines lines were cleared at once, 1 to 4.
+    pub fn get_nr_line_clears(&self, nr_lines: u32) -> u32 {
+        self.nr_line_clears[nr_lines as usize - 1]
+    }
+
+    pub fn get_nr_lines_cleared(&self) -> u32 {
+        (1..=4)
+            .map(|nr_lines| nr_lines * self.get_nr_line_clears(nr_lines))
+            .sum()
+    }
+
+    /// Returns the part of the cleared lines that were cleared by tetrises, from 0 to 1.
+    pub fn get_tetris_rate(&self) -> f32 {
+        let total_lines = self.get_nr_lines_cleared();
+        if total_lines == 0 {
+            return 0.0;
+        }
+        (4 * self.get_nr_line_clears(4)) as f32 / total_lines as f32
+    }
+
+    pub fn get_nr_spawned(&self, tetromino_type: TetrominoType) -> u32 {
+        self.nr_spawned[tetromino_type as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn get_pieces_per_second_divides_pieces_by_play_time() {
+        // Arrange
+        let mut sut = GameStatistics::new();
+        for _ in 0..5 {
+            sut.handle_tetromino_locked();
+        }
+        sut.update(Duration::from_secs(2));
+
+        // Act
+        let result = sut.get_pieces_per_second();
+
+        // Assert
+        assert_eq!(result, 2.5);
+    }
+
+    #[test]
+    fn get_pieces_per_second_without_play_time_returns_zero() {
+        // Arrange
+        let mut sut = GameStatistics::new();
+        sut.handle_tetromino_locked();
+
+        // Act
+        let result = sut.get_pieces_per_second();
+
+        // Assert
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn get_inputs_per_piece_divides_inputs_by_pieces() {
+        // Arrange
+        let mut sut = GameStatistics::new();
+        for _ in 0..9 {
+            sut.handle_input();
+        }
+        sut.handle_tetromino_locked();
+        sut.handle_tetromino_locked();
+
+        // Act
+        let result = sut.get_inputs_per_piece();
+
+        // Assert
+        assert_eq!(result, 4.5);
+    }
+
+    #[rstest]
+    #[case(&[], 0.0)]
+    #[case(&[1, 1, 2], 0.0)]
+    #[case(&[4, 2, 1, 1], 0.5)]
+    #[case(&[4, 4], 1.0)]
+    fn get_tetris_rate_returns_part_of_lines_cleared_by_tetrises(
+        #[case] line_clears: &[u32],
+        #[case] expected: f32,
+    ) {
+        // Arrange
+        let mut sut = GameStatistics::new();
+        for nr_lines in line_clears {
+            sut.handle_lines_cleared(*nr_lines);
+        }
+
+        // Act
+        let result = sut.get_tetris_rate();
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn handle_lines_cleared_counts_each_kind_of_clear() {
+        // Arrange
+        let mut sut = GameStatistics::new();
+
+        // Act
+        for nr_lines in [1, 2, 2, 3, 3, 3, 4] {
+            sut.handle_lines_cleared(nr_lines);
+        }
+
+        // Assert
+        let result: Vec<u32> = (1..=4).map(|n| sut.get_nr_line_clears(n)).collect();
+        assert_eq!(result, vec![1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn handle_lines_cleared_by_consecutive_pieces_counts_combo() {
+        // Arrange
+        let mut sut = GameStatistics::new();
+
+        // Act
+        for nr_lines in [1, 2, 0, 1, 1, 1, 0] {
+            sut.handle_tetromino_locked();
+            sut.handle_lines_cleared(nr_lines);
+        }
+
+        // Assert
+        assert_eq!(sut.max_combo, 3);
+    }
+
+    #[test]
+    fn handle_lines_cleared_counts_tetrises_without_other_clears_in_between() {
+        // Arrange
+        let mut sut = GameStatistics::new();
+
+        // Act
+        for nr_lines in [4, 0, 4, 4, 1, 4] {
+            sut.handle_tetromino_locked();
+            sut.handle_lines_cleared(nr_lines);
+        }
+
+        // Assert
+        assert_eq!(sut.max_tetris_streak, 3);
+        assert_eq!(sut.tetris_streak, 1);
+    }
+
+    #[test]
+    fn handle_tetromino_spawned_counts_per_type() {
+        // Arrange
+        let mut sut = GameStatistics::new();
+
+        // Act
+        sut.handle_tetromino_spawned(TetrominoType::T);
+        sut.handle_tetromino_spawned(TetrominoType::L);
+        sut.handle_tetromino_spawned(TetrominoType::T);
+
+        // Assert
+        assert_eq!(sut.get_nr_spawned(TetrominoType::T), 2);
+        assert_eq!(sut.get_nr_spawned(TetrominoType::L), 1);
+        assert_eq!(sut.get_nr_spawned(TetrominoType::I), 0);
+    }
+}
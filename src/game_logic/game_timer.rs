@@ -19,6 +19,12 @@ impl GameTimer {
     }
 }
 
+impl Default for GameTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
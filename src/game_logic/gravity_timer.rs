@@ -40,11 +40,18 @@ impl GravityTimer {
         self.level = Self::cap_level(level);
     }
 
-    #[cfg(test)]
     pub fn get_level(&self) -> u32 {
         self.level
     }
 
+    pub fn get_time_since_last_drop(&self) -> Duration {
+        self.time_since_last_drop
+    }
+
+    pub fn set_time_since_last_drop(&mut self, time_since_last_drop: Duration) {
+        self.time_since_last_drop = time_since_last_drop;
+    }
+
     pub fn reset(&mut self) {
         self.time_since_last_drop = Duration::ZERO;
     }
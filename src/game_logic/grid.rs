@@ -1,5 +1,12 @@
 use crate::common::{Dimensions, Position};
-use crate::tetromino::TetrominoType;
+use crate::tetromino::{TetrominoInstance, TetrominoType};
+
+/// The character of an empty cell in the text rows of a grid, see PlayfieldGrid::from_rows.
+pub const EMPTY_CELL_CHAR: char = '.';
+
+/// There is no separate texture for garbage blocks, so garbage lines are drawn with the blocks of
+/// one of the tetrominoes.
+pub const GARBAGE_TETROMINO_TYPE: TetrominoType = TetrominoType::O;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PlayfieldGrid {
@@ -14,6 +21,41 @@ impl PlayfieldGrid {
         Self { dimensions, cells }
     }
 
+    /// Parses a grid from text rows, top row first, with a '.' for an empty cell and the letter
+    /// of the tetromino type for a block.
+    pub fn from_rows(rows: &[&str]) -> Result<Self, String> {
+        let width = rows.first().ok_or("Grid has no rows")?.chars().count();
+        let mut grid = Self::new(Dimensions::new(width as u32, rows.len() as u32));
+        for (y, row) in rows.iter().enumerate() {
+            if row.chars().count() != width {
+                return Err(format!("Grid row {y} doesn't have {width} cells: '{row}'"));
+            }
+            for (x, cell) in row.chars().enumerate() {
+                let value = match cell {
+                    EMPTY_CELL_CHAR => None,
+                    _ => Some(
+                        TetrominoType::from_char(cell)
+                            .ok_or(format!("Invalid grid cell '{cell}' in row {y}"))?,
+                    ),
+                };
+                grid.set(Position::new(x as i32, y as i32), value);
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Returns the grid as text rows, see from_rows.
+    pub fn to_rows(&self) -> Vec<String> {
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.map_or(EMPTY_CELL_CHAR, TetrominoType::to_char))
+                    .collect()
+            })
+            .collect()
+    }
+
     pub fn get_dimensions(&self) -> Dimensions {
         self.dimensions
     }
@@ -51,6 +93,13 @@ impl PlayfieldGrid {
         self.cells[y as usize][x as usize].is_some()
     }
 
+    /// Returns true when all blocks of the tetromino are inside the grid on empty cells.
+    pub fn can_place_tetromino(&self, tetromino: &TetrominoInstance) -> bool {
+        tetromino.get_world_blocks().iter().all(|position| {
+            self.dimensions.contains(*position) && !self.is_position_occupied(*position)
+        })
+    }
+
     pub fn clear(&mut self) {
         for row in &mut self.cells {
             for cell in row {
@@ -69,6 +118,14 @@ impl PlayfieldGrid {
         (0..self.dimensions.width).all(|x| self.is_xy_occupied(x as i32, y as i32))
     }
 
+    /// Returns true when all blocks are in the given lines, so removing them leaves the grid
+    /// empty.
+    pub fn is_empty_without_lines(&self, lines: &[u32]) -> bool {
+        (0..self.dimensions.height)
+            .filter(|y| !lines.contains(y))
+            .all(|y| (0..self.dimensions.width).all(|x| !self.is_xy_occupied(x as i32, y as i32)))
+    }
+
     pub fn remove_lines(&mut self, lines: &[u32]) {
         // Remove lines from bottom to top to avoid index shifting issues
         let mut sorted_lines = lines.to_vec();
@@ -89,11 +146,31 @@ impl PlayfieldGrid {
         let empty_line = vec![None; self.dimensions.width as usize];
         self.cells.insert(0, empty_line);
     }
+
+    /// Pushes all blocks up and fills the bottom lines with garbage, leaving one hole per line
+    /// at hole_column. Returns false when occupied blocks were pushed out of the top.
+    pub fn add_garbage_lines(&mut self, nr_lines: u32, hole_column: u32) -> bool {
+        let mut fits = true;
+        for _ in 0..nr_lines.min(self.dimensions.height) {
+            let top_line = self.cells.remove(0);
+            if top_line.iter().any(|cell| cell.is_some()) {
+                fits = false;
+            }
+
+            let garbage_line = (0..self.dimensions.width)
+                .map(|x| (x != hole_column).then_some(GARBAGE_TETROMINO_TYPE))
+                .collect();
+            self.cells.push(garbage_line);
+        }
+        fits
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::create_grid_from_rows;
+    use rstest::rstest;
 
     #[test]
     fn new_creates_empty_grid_with_correct_dimensions() {
@@ -256,4 +333,83 @@ mod tests {
         assert_eq!(sut.get(Position::new(0, 3)), Some(&TetrominoType::S));
         assert_eq!(sut.get(Position::new(0, 4)), Some(&TetrominoType::I));
     }
+
+    #[test]
+    fn add_garbage_lines_pushes_blocks_up_and_leaves_hole() {
+        // Arrange
+        let mut sut = PlayfieldGrid::new(Dimensions::new(3, 4));
+        sut.set(Position::new(1, 3), Some(TetrominoType::T));
+
+        // Act
+        let result = sut.add_garbage_lines(2, 2);
+
+        // Assert
+        assert!(result);
+        assert_eq!(sut.get(Position::new(1, 1)), Some(&TetrominoType::T));
+        for y in 2..4 {
+            assert_eq!(sut.get(Position::new(0, y)), Some(&GARBAGE_TETROMINO_TYPE));
+            assert_eq!(sut.get(Position::new(1, y)), Some(&GARBAGE_TETROMINO_TYPE));
+            assert_eq!(sut.get(Position::new(2, y)), None);
+        }
+    }
+
+    #[test]
+    fn add_garbage_lines_returns_false_when_blocks_are_pushed_out_of_the_top() {
+        // Arrange
+        let mut sut = PlayfieldGrid::new(Dimensions::new(3, 4));
+        sut.set(Position::new(0, 1), Some(TetrominoType::T));
+
+        // Act
+        let result = sut.add_garbage_lines(2, 0);
+
+        // Assert
+        assert!(!result);
+    }
+
+    #[test]
+    fn to_rows_and_from_rows_round_trip() {
+        // Arrange
+        let mut sut = PlayfieldGrid::new(Dimensions::new(3, 2));
+        sut.set(Position::new(0, 1), Some(TetrominoType::T));
+        sut.set(Position::new(2, 1), Some(TetrominoType::L));
+
+        // Act
+        let rows = sut.to_rows();
+        let result = PlayfieldGrid::from_rows(&rows.iter().map(String::as_str).collect::<Vec<_>>());
+
+        // Assert
+        assert_eq!(rows, vec!["...", "T.L"]);
+        assert_eq!(result, Ok(sut));
+    }
+
+    #[rstest]
+    #[case(&["...", "##.", "###"], &[2], false)]
+    #[case(&["...", "###", "###"], &[1, 2], true)]
+    #[case(&["...", "...", "..."], &[], true)]
+    fn is_empty_without_lines_ignores_given_lines(
+        #[case] rows: &[&str],
+        #[case] lines: &[u32],
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let sut = create_grid_from_rows(rows);
+
+        // Act
+        let result = sut.is_empty_without_lines(lines);
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(&["...", ".."], "Grid row 1 doesn't have 3 cells: '..'")]
+    #[case(&["..X"], "Invalid grid cell 'X' in row 0")]
+    #[case(&[], "Grid has no rows")]
+    fn from_rows_rejects_invalid_rows(#[case] rows: &[&str], #[case] expected_error: &str) {
+        // Act
+        let result = PlayfieldGrid::from_rows(rows);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
 }
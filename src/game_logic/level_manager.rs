@@ -45,6 +45,13 @@ impl LevelManager {
         self.total_lines_cleared
     }
 
+    /// Continues a game with the given progress, without announcing a level start.
+    pub fn restore(&mut self, level: u32, total_lines_cleared: u32, score: u32) {
+        self.current_level = level;
+        self.total_lines_cleared = total_lines_cleared;
+        self.score = score;
+    }
+
     pub fn start_level(&mut self, level: u32) {
         self.current_level = level;
         self.event_bus.push_back(Event::LevelStarted(level));
@@ -1,17 +1,31 @@
+mod attack_table;
 mod game;
+mod game_mode;
 mod game_result;
+mod game_rules;
 mod game_state;
+mod game_statistics;
 mod game_timer;
 mod gravity_timer;
 mod grid;
 mod level_manager;
+mod placement_finder;
 mod playfield;
+mod practice_history;
+mod versus_match;
 
+pub use attack_table::*;
 pub use game::*;
+pub use game_mode::GameMode;
 pub use game_result::GameResult;
+pub use game_rules::GameRules;
 pub use game_state::*;
+pub use game_statistics::GameStatistics;
 pub use game_timer::*;
 pub use gravity_timer::*;
-pub use grid::PlayfieldGrid;
+pub use grid::{PlayfieldGrid, EMPTY_CELL_CHAR, GARBAGE_TETROMINO_TYPE};
 pub use level_manager::*;
+pub use placement_finder::*;
 pub use playfield::*;
+pub use practice_history::PracticeHistory;
+pub use versus_match::*;
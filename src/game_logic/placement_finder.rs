@@ -0,0 +1,402 @@
+use crate::common::Position;
+use crate::constants::{TETRIS_SPAWN_X, TETRIS_SPAWN_Y};
+use crate::game_logic::PlayfieldGrid;
+use crate::gui::GameInput;
+use crate::tetromino::{TetrominoDefinitions, TetrominoInstance, TetrominoType};
+use std::collections::{HashSet, VecDeque};
+
+/// The inputs that can move a tetromino without locking it.
+const MOVE_INPUTS: [GameInput; 6] = [
+    GameInput::MoveLeft,
+    GameInput::MoveRight,
+    GameInput::MoveDown,
+    GameInput::RotateClockwise,
+    GameInput::RotateCounterclockwise,
+    GameInput::Rotate180,
+];
+
+/// The inputs finesse is about. Moving down and dropping are left out, since gravity moves a
+/// tetromino down as well.
+pub const FINESSE_INPUTS: [GameInput; 5] = [
+    GameInput::MoveLeft,
+    GameInput::MoveRight,
+    GameInput::RotateClockwise,
+    GameInput::RotateCounterclockwise,
+    GameInput::Rotate180,
+];
+
+/// A position where a tetromino can lock, together with the inputs that bring it there from its
+/// start position. The inputs always end with a drop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub tetromino: TetrominoInstance,
+    pub inputs: Vec<GameInput>,
+}
+
+/// Returns every placement that a tetromino of the given type, spawning at the standard spawn
+/// position, can reach on the grid.
+pub fn find_placements(grid: &PlayfieldGrid, tetromino_type: TetrominoType) -> Vec<Placement> {
+    let spawn_position = Position::new(TETRIS_SPAWN_X, TETRIS_SPAWN_Y);
+    let tetromino =
+        TetrominoInstance::new(tetromino_type, spawn_position, &TetrominoDefinitions::new());
+    find_placements_from(grid, &tetromino)
+}
+
+/// Returns every placement the tetromino can reach on the grid, using the same moves and
+/// rotations as the playfield, so placements that need a tuck or a spin are found too. Each
+/// placement has the shortest input sequence that reaches it. Returns no placements when the
+/// tetromino itself doesn't fit.
+pub fn find_placements_from(grid: &PlayfieldGrid, tetromino: &TetrominoInstance) -> Vec<Placement> {
+    let mut placements = Vec::new();
+    if !grid.can_place_tetromino(tetromino) {
+        return placements;
+    }
+
+    let mut visited_states = HashSet::from([get_state(tetromino)]);
+    let mut found_placements = HashSet::new();
+    let mut queue = VecDeque::from([(tetromino.clone(), Vec::new())]);
+
+    // Breadth-first, so the first input sequence that reaches a placement is the shortest.
+    while let Some((current_tetromino, inputs)) = queue.pop_front() {
+        let dropped_tetromino = drop_tetromino(grid, &current_tetromino);
+        if found_placements.insert(get_sorted_blocks(&dropped_tetromino)) {
+            let mut placement_inputs = inputs.clone();
+            placement_inputs.push(GameInput::Drop);
+            placements.push(Placement {
+                tetromino: dropped_tetromino,
+                inputs: placement_inputs,
+            });
+        }
+
+        for input in MOVE_INPUTS {
+            let Some(moved_tetromino) = try_move(grid, &current_tetromino, input) else {
+                continue;
+            };
+            if visited_states.insert(get_state(&moved_tetromino)) {
+                let mut moved_inputs = inputs.clone();
+                moved_inputs.push(input);
+                queue.push_back((moved_tetromino, moved_inputs));
+            }
+        }
+    }
+
+    placements
+}
+
+/// Returns the fewest finesse inputs that bring the tetromino to where it drops onto the given
+/// blocks. Returns None when no drop reaches them, like for tucks and spins.
+pub fn find_min_nr_finesse_inputs(
+    grid: &PlayfieldGrid,
+    tetromino: &TetrominoInstance,
+    blocks: &[Position],
+) -> Option<u32> {
+    let mut target_blocks = blocks.to_vec();
+    target_blocks.sort_by_key(|position| (position.y, position.x));
+
+    let mut visited_states = HashSet::from([get_state(tetromino)]);
+    let mut queue = VecDeque::from([(tetromino.clone(), 0)]);
+    while let Some((current_tetromino, nr_inputs)) = queue.pop_front() {
+        if get_sorted_blocks(&drop_tetromino(grid, &current_tetromino)) == target_blocks {
+            return Some(nr_inputs);
+        }
+
+        for input in FINESSE_INPUTS {
+            let Some(moved_tetromino) = try_move(grid, &current_tetromino, input) else {
+                continue;
+            };
+            if visited_states.insert(get_state(&moved_tetromino)) {
+                queue.push_back((moved_tetromino, nr_inputs + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Applies a move input like Playfield::handle_input does. Returns None when it is blocked.
+fn try_move(
+    grid: &PlayfieldGrid,
+    tetromino: &TetrominoInstance,
+    input: GameInput,
+) -> Option<TetrominoInstance> {
+    let mut moved_tetromino = tetromino.clone();
+    match input {
+        GameInput::MoveLeft => moved_tetromino.move_left(),
+        GameInput::MoveRight => moved_tetromino.move_right(),
+        GameInput::MoveDown => moved_tetromino.move_down(),
+        GameInput::RotateClockwise => moved_tetromino.rotate_clockwise(),
+        GameInput::RotateCounterclockwise => moved_tetromino.rotate_counterclockwise(),
+        GameInput::Rotate180 => moved_tetromino.rotate_180(),
+        _ => return None,
+    }
+
+    grid.can_place_tetromino(&moved_tetromino)
+        .then_some(moved_tetromino)
+}
+
+fn drop_tetromino(grid: &PlayfieldGrid, tetromino: &TetrominoInstance) -> TetrominoInstance {
+    let mut dropped_tetromino = tetromino.clone();
+    while let Some(moved_tetromino) = try_move(grid, &dropped_tetromino, GameInput::MoveDown) {
+        dropped_tetromino = moved_tetromino;
+    }
+    dropped_tetromino
+}
+
+fn get_state(tetromino: &TetrominoInstance) -> (Position, usize) {
+    (
+        tetromino.get_position(),
+        tetromino.get_rotation_index().into(),
+    )
+}
+
+/// Different rotation states can cover the same cells, those are the same placement.
+fn get_sorted_blocks(tetromino: &TetrominoInstance) -> Vec<Position> {
+    let mut blocks = tetromino.get_world_blocks();
+    blocks.sort_by_key(|position| (position.y, position.x));
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{PLAYFIELD_HEIGHT, PLAYFIELD_WIDTH};
+    use crate::test_helpers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(TetrominoType::O, 9)]
+    #[case(TetrominoType::I, 17)]
+    #[case(TetrominoType::T, 34)]
+    fn find_placements_on_empty_grid_finds_every_column_and_rotation(
+        #[case] tetromino_type: TetrominoType,
+        #[case] expected_nr_placements: usize,
+    ) {
+        // Arrange
+        let grid = create_empty_grid();
+
+        // Act
+        let result = find_placements(&grid, tetromino_type);
+
+        // Assert
+        assert_eq!(result.len(), expected_nr_placements);
+    }
+
+    #[test]
+    fn find_placements_uses_shortest_input_sequence() {
+        // Arrange
+        let grid = create_empty_grid();
+
+        // Act
+        let result = find_placements(&grid, TetrominoType::O);
+
+        // Assert
+        let straight_drop = result
+            .iter()
+            .find(|placement| placement.tetromino.get_position().x == TETRIS_SPAWN_X)
+            .unwrap();
+        assert_eq!(straight_drop.inputs, vec![GameInput::Drop]);
+    }
+
+    #[test]
+    fn find_placements_finds_tuck_under_overhang() {
+        // Arrange
+        let grid = create_grid_with_bottom_rows(&["####......", "..........", ".........."]);
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        let expected_blocks = vec![
+            Position::new(0, bottom_y - 1),
+            Position::new(1, bottom_y - 1),
+            Position::new(0, bottom_y),
+            Position::new(1, bottom_y),
+        ];
+
+        // Act
+        let result = find_placements(&grid, TetrominoType::O);
+
+        // Assert
+        let tuck = find_placement_with_blocks(&result, &expected_blocks);
+        assert!(tuck.inputs.contains(&GameInput::MoveDown));
+        assert_placement_reached_by_playfield(&grid, TetrominoType::O, tuck);
+    }
+
+    #[test]
+    fn find_placements_finds_t_spin_into_covered_slot() {
+        // Arrange
+        let grid = create_grid_with_bottom_rows(&["####......", "###...####", "####.#####"]);
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        let expected_blocks = vec![
+            Position::new(3, bottom_y - 1),
+            Position::new(4, bottom_y - 1),
+            Position::new(5, bottom_y - 1),
+            Position::new(4, bottom_y),
+        ];
+
+        // Act
+        let result = find_placements(&grid, TetrominoType::T);
+
+        // Assert
+        let spin = find_placement_with_blocks(&result, &expected_blocks);
+        let last_move = spin.inputs[spin.inputs.len() - 2];
+        assert!(matches!(
+            last_move,
+            GameInput::RotateClockwise | GameInput::RotateCounterclockwise | GameInput::Rotate180
+        ));
+        assert_placement_reached_by_playfield(&grid, TetrominoType::T, spin);
+    }
+
+    #[test]
+    fn find_placements_skips_cells_that_cannot_be_reached() {
+        // Arrange
+        let grid = create_grid_with_bottom_rows(&["##########", "..........", ".........."]);
+
+        // Act
+        let result = find_placements(&grid, TetrominoType::O);
+
+        // Assert
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        let reaches_covered_rows = result.iter().any(|placement| {
+            placement
+                .tetromino
+                .get_world_blocks()
+                .iter()
+                .any(|position| position.y > bottom_y - 2)
+        });
+        assert!(!reaches_covered_rows);
+        assert_eq!(result.len(), 9);
+    }
+
+    #[test]
+    fn find_placements_without_room_to_spawn_returns_nothing() {
+        // Arrange
+        let mut grid = create_empty_grid();
+        grid.set(
+            Position::new(TETRIS_SPAWN_X + 1, TETRIS_SPAWN_Y + 1),
+            Some(TetrominoType::I),
+        );
+
+        // Act
+        let result = find_placements(&grid, TetrominoType::O);
+
+        // Assert
+        assert!(result.is_empty());
+    }
+
+    #[rstest]
+    #[case(TetrominoType::O, 0, 4)]
+    #[case(TetrominoType::O, 1, 3)]
+    #[case(TetrominoType::O, 5, 1)]
+    #[case(TetrominoType::I, 6, 3)]
+    fn find_min_nr_finesse_inputs_counts_moves_to_column(
+        #[case] tetromino_type: TetrominoType,
+        #[case] left_x: i32,
+        #[case] expected: u32,
+    ) {
+        // Arrange
+        let grid = create_empty_grid();
+        let placement = find_placements(&grid, tetromino_type)
+            .into_iter()
+            .find(|placement| {
+                let blocks = placement.tetromino.get_world_blocks();
+                let is_lying = blocks
+                    .iter()
+                    .all(|block| block.y >= PLAYFIELD_HEIGHT as i32 - 2);
+                is_lying && blocks.iter().map(|block| block.x).min() == Some(left_x)
+            })
+            .unwrap();
+        let spawned = get_spawned_tetromino(tetromino_type);
+
+        // Act
+        let result =
+            find_min_nr_finesse_inputs(&grid, &spawned, &placement.tetromino.get_world_blocks());
+
+        // Assert
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn find_min_nr_finesse_inputs_counts_rotation() {
+        // Arrange
+        let grid = create_empty_grid();
+        let mut rotated = get_spawned_tetromino(TetrominoType::T);
+        rotated.rotate_counterclockwise();
+        let blocks = drop_tetromino(&grid, &rotated).get_world_blocks();
+
+        // Act
+        let result =
+            find_min_nr_finesse_inputs(&grid, &get_spawned_tetromino(TetrominoType::T), &blocks);
+
+        // Assert
+        assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn find_min_nr_finesse_inputs_for_tuck_returns_none() {
+        // Arrange
+        let grid = create_grid_with_bottom_rows(&["####......", "..........", ".........."]);
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        let tuck_blocks = [
+            Position::new(0, bottom_y - 1),
+            Position::new(1, bottom_y - 1),
+            Position::new(0, bottom_y),
+            Position::new(1, bottom_y),
+        ];
+
+        // Act
+        let result = find_min_nr_finesse_inputs(
+            &grid,
+            &get_spawned_tetromino(TetrominoType::O),
+            &tuck_blocks,
+        );
+
+        // Assert
+        assert_eq!(result, None);
+    }
+
+    fn get_spawned_tetromino(tetromino_type: TetrominoType) -> TetrominoInstance {
+        let spawn_position = Position::new(TETRIS_SPAWN_X, TETRIS_SPAWN_Y);
+        TetrominoInstance::new(tetromino_type, spawn_position, &TetrominoDefinitions::new())
+    }
+
+    fn create_empty_grid() -> PlayfieldGrid {
+        PlayfieldGrid::new(crate::common::Dimensions::new(
+            PLAYFIELD_WIDTH,
+            PLAYFIELD_HEIGHT,
+        ))
+    }
+
+    /// Creates a standard size grid whose bottom rows are given as text.
+    fn create_grid_with_bottom_rows(bottom_rows: &[&str]) -> PlayfieldGrid {
+        let empty_row = ".".repeat(PLAYFIELD_WIDTH as usize);
+        let nr_empty_rows = PLAYFIELD_HEIGHT as usize - bottom_rows.len();
+        let mut rows = vec![empty_row.as_str(); nr_empty_rows];
+        rows.extend_from_slice(bottom_rows);
+        create_grid_from_rows(&rows)
+    }
+
+    fn find_placement_with_blocks<'a>(
+        placements: &'a [Placement],
+        expected_blocks: &[Position],
+    ) -> &'a Placement {
+        placements
+            .iter()
+            .find(|placement| get_sorted_blocks(&placement.tetromino) == expected_blocks)
+            .expect("placement not found")
+    }
+
+    /// Replays the inputs of the placement on a real playfield and checks that the tetromino
+    /// locks in the cells of the placement.
+    fn assert_placement_reached_by_playfield(
+        grid: &PlayfieldGrid,
+        tetromino_type: TetrominoType,
+        placement: &Placement,
+    ) {
+        let mut playfield = create_test_playfield_with_specific_type(tetromino_type);
+        playfield.set_grid(grid.clone());
+        playfield.spawn_tetromino();
+        for input in &placement.inputs {
+            playfield.handle_input(*input);
+        }
+
+        for position in placement.tetromino.get_world_blocks() {
+            assert!(playfield.is_position_occupied(position));
+        }
+    }
+}
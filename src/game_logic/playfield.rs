@@ -7,17 +7,26 @@ use crate::common::Position;
 use crate::constants::*;
 use crate::events::Event;
 use crate::events::EventQueue;
+use crate::game_logic::GameRules;
 use crate::game_logic::GravityTimer;
 use crate::game_logic::PlayfieldGrid;
+use crate::game_logic::{find_min_nr_finesse_inputs, FINESSE_INPUTS};
 use crate::graphics::PlayfieldView;
 use crate::gui::GameInput;
+use crate::tetromino::TetrominoDefinitions;
 use crate::tetromino::TetrominoGenerator;
 use crate::tetromino::TetrominoInstance;
 use crate::tetromino::TetrominoType;
 
+/// The center block of a T tetromino relative to its position, the same in every rotation.
+const T_CENTER_OFFSET: Position = Position::new(1, 2);
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum PlayfieldState {
     Playing,
+    EntryDelay {
+        countdown: Duration,
+    },
     AnimatingLines {
         countdown: Duration,
         full_lines: Vec<u32>,
@@ -25,44 +34,219 @@ pub enum PlayfieldState {
     GameOver,
 }
 
+/// Everything needed to resume a single player playfield exactly where it was, except the
+/// tetromino generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayfieldSnapshot {
+    pub grid: PlayfieldGrid,
+    pub tetromino: Option<TetrominoInstance>,
+    pub state: PlayfieldState,
+    pub rules: GameRules,
+    pub gravity_level: u32,
+    pub time_since_last_drop: Duration,
+    pub nr_locked_tetrominoes: u32,
+    /// The current tetromino as it spawned and the finesse inputs used for it so far.
+    pub spawned_tetromino: Option<TetrominoInstance>,
+    pub nr_finesse_inputs: u32,
+    pub hold_tetromino_type: Option<TetrominoType>,
+    pub can_hold: bool,
+}
+
+/// The tetromino controlled by a single player, with its own spawn position and gravity.
+struct PlayerTetromino {
+    spawn_position: Position,
+    tetromino: Option<TetrominoInstance>,
+    gravity_timer: GravityTimer,
+    held_inputs: Vec<GameInput>,
+    /// Whether the last successful move of the tetromino rotated it, needed to detect T-spins.
+    last_move_was_rotation: bool,
+    /// The tetromino as it spawned and the finesse inputs used since, to detect finesse faults.
+    spawned_tetromino: Option<TetrominoInstance>,
+    nr_finesse_inputs: u32,
+    hold_tetromino_type: Option<TetrominoType>,
+    /// Hold can be used once per tetromino, until the next one spawns.
+    can_hold: bool,
+}
+
+impl PlayerTetromino {
+    fn new(spawn_position: Position) -> Self {
+        Self {
+            spawn_position,
+            tetromino: None,
+            gravity_timer: GravityTimer::new(0),
+            held_inputs: Vec::new(),
+            last_move_was_rotation: false,
+            spawned_tetromino: None,
+            nr_finesse_inputs: 0,
+            hold_tetromino_type: None,
+            can_hold: true,
+        }
+    }
+}
+
+/// The playfield with the locked blocks and the tetrominoes of the players. Methods without a
+/// player argument act on the first player.
 pub struct Playfield<T: TetrominoGenerator> {
     dimensions: Dimensions,
     grid: PlayfieldGrid,
-    current_tetromino: Option<TetrominoInstance>,
+    players: Vec<PlayerTetromino>,
     tetromino_generator: T,
-    gravity_timer: GravityTimer,
     state: PlayfieldState,
+    rules: GameRules,
+    pending_garbage: Vec<(u32, u32)>, // (number of lines, hole column)
+    nr_locked_tetrominoes: u32,
     event_bus: Arc<EventQueue>,
 }
 
 impl<T: TetrominoGenerator> Playfield<T> {
     pub fn new(dimensions: Dimensions, tetromino_generator: T, event_bus: Arc<EventQueue>) -> Self {
+        let spawn_position = Position::new(TETRIS_SPAWN_X, TETRIS_SPAWN_Y);
+        Self::with_spawn_positions(
+            dimensions,
+            tetromino_generator,
+            event_bus,
+            &[spawn_position],
+        )
+    }
+
+    /// Creates a playfield shared by several players, each spawning tetrominoes at their own
+    /// spawn position. The players draw their tetrominoes from the same generator.
+    pub fn with_spawn_positions(
+        dimensions: Dimensions,
+        tetromino_generator: T,
+        event_bus: Arc<EventQueue>,
+        spawn_positions: &[Position],
+    ) -> Self {
         let grid = PlayfieldGrid::new(dimensions);
 
         Self {
             dimensions,
             grid,
-            current_tetromino: None,
+            players: spawn_positions
+                .iter()
+                .map(|spawn_position| PlayerTetromino::new(*spawn_position))
+                .collect(),
             tetromino_generator,
-            gravity_timer: GravityTimer::new(0),
             state: PlayfieldState::Playing,
+            rules: GameRules::default(),
+            pending_garbage: Vec::new(),
+            nr_locked_tetrominoes: 0,
             event_bus,
         }
     }
 
+    pub fn get_rules(&self) -> GameRules {
+        self.rules
+    }
+
+    pub fn set_rules(&mut self, rules: GameRules) {
+        self.rules = rules;
+    }
+
+    /// Sets the inputs that are currently held down. These are consulted when a new tetromino
+    /// spawns, to apply an initial hold (IHS) and an initial rotation (IRS).
+    pub fn set_held_inputs(&mut self, held_inputs: &[GameInput]) {
+        self.set_player_held_inputs(0, held_inputs);
+    }
+
+    pub fn set_player_held_inputs(&mut self, player: usize, held_inputs: &[GameInput]) {
+        self.players[player].held_inputs = held_inputs.to_vec();
+    }
+
+    /// Queues garbage lines with a hole at hole_column. They are added to the bottom of the
+    /// playfield when the next tetromino locks without clearing lines.
+    pub fn add_pending_garbage(&mut self, nr_lines: u32, hole_column: u32) {
+        self.pending_garbage.push((nr_lines, hole_column));
+    }
+
+    /// Cancels up to nr_lines of the pending garbage, the garbage that was queued first goes
+    /// first. Returns the number of lines that were left after cancelling.
+    pub fn cancel_pending_garbage(&mut self, nr_lines: u32) -> u32 {
+        let mut nr_lines_left = nr_lines;
+        while let Some((nr_pending_lines, _)) = self.pending_garbage.first_mut() {
+            if nr_lines_left == 0 {
+                break;
+            }
+            let nr_cancelled_lines = nr_lines_left.min(*nr_pending_lines);
+            *nr_pending_lines -= nr_cancelled_lines;
+            nr_lines_left -= nr_cancelled_lines;
+            if *nr_pending_lines == 0 {
+                self.pending_garbage.remove(0);
+            }
+        }
+        nr_lines_left
+    }
+
     pub fn start_level(&mut self, level: u32) {
-        self.gravity_timer.set_level(level);
-        if self.current_tetromino.is_none() && self.state == PlayfieldState::Playing {
-            self.spawn_tetromino();
+        for player in &mut self.players {
+            player.gravity_timer.set_level(level);
+        }
+        if self.state == PlayfieldState::Playing {
+            self.spawn_missing_tetrominoes();
+        }
+    }
+
+    pub fn get_tetromino_generator(&self) -> &T {
+        &self.tetromino_generator
+    }
+
+    pub fn set_tetromino_generator(&mut self, tetromino_generator: T) {
+        self.tetromino_generator = tetromino_generator;
+    }
+
+    /// Returns the state of the playfield and the first player, see PlayfieldSnapshot.
+    pub fn get_snapshot(&self) -> PlayfieldSnapshot {
+        let player = &self.players[0];
+        PlayfieldSnapshot {
+            grid: self.grid.clone(),
+            tetromino: player.tetromino.clone(),
+            state: self.state.clone(),
+            rules: self.rules,
+            gravity_level: player.gravity_timer.get_level(),
+            time_since_last_drop: player.gravity_timer.get_time_since_last_drop(),
+            nr_locked_tetrominoes: self.nr_locked_tetrominoes,
+            spawned_tetromino: player.spawned_tetromino.clone(),
+            nr_finesse_inputs: player.nr_finesse_inputs,
+            hold_tetromino_type: player.hold_tetromino_type,
+            can_hold: player.can_hold,
         }
     }
 
+    pub fn restore_snapshot(&mut self, snapshot: PlayfieldSnapshot) {
+        self.grid = snapshot.grid;
+        self.state = snapshot.state;
+        self.rules = snapshot.rules;
+        self.nr_locked_tetrominoes = snapshot.nr_locked_tetrominoes;
+        let player = &mut self.players[0];
+        player.tetromino = snapshot.tetromino;
+        player.spawned_tetromino = snapshot.spawned_tetromino;
+        player.nr_finesse_inputs = snapshot.nr_finesse_inputs;
+        player.hold_tetromino_type = snapshot.hold_tetromino_type;
+        player.can_hold = snapshot.can_hold;
+        player.gravity_timer.set_level(snapshot.gravity_level);
+        player
+            .gravity_timer
+            .set_time_since_last_drop(snapshot.time_since_last_drop);
+    }
+
+    pub fn get_nr_locked_tetrominoes(&self) -> u32 {
+        self.nr_locked_tetrominoes
+    }
+
     pub fn get_current_tetromino(&self) -> Option<&TetrominoInstance> {
-        self.current_tetromino.as_ref()
+        self.get_player_tetromino(0)
+    }
+
+    pub fn get_player_tetromino(&self, player: usize) -> Option<&TetrominoInstance> {
+        self.players[player].tetromino.as_ref()
+    }
+
+    pub fn get_hold_tetromino_type(&self) -> Option<TetrominoType> {
+        self.players[0].hold_tetromino_type
     }
 
     pub fn set_current_tetromino(&mut self, tetromino: Option<TetrominoInstance>) {
-        self.current_tetromino = tetromino;
+        self.players[0].tetromino = tetromino;
     }
 
     #[cfg(test)]
@@ -70,13 +254,17 @@ impl<T: TetrominoGenerator> Playfield<T> {
         self.grid.get(position).copied()
     }
 
-    pub fn get_view(&self) -> PlayfieldView {
+    pub fn get_view(&self) -> PlayfieldView<'_> {
         let full_lines = self.get_full_lines_from_state();
 
         PlayfieldView {
             dimensions: self.dimensions,
             grid: &self.grid,
-            current_tetromino: self.current_tetromino.as_ref(),
+            current_tetrominoes: self
+                .players
+                .iter()
+                .filter_map(|player| player.tetromino.as_ref())
+                .collect(),
             next_tetromino_type: self.tetromino_generator.peek_next_type(),
             full_lines,
             show_blinking_lines: self.is_showing_blinking_lines(),
@@ -100,30 +288,132 @@ impl<T: TetrominoGenerator> Playfield<T> {
     }
 
     pub fn spawn_tetromino(&mut self) -> PlayfieldState {
-        let position = Position::new(TETRIS_SPAWN_X, TETRIS_SPAWN_Y);
+        self.spawn_player_tetromino(0)
+    }
+
+    /// Spawns a new tetromino for the player, swapping it with the hold right away when hold is
+    /// held down (IHS). It is game over when the new tetromino overlaps blocks or the tetromino of
+    /// another player.
+    fn spawn_player_tetromino(&mut self, player: usize) -> PlayfieldState {
+        self.players[player].can_hold = true;
+        let mut spawned_tetromino = self.generate_player_tetromino(player);
+        if self.players[player].held_inputs.contains(&GameInput::Hold) {
+            spawned_tetromino = self.swap_with_hold(player, spawned_tetromino.get_type());
+        }
+
+        self.enter_player_tetromino(player, spawned_tetromino)
+    }
+
+    fn generate_player_tetromino(&mut self, player: usize) -> TetrominoInstance {
+        let position = self.players[player].spawn_position;
         let tetromino = self.tetromino_generator.generate(position);
+        self.event_bus
+            .push_back(Event::TetrominoSpawned(tetromino.get_type()));
+        tetromino
+    }
+
+    /// Puts the tetromino type in the hold of the player and returns the tetromino that was held
+    /// before at the spawn position, or the next tetromino when the hold was empty.
+    fn swap_with_hold(
+        &mut self,
+        player: usize,
+        tetromino_type: TetrominoType,
+    ) -> TetrominoInstance {
+        let player_tetromino = &mut self.players[player];
+        player_tetromino.can_hold = false;
+        match player_tetromino.hold_tetromino_type.replace(tetromino_type) {
+            Some(hold_tetromino_type) => TetrominoInstance::new(
+                hold_tetromino_type,
+                player_tetromino.spawn_position,
+                &TetrominoDefinitions::new(),
+            ),
+            None => self.generate_player_tetromino(player),
+        }
+    }
+
+    /// Puts a tetromino at the spawn position under control of the player, rotated when a
+    /// rotation input is held (IRS).
+    fn enter_player_tetromino(
+        &mut self,
+        player: usize,
+        spawned_tetromino: TetrominoInstance,
+    ) -> PlayfieldState {
+        let tetromino = self.apply_initial_rotation(player, spawned_tetromino.clone());
 
-        if !self.can_place_tetromino(&tetromino) {
+        if !self.can_place_player_tetromino(player, &tetromino) {
             self.state = PlayfieldState::GameOver;
         }
 
-        self.current_tetromino = Some(tetromino);
+        let is_rotated = tetromino != spawned_tetromino;
+        let player_tetromino = &mut self.players[player];
+        player_tetromino.tetromino = Some(tetromino);
+        player_tetromino.last_move_was_rotation = false;
+        player_tetromino.spawned_tetromino = Some(spawned_tetromino);
+        player_tetromino.nr_finesse_inputs = u32::from(is_rotated); // The held rotation input
 
         self.state.clone()
     }
 
+    fn spawn_missing_tetrominoes(&mut self) -> PlayfieldState {
+        for player in 0..self.players.len() {
+            if self.players[player].tetromino.is_none()
+                && self.spawn_player_tetromino(player) == PlayfieldState::GameOver
+            {
+                break;
+            }
+        }
+        self.state.clone()
+    }
+
+    /// Rotates a freshly spawned tetromino when a rotation input is held, but only when the
+    /// rotated tetromino fits at the spawn position.
+    fn apply_initial_rotation(
+        &self,
+        player: usize,
+        tetromino: TetrominoInstance,
+    ) -> TetrominoInstance {
+        let held_inputs = &self.players[player].held_inputs;
+        let mut rotated_tetromino = tetromino.clone();
+        if held_inputs.contains(&GameInput::RotateClockwise) {
+            rotated_tetromino.rotate_clockwise();
+        } else if held_inputs.contains(&GameInput::RotateCounterclockwise) {
+            rotated_tetromino.rotate_counterclockwise();
+        } else if held_inputs.contains(&GameInput::Rotate180) {
+            rotated_tetromino.rotate_180();
+        } else {
+            return tetromino;
+        }
+
+        if self.can_place_player_tetromino(player, &rotated_tetromino) {
+            rotated_tetromino
+        } else {
+            tetromino
+        }
+    }
+
     /// Try to move the current tetromino. Returns true if the tetromino was moved successfully
     /// (there were no obstacles), false otherwise.
     pub fn try_move_current_tetromino<F>(&mut self, move_fn: F) -> bool
     where
         F: FnOnce(&mut TetrominoInstance),
     {
-        if let Some(tetromino) = &self.current_tetromino {
+        self.try_move_player_tetromino(0, move_fn)
+    }
+
+    /// Try to move the tetromino of the player, which is blocked by locked blocks and by the
+    /// tetrominoes of the other players.
+    pub fn try_move_player_tetromino<F>(&mut self, player: usize, move_fn: F) -> bool
+    where
+        F: FnOnce(&mut TetrominoInstance),
+    {
+        if let Some(tetromino) = &self.players[player].tetromino {
             let mut moved_tetromino = tetromino.clone();
             move_fn(&mut moved_tetromino);
 
-            if self.can_place_tetromino(&moved_tetromino) {
-                self.set_current_tetromino(Some(moved_tetromino));
+            if self.can_place_player_tetromino(player, &moved_tetromino) {
+                self.players[player].last_move_was_rotation =
+                    moved_tetromino.get_rotation_index() != tetromino.get_rotation_index();
+                self.players[player].tetromino = Some(moved_tetromino);
                 return true;
             }
         }
@@ -131,12 +421,19 @@ impl<T: TetrominoGenerator> Playfield<T> {
         false
     }
 
-    /// Locks the current tetromino in its current position and spawns a new tetromino in the
-    /// start position. Resets the gravity timer.
     pub fn lock_tetromino(&mut self) -> PlayfieldState {
-        let tetromino = self.current_tetromino.as_ref().unwrap();
+        self.lock_player_tetromino(0)
+    }
+
+    /// Locks the tetromino of the player in its current position and spawns a new tetromino in
+    /// the start position, after the line clear and entry delays have passed. Resets the gravity
+    /// timer.
+    pub fn lock_player_tetromino(&mut self, player: usize) -> PlayfieldState {
+        let tetromino = self.players[player].tetromino.take().unwrap();
+        let is_t_spin = self.players[player].last_move_was_rotation && self.is_t_spin(&tetromino);
         let tetromino_type: TetrominoType = tetromino.get_type();
         let world_blocks: Vec<Position> = tetromino.get_world_blocks();
+        self.check_finesse(player, &world_blocks);
 
         for position in world_blocks {
             if self.dimensions.contains(position) {
@@ -144,36 +441,161 @@ impl<T: TetrominoGenerator> Playfield<T> {
             }
         }
 
-        self.current_tetromino = None;
-
-        self.gravity_timer.reset();
+        self.players[player].gravity_timer.reset();
+        self.nr_locked_tetrominoes += 1;
+        self.event_bus
+            .push_back(Event::TetrominoLocked(tetromino_type));
 
         let full_lines = self.grid.get_full_lines();
-        if !full_lines.is_empty() {
-            let nr_full_lines = full_lines.len() as u32;
-            self.state = PlayfieldState::AnimatingLines {
-                countdown: Duration::from_millis(FILLED_LINES_ANIMATION_DURATION_MS),
-                full_lines,
-            };
-            self.event_bus.push_back(Event::LinesCleared(nr_full_lines));
-        } else {
-            return self.spawn_tetromino();
+        if is_t_spin {
+            self.event_bus
+                .push_back(Event::TSpin(full_lines.len() as u32));
+        }
+        if full_lines.is_empty() {
+            if !self.add_pending_garbage_to_grid() {
+                self.state = PlayfieldState::GameOver;
+                return self.state.clone();
+            }
+            return self.start_entry_delay();
         }
 
+        let nr_full_lines = full_lines.len() as u32;
+        self.event_bus.push_back(Event::LinesCleared(nr_full_lines));
+        if self.grid.is_empty_without_lines(&full_lines) {
+            self.event_bus.push_back(Event::PerfectClear);
+        }
+
+        if self.rules.line_clear_delay.is_zero() {
+            return self.remove_full_lines(&full_lines);
+        }
+
+        self.state = PlayfieldState::AnimatingLines {
+            countdown: self.rules.line_clear_delay,
+            full_lines,
+        };
         self.state.clone()
     }
 
-    pub fn can_place_tetromino(&self, tetromino: &TetrominoInstance) -> bool {
-        let world_blocks: Vec<Position> = tetromino.get_world_blocks();
-        for position in world_blocks {
-            if !self.dimensions.contains(position) || self.is_position_occupied(position) {
-                return false;
+    /// Publishes a finesse fault when the player used more inputs than needed to drop the
+    /// tetromino onto the blocks. Placements that can't be reached with a drop aren't judged.
+    fn check_finesse(&mut self, player: usize, blocks: &[Position]) {
+        let Some(spawned_tetromino) = self.players[player].spawned_tetromino.take() else {
+            return;
+        };
+        let nr_inputs = self.players[player].nr_finesse_inputs;
+        if let Some(min_nr_inputs) =
+            find_min_nr_finesse_inputs(&self.grid, &spawned_tetromino, blocks)
+        {
+            if nr_inputs > min_nr_inputs {
+                self.event_bus
+                    .push_back(Event::FinesseFault(nr_inputs - min_nr_inputs));
             }
         }
+    }
+
+    /// Returns true when the tetromino is a T with at least three of the four cells diagonally
+    /// next to its center occupied, walls and floor count as occupied.
+    fn is_t_spin(&self, tetromino: &TetrominoInstance) -> bool {
+        if tetromino.get_type() != TetrominoType::T {
+            return false;
+        }
+
+        let center = tetromino.get_position() + T_CENTER_OFFSET;
+        let nr_occupied_corners = [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+            .iter()
+            .map(|(dx, dy)| center.translate(*dx, *dy))
+            .filter(|corner| {
+                !self.dimensions.contains(*corner) || self.grid.is_position_occupied(*corner)
+            })
+            .count();
+        nr_occupied_corners >= 3
+    }
+
+    /// Returns false when the garbage pushed blocks or a tetromino of a player out of the top of
+    /// the playfield.
+    fn add_pending_garbage_to_grid(&mut self) -> bool {
+        let mut fits = true;
+        for (nr_lines, hole_column) in self.pending_garbage.drain(..) {
+            fits &= self.grid.add_garbage_lines(nr_lines, hole_column);
+        }
+        fits && self.push_up_player_tetrominoes()
+    }
+
+    /// Removes the full lines and starts the entry delay. The blocks that drop down can land in
+    /// the tetromino of another player, which is then pushed up, or it's game over when it can't.
+    fn remove_full_lines(&mut self, full_lines: &[u32]) -> PlayfieldState {
+        self.grid.remove_lines(full_lines);
+        if !self.push_up_player_tetrominoes() {
+            self.state = PlayfieldState::GameOver;
+            return self.state.clone();
+        }
+        self.start_entry_delay()
+    }
 
+    /// Pushes the tetrominoes of the players up until they no longer overlap the blocks that were
+    /// moved into them. Returns false when a tetromino would be pushed out of the top.
+    fn push_up_player_tetrominoes(&mut self) -> bool {
+        for player in 0..self.players.len() {
+            let Some(mut tetromino) = self.players[player].tetromino.clone() else {
+                continue;
+            };
+            while !self.can_place_player_tetromino(player, &tetromino) {
+                if tetromino
+                    .get_world_blocks()
+                    .iter()
+                    .any(|position| position.y <= 0)
+                {
+                    return false;
+                }
+                tetromino.move_up();
+            }
+            self.players[player].tetromino = Some(tetromino);
+        }
         true
     }
 
+    /// Spawns the next tetromino immediately when there is no entry delay, otherwise waits for
+    /// the entry delay to pass before the next tetromino is spawned in update.
+    fn start_entry_delay(&mut self) -> PlayfieldState {
+        if self.rules.entry_delay.is_zero() {
+            self.state = PlayfieldState::Playing;
+            return self.spawn_missing_tetrominoes();
+        }
+
+        self.state = PlayfieldState::EntryDelay {
+            countdown: self.rules.entry_delay,
+        };
+        self.state.clone()
+    }
+
+    pub fn can_place_tetromino(&self, tetromino: &TetrominoInstance) -> bool {
+        self.grid.can_place_tetromino(tetromino)
+    }
+
+    /// Like can_place_tetromino, but the tetrominoes of the other players are obstacles too.
+    fn can_place_player_tetromino(&self, player: usize, tetromino: &TetrominoInstance) -> bool {
+        if !self.can_place_tetromino(tetromino) {
+            return false;
+        }
+
+        let world_blocks = tetromino.get_world_blocks();
+        self.players
+            .iter()
+            .enumerate()
+            .filter(|(other_player, _)| *other_player != player)
+            .filter_map(|(_, other)| other.tetromino.as_ref())
+            .all(|other_tetromino| {
+                other_tetromino
+                    .get_world_blocks()
+                    .iter()
+                    .all(|position| !world_blocks.contains(position))
+            })
+    }
+
+    pub fn set_grid(&mut self, grid: PlayfieldGrid) {
+        self.grid = grid;
+    }
+
     #[cfg(test)]
     pub fn clear(&mut self) {
         self.grid.clear();
@@ -181,35 +603,54 @@ impl<T: TetrominoGenerator> Playfield<T> {
     }
 
     pub fn handle_input(&mut self, input: GameInput) -> PlayfieldState {
+        self.handle_player_input(0, input)
+    }
+
+    pub fn handle_player_input(&mut self, player: usize, input: GameInput) -> PlayfieldState {
         if self.state != PlayfieldState::Playing {
             return self.state.clone();
         }
 
+        if FINESSE_INPUTS.contains(&input) && self.players[player].tetromino.is_some() {
+            self.players[player].nr_finesse_inputs += 1;
+        }
+
         match input {
             GameInput::MoveLeft => {
-                let _ = self.try_move_current_tetromino(|tetromino| tetromino.move_left());
+                let _ = self.try_move_player_tetromino(player, |tetromino| tetromino.move_left());
             }
             GameInput::MoveRight => {
-                let _ = self.try_move_current_tetromino(|tetromino| tetromino.move_right());
+                let _ = self.try_move_player_tetromino(player, |tetromino| tetromino.move_right());
             }
             GameInput::MoveDown => {
                 let has_moved: bool =
-                    self.try_move_current_tetromino(|tetromino| tetromino.move_down());
+                    self.try_move_player_tetromino(player, |tetromino| tetromino.move_down());
                 if has_moved {
-                    self.gravity_timer.reset();
-                } else {
-                    return self.lock_tetromino();
+                    self.players[player].gravity_timer.reset();
+                } else if self.players[player].tetromino.is_some() {
+                    return self.lock_player_tetromino(player);
                 }
             }
             GameInput::RotateClockwise => {
-                let _ = self.try_move_current_tetromino(|tetromino| tetromino.rotate_clockwise());
+                let _ = self
+                    .try_move_player_tetromino(player, |tetromino| tetromino.rotate_clockwise());
             }
             GameInput::RotateCounterclockwise => {
-                let _ = self
-                    .try_move_current_tetromino(|tetromino| tetromino.rotate_counterclockwise());
+                let _ = self.try_move_player_tetromino(player, |tetromino| {
+                    tetromino.rotate_counterclockwise()
+                });
+            }
+            GameInput::Rotate180 => {
+                let _ = self.try_move_player_tetromino(player, |tetromino| tetromino.rotate_180());
+            }
+            GameInput::Drop if self.players[player].tetromino.is_some() => {
+                return self.harddrop_tetromino(player);
             }
-            GameInput::Drop => {
-                return self.harddrop_tetromino();
+            GameInput::SonicDrop => {
+                self.sonic_drop_tetromino(player);
+            }
+            GameInput::Hold if self.players[player].can_hold => {
+                return self.hold_tetromino(player);
             }
             _ => {}
         }
@@ -217,16 +658,51 @@ impl<T: TetrominoGenerator> Playfield<T> {
         self.state.clone()
     }
 
-    fn harddrop_tetromino(&mut self) -> PlayfieldState {
-        while self.try_move_current_tetromino(|tetromino| tetromino.move_down()) {}
-        self.lock_tetromino()
+    /// Swaps the tetromino of the player with the hold. The tetromino that comes out of the
+    /// hold starts again at the spawn position.
+    fn hold_tetromino(&mut self, player: usize) -> PlayfieldState {
+        let Some(tetromino) = self.players[player].tetromino.take() else {
+            return self.state.clone();
+        };
+
+        let spawned_tetromino = self.swap_with_hold(player, tetromino.get_type());
+        self.players[player].gravity_timer.reset();
+        self.enter_player_tetromino(player, spawned_tetromino)
+    }
+
+    fn harddrop_tetromino(&mut self, player: usize) -> PlayfieldState {
+        self.move_tetromino_to_landing_row(player);
+        self.lock_player_tetromino(player)
+    }
+
+    /// Moves the tetromino of the player to its landing row without locking it, so it can still
+    /// be moved and rotated. Without a lock delay the gravity timer decides when it locks, so the
+    /// timer is restarted to give the player a full gravity interval.
+    fn sonic_drop_tetromino(&mut self, player: usize) {
+        if self.move_tetromino_to_landing_row(player) {
+            self.players[player].gravity_timer.reset();
+        }
+    }
+
+    /// Moves the tetromino of the player down as far as possible. Returns true if it has moved.
+    fn move_tetromino_to_landing_row(&mut self, player: usize) -> bool {
+        let mut has_moved = false;
+        while self.try_move_player_tetromino(player, |tetromino| tetromino.move_down()) {
+            has_moved = true;
+        }
+        has_moved
     }
 
     pub fn update(&mut self, delta_time: Duration) -> PlayfieldState {
         match self.state {
             PlayfieldState::Playing => {
-                if self.get_current_tetromino().is_some() && self.gravity_timer.update(delta_time) {
-                    return self.apply_gravity();
+                for player in 0..self.players.len() {
+                    if self.players[player].tetromino.is_some()
+                        && self.players[player].gravity_timer.update(delta_time)
+                        && self.apply_gravity(player) != PlayfieldState::Playing
+                    {
+                        break;
+                    }
                 }
             }
             PlayfieldState::AnimatingLines {
@@ -234,9 +710,8 @@ impl<T: TetrominoGenerator> Playfield<T> {
                 ref full_lines,
             } => {
                 if delta_time >= countdown {
-                    self.grid.remove_lines(full_lines);
-                    self.state = PlayfieldState::Playing;
-                    return self.spawn_tetromino();
+                    let full_lines = full_lines.clone();
+                    return self.remove_full_lines(&full_lines);
                 } else {
                     self.state = PlayfieldState::AnimatingLines {
                         countdown: countdown - delta_time,
@@ -244,17 +719,27 @@ impl<T: TetrominoGenerator> Playfield<T> {
                     };
                 }
             }
+            PlayfieldState::EntryDelay { countdown } => {
+                if delta_time >= countdown {
+                    self.state = PlayfieldState::Playing;
+                    return self.spawn_missing_tetrominoes();
+                } else {
+                    self.state = PlayfieldState::EntryDelay {
+                        countdown: countdown - delta_time,
+                    };
+                }
+            }
             PlayfieldState::GameOver => {}
         }
 
         self.state.clone()
     }
 
-    fn apply_gravity(&mut self) -> PlayfieldState {
-        let moved = self.try_move_current_tetromino(|tetromino| tetromino.move_down());
+    fn apply_gravity(&mut self, player: usize) -> PlayfieldState {
+        let moved = self.try_move_player_tetromino(player, |tetromino| tetromino.move_down());
 
         if !moved {
-            self.lock_tetromino()
+            self.lock_player_tetromino(player)
         } else {
             self.state.clone()
         }
@@ -277,9 +762,14 @@ impl<T: TetrominoGenerator> Playfield<T> {
         self.state = state;
     }
 
+    #[cfg(test)]
+    pub fn get_pending_garbage(&self) -> &[(u32, u32)] {
+        &self.pending_garbage
+    }
+
     #[cfg(test)]
     pub fn get_gravity_timer(&self) -> &GravityTimer {
-        &self.gravity_timer
+        &self.players[0].gravity_timer
     }
 
     #[cfg(test)]
@@ -295,7 +785,10 @@ impl<T: TetrominoGenerator> Playfield<T> {
 mod tests {
     use super::*;
     use crate::constants::{TETRIS_SPAWN_X, TETRIS_SPAWN_Y};
+    use crate::game_logic::grid::GARBAGE_TETROMINO_TYPE;
     use crate::test_helpers::*;
+    use crate::tetromino::FixedTetrominoGenerator;
+    use crate::tetromino::SequenceTetrominoGenerator;
     use crate::tetromino::TetrominoDefinitions;
     use rstest::rstest;
 
@@ -521,6 +1014,7 @@ mod tests {
     #[case(GameInput::MoveDown, 0, 1, 0)]
     #[case(GameInput::RotateClockwise, 0, 0, 1)]
     #[case(GameInput::RotateCounterclockwise, 0, 0, 3)]
+    #[case(GameInput::Rotate180, 0, 0, 2)]
     fn can_move_tetromino_when_no_collision(
         #[case] move_input: GameInput,
         #[case] expected_x_delta: i32,
@@ -641,6 +1135,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn handle_input_sonic_drop_moves_tetromino_to_bottom_without_locking() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.spawn_tetromino();
+
+        // Act
+        let result = sut.handle_input(GameInput::SonicDrop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let landing_position = Position::new(TETRIS_SPAWN_X, PLAYFIELD_HEIGHT as i32 - 3);
+        assert_eq!(
+            sut.get_current_tetromino().unwrap().get_position(),
+            landing_position
+        );
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        assert!(!sut.is_position_occupied(Position::new(TETRIS_SPAWN_X + 1, bottom_y)));
+    }
+
+    #[test]
+    fn handle_input_sonic_drop_allows_sliding_afterwards() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.spawn_tetromino();
+        sut.handle_input(GameInput::SonicDrop);
+
+        // Act
+        sut.handle_input(GameInput::MoveLeft);
+
+        // Assert
+        assert_eq!(
+            sut.get_current_tetromino().unwrap().get_position(),
+            Position::new(TETRIS_SPAWN_X - 1, PLAYFIELD_HEIGHT as i32 - 3)
+        );
+    }
+
+    #[test]
+    fn handle_input_sonic_drop_restarts_gravity_timer_before_locking() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.start_level(0);
+        sut.update(Duration::from_millis(500));
+        sut.handle_input(GameInput::SonicDrop);
+
+        // Act
+        sut.update(Duration::from_millis(500));
+
+        // Assert
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        assert!(!sut.is_position_occupied(Position::new(TETRIS_SPAWN_X + 1, bottom_y)));
+
+        sut.update(Duration::from_millis(500));
+        assert!(sut.is_position_occupied(Position::new(TETRIS_SPAWN_X + 1, bottom_y)));
+    }
+
     #[test]
     fn lock_tetromino_with_full_line_sets_animating_state() {
         // Arrange
@@ -671,7 +1221,7 @@ mod tests {
         assert_eq!(
             sut.state,
             PlayfieldState::AnimatingLines {
-                countdown: Duration::from_millis(FILLED_LINES_ANIMATION_DURATION_MS),
+                countdown: Duration::from_millis(DEFAULT_LINE_CLEAR_DELAY_MS),
                 full_lines: vec![
                     PLAYFIELD_HEIGHT - 4,
                     PLAYFIELD_HEIGHT - 3,
@@ -770,62 +1320,817 @@ mod tests {
     }
 
     #[test]
-    fn lock_tetromino_publishes_lines_cleared_event_when_lines_are_full() {
+    fn lock_tetromino_publishes_perfect_clear_event_when_grid_is_left_empty() {
         // Arrange
         let event_bus = Arc::new(EventQueue::new());
         let mut sut = create_test_playfield_with_event_queue(event_bus.clone());
+        sut.set_grid(create_bottom_grid(&["####..####", "####..####"]));
+        sut.spawn_tetromino();
 
-        sut.fill_row(PLAYFIELD_HEIGHT as i32 - 1, TetrominoType::I);
+        // Act
+        sut.harddrop_tetromino(0);
+
+        // Assert
+        event_bus.assert_contains(Event::LinesCleared(2));
+        event_bus.assert_contains(Event::PerfectClear);
+    }
+
+    #[test]
+    fn lock_tetromino_does_not_publish_perfect_clear_event_when_blocks_remain() {
+        // Arrange
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_test_playfield_with_event_queue(event_bus.clone());
+        sut.set_grid(create_bottom_grid(&[
+            "#.........",
+            "####..####",
+            "####..####",
+        ]));
         sut.spawn_tetromino();
 
         // Act
-        sut.harddrop_tetromino();
+        sut.harddrop_tetromino(0);
 
         // Assert
-        event_bus.assert_contains(Event::LinesCleared(1));
+        assert_eq!(
+            drain_events_without_tetromino_events(&event_bus),
+            vec![Event::LinesCleared(2)]
+        );
     }
 
     #[test]
-    fn start_level_during_line_animation_should_not_spawn_tetromino() {
+    fn lock_tetromino_publishes_t_spin_event_when_t_rotated_into_slot() {
         // Arrange
-        let mut sut = create_test_playfield();
-        sut.state = PlayfieldState::AnimatingLines {
-            countdown: Duration::from_millis(500),
-            full_lines: vec![19],
-        };
-        assert!(sut.get_current_tetromino().is_none());
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_t_spin_test_playfield(event_bus.clone());
 
-        // Act - This should not spawn a tetromino during animation
-        sut.start_level(1);
+        // Act
+        sut.handle_input(GameInput::RotateClockwise);
+        sut.handle_input(GameInput::SonicDrop);
+        sut.handle_input(GameInput::RotateClockwise);
+        sut.handle_input(GameInput::Drop);
 
-        // Assert - No tetromino should be spawned
-        assert!(sut.get_current_tetromino().is_none());
+        // Assert
+        event_bus.assert_contains(Event::TSpin(2));
+        event_bus.assert_contains(Event::LinesCleared(2));
     }
 
     #[test]
-    fn get_view_returns_correct_next_tetromino_type() {
+    fn lock_tetromino_does_not_publish_t_spin_event_when_t_moved_after_rotating() {
         // Arrange
-        let sut = create_test_playfield_with_specific_type(TetrominoType::Z);
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_t_spin_test_playfield(event_bus.clone());
 
         // Act
-        let view = sut.get_view();
+        sut.handle_input(GameInput::RotateClockwise);
+        sut.handle_input(GameInput::Drop);
 
         // Assert
-        assert_eq!(view.next_tetromino_type, TetrominoType::Z);
+        assert_eq!(
+            drain_events_without_tetromino_events(&event_bus),
+            vec![Event::LinesCleared(1)]
+        );
     }
 
     #[test]
-    fn spawn_tetromino_after_clear_in_game_over_state_should_work() {
+    fn lock_tetromino_publishes_finesse_fault_event_for_unneeded_inputs() {
         // Arrange
-        let mut sut = create_test_playfield();
-        sut.state = PlayfieldState::GameOver;
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_test_playfield_with_event_queue(event_bus.clone());
+        sut.spawn_tetromino();
 
         // Act
-        sut.clear();
-        let result = sut.spawn_tetromino();
+        sut.handle_input(GameInput::MoveLeft);
+        sut.handle_input(GameInput::MoveRight);
+        sut.handle_input(GameInput::MoveRight);
+        sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(
+            drain_events_without_tetromino_events(&event_bus),
+            vec![Event::FinesseFault(2)]
+        );
+    }
+
+    #[test]
+    fn lock_tetromino_does_not_publish_finesse_fault_event_for_minimal_inputs() {
+        // Arrange
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_test_playfield_with_event_queue(event_bus.clone());
+        sut.spawn_tetromino();
+
+        // Act
+        sut.handle_input(GameInput::MoveLeft);
+        sut.handle_input(GameInput::MoveDown);
+        sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(drain_events_without_tetromino_events(&event_bus), vec![]);
+    }
+
+    /// Creates a playfield that spawns the given tetrominoes in order.
+    fn create_test_playfield_with_sequence(
+        tetromino_types: &[TetrominoType],
+    ) -> Playfield<SequenceTetrominoGenerator> {
+        Playfield::new(
+            Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+            SequenceTetrominoGenerator::new(tetromino_types.to_vec()),
+            Arc::new(EventQueue::new()),
+        )
+    }
+
+    /// Drains the queued events, leaving out the ones published for every spawned and locked
+    /// tetromino.
+    fn drain_events_without_tetromino_events(event_bus: &EventQueue) -> Vec<Event> {
+        event_bus
+            .drain()
+            .into_iter()
+            .filter(|event| {
+                !matches!(
+                    event,
+                    Event::TetrominoSpawned(_) | Event::TetrominoLocked(_)
+                )
+            })
+            .collect()
+    }
+
+    /// A T-spin double slot that a T can only fit into by dropping it pointing right and then
+    /// rotating it clockwise.
+    fn create_t_spin_test_playfield(
+        event_bus: Arc<EventQueue>,
+    ) -> Playfield<FixedTetrominoGenerator> {
+        let mut playfield = Playfield::new(
+            Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+            FixedTetrominoGenerator::new(TetrominoType::T),
+            event_bus,
+        );
+        playfield.set_grid(create_bottom_grid(&[
+            "####......",
+            "###...####",
+            "####.#####",
+        ]));
+        playfield.spawn_tetromino();
+        playfield
+    }
+
+    /// Creates a playfield sized grid with the given rows at the bottom, see create_grid_from_rows.
+    fn create_bottom_grid(bottom_rows: &[&str]) -> PlayfieldGrid {
+        let empty_row = ".".repeat(PLAYFIELD_WIDTH as usize);
+        let mut rows = vec![empty_row.as_str(); PLAYFIELD_HEIGHT as usize - bottom_rows.len()];
+        rows.extend_from_slice(bottom_rows);
+        create_grid_from_rows(&rows)
+    }
+
+    #[test]
+    fn lock_tetromino_publishes_lines_cleared_event_when_lines_are_full() {
+        // Arrange
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_test_playfield_with_event_queue(event_bus.clone());
+
+        sut.fill_row(PLAYFIELD_HEIGHT as i32 - 1, TetrominoType::I);
+        sut.spawn_tetromino();
+
+        // Act
+        sut.harddrop_tetromino(0);
+
+        // Assert
+        event_bus.assert_contains(Event::LinesCleared(1));
+    }
+
+    #[test]
+    fn start_level_during_line_animation_should_not_spawn_tetromino() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.state = PlayfieldState::AnimatingLines {
+            countdown: Duration::from_millis(500),
+            full_lines: vec![19],
+        };
+        assert!(sut.get_current_tetromino().is_none());
+
+        // Act - This should not spawn a tetromino during animation
+        sut.start_level(1);
+
+        // Assert - No tetromino should be spawned
+        assert!(sut.get_current_tetromino().is_none());
+    }
+
+    #[test]
+    fn get_view_returns_correct_next_tetromino_type() {
+        // Arrange
+        let sut = create_test_playfield_with_specific_type(TetrominoType::Z);
+
+        // Act
+        let view = sut.get_view();
+
+        // Assert
+        assert_eq!(view.next_tetromino_type, TetrominoType::Z);
+    }
+
+    #[test]
+    fn spawn_tetromino_after_clear_in_game_over_state_should_work() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.state = PlayfieldState::GameOver;
+
+        // Act
+        sut.clear();
+        let result = sut.spawn_tetromino();
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        assert!(sut.get_current_tetromino().is_some());
+    }
+
+    #[test]
+    fn lock_tetromino_with_entry_delay_sets_entry_delay_state() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.set_rules(GameRules::new(Duration::from_millis(300), Duration::ZERO));
+        sut.spawn_tetromino();
+
+        // Act
+        let result = sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(
+            result,
+            PlayfieldState::EntryDelay {
+                countdown: Duration::from_millis(300)
+            }
+        );
+        assert!(sut.get_current_tetromino().is_none());
+    }
+
+    #[test]
+    fn lock_tetromino_counts_locked_tetrominoes() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.spawn_tetromino();
+
+        // Act
+        sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(sut.get_nr_locked_tetrominoes(), 1);
+    }
+
+    #[test]
+    fn lock_tetromino_publishes_locked_and_spawned_events() {
+        // Arrange
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_test_playfield_with_event_queue(event_bus.clone());
+        sut.set_rules(GameRules::without_delays());
+        sut.spawn_tetromino();
+
+        // Act
+        sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(
+            event_bus.drain(),
+            vec![
+                Event::TetrominoSpawned(TetrominoType::O),
+                Event::TetrominoLocked(TetrominoType::O),
+                Event::TetrominoSpawned(TetrominoType::O),
+            ]
+        );
+    }
+
+    #[test]
+    fn update_when_entry_delay_decreases_countdown() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.state = PlayfieldState::EntryDelay {
+            countdown: Duration::from_millis(300),
+        };
+
+        // Act
+        sut.update(Duration::from_millis(100));
+
+        // Assert
+        assert_eq!(
+            sut.state,
+            PlayfieldState::EntryDelay {
+                countdown: Duration::from_millis(200)
+            }
+        );
+        assert!(sut.get_current_tetromino().is_none());
+    }
+
+    #[test]
+    fn update_when_entry_delay_spawns_tetromino_after_timeout() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.state = PlayfieldState::EntryDelay {
+            countdown: Duration::from_millis(300),
+        };
+
+        // Act
+        let result = sut.update(Duration::from_millis(300));
 
         // Assert
         assert_eq!(result, PlayfieldState::Playing);
         assert!(sut.get_current_tetromino().is_some());
     }
+
+    #[test]
+    fn update_after_line_clear_delay_starts_entry_delay() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.set_rules(GameRules::new(
+            Duration::from_millis(300),
+            Duration::from_millis(500),
+        ));
+        sut.state = PlayfieldState::AnimatingLines {
+            countdown: Duration::from_millis(100),
+            full_lines: vec![19],
+        };
+
+        // Act
+        let result = sut.update(Duration::from_millis(100));
+
+        // Assert
+        assert_eq!(
+            result,
+            PlayfieldState::EntryDelay {
+                countdown: Duration::from_millis(300)
+            }
+        );
+        assert!(sut.get_current_tetromino().is_none());
+    }
+
+    #[test]
+    fn lock_tetromino_without_delays_removes_lines_and_spawns_immediately() {
+        // Arrange
+        let event_bus = Arc::new(EventQueue::new());
+        let mut sut = create_test_playfield_with_event_queue(event_bus.clone());
+        sut.set_rules(GameRules::without_delays());
+        sut.fill_row(PLAYFIELD_HEIGHT as i32 - 1, TetrominoType::I);
+        sut.grid.set(
+            Position::new(0, PLAYFIELD_HEIGHT as i32 - 2),
+            Some(TetrominoType::I),
+        );
+        sut.spawn_tetromino();
+
+        // Act
+        let result = sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        assert!(sut.get_current_tetromino().is_some());
+        event_bus.assert_contains(Event::LinesCleared(1));
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        assert_eq!(
+            sut.get_tetromino_type_at(Position::new(0, bottom_y)),
+            Some(TetrominoType::I)
+        );
+        assert!(sut.is_position_occupied(Position::new(TETRIS_SPAWN_X + 1, bottom_y)));
+        assert!(!sut.is_position_occupied(Position::new(TETRIS_SPAWN_X + 1, bottom_y - 2)));
+    }
+
+    #[test]
+    fn handle_input_when_entry_delay_blocks_movement() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        let state = PlayfieldState::EntryDelay {
+            countdown: Duration::from_millis(100),
+        };
+        sut.state = state.clone();
+
+        // Act
+        let result = sut.handle_input(GameInput::MoveLeft);
+
+        // Assert
+        assert_eq!(result, state);
+        assert!(sut.get_current_tetromino().is_none());
+    }
+
+    #[rstest]
+    #[case(GameInput::RotateClockwise, 1)]
+    #[case(GameInput::RotateCounterclockwise, 3)]
+    #[case(GameInput::Rotate180, 2)]
+    #[case(GameInput::MoveLeft, 0)]
+    fn spawn_tetromino_applies_initial_rotation_of_held_input(
+        #[case] held_input: GameInput,
+        #[case] expected_rotation_index: usize,
+    ) {
+        // Arrange
+        let mut sut = create_test_playfield_with_specific_type(TetrominoType::T);
+        sut.set_held_inputs(&[held_input]);
+
+        // Act
+        let result = sut.spawn_tetromino();
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let current_tetromino = sut.get_current_tetromino().unwrap();
+        assert_eq!(
+            usize::from(current_tetromino.get_rotation_index()),
+            expected_rotation_index
+        );
+        assert_eq!(
+            current_tetromino.get_position(),
+            get_tetromino_start_position()
+        );
+    }
+
+    #[test]
+    fn spawn_tetromino_skips_initial_rotation_when_rotated_tetromino_does_not_fit() {
+        // Arrange
+        let mut sut = create_test_playfield_with_specific_type(TetrominoType::I);
+        // The vertical I spawns in column TETRIS_SPAWN_X + 1, rows 0 to 3.
+        sut.grid.set(
+            Position::new(TETRIS_SPAWN_X + 1, TETRIS_SPAWN_Y + 3),
+            Some(TetrominoType::O),
+        );
+        sut.set_held_inputs(&[GameInput::RotateClockwise]);
+
+        // Act
+        let result = sut.spawn_tetromino();
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let current_tetromino = sut.get_current_tetromino().unwrap();
+        assert_eq!(usize::from(current_tetromino.get_rotation_index()), 0);
+    }
+
+    #[test]
+    fn spawn_tetromino_checks_game_over_with_rotated_tetromino() {
+        // Arrange
+        let mut sut = create_test_playfield_with_specific_type(TetrominoType::I);
+        // Blocks the horizontal I but not the vertical I.
+        sut.grid.set(
+            Position::new(TETRIS_SPAWN_X, TETRIS_SPAWN_Y + 1),
+            Some(TetrominoType::O),
+        );
+        sut.set_held_inputs(&[GameInput::RotateClockwise]);
+
+        // Act
+        let result = sut.spawn_tetromino();
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let current_tetromino = sut.get_current_tetromino().unwrap();
+        assert_eq!(usize::from(current_tetromino.get_rotation_index()), 1);
+    }
+
+    #[test]
+    fn handle_input_hold_puts_tetromino_in_empty_hold_and_spawns_next() {
+        // Arrange
+        let mut sut = create_test_playfield_with_sequence(&[TetrominoType::T, TetrominoType::I]);
+        sut.spawn_tetromino();
+
+        // Act
+        let result = sut.handle_input(GameInput::Hold);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        assert_eq!(sut.get_hold_tetromino_type(), Some(TetrominoType::T));
+        let current_tetromino = sut.get_current_tetromino().unwrap();
+        assert_eq!(current_tetromino.get_type(), TetrominoType::I);
+        assert_eq!(
+            current_tetromino.get_position(),
+            get_tetromino_start_position()
+        );
+    }
+
+    #[test]
+    fn handle_input_hold_swaps_tetromino_with_held_tetromino_at_spawn_position() {
+        // Arrange
+        let mut sut = create_test_playfield_with_sequence(&[
+            TetrominoType::T,
+            TetrominoType::I,
+            TetrominoType::O,
+        ]);
+        sut.spawn_tetromino();
+        sut.handle_input(GameInput::Hold);
+        sut.handle_input(GameInput::Drop); // Spawns the O
+        sut.handle_input(GameInput::MoveLeft);
+
+        // Act
+        sut.handle_input(GameInput::Hold);
+
+        // Assert
+        assert_eq!(sut.get_hold_tetromino_type(), Some(TetrominoType::O));
+        let current_tetromino = sut.get_current_tetromino().unwrap();
+        assert_eq!(current_tetromino.get_type(), TetrominoType::T);
+        assert_eq!(
+            current_tetromino.get_position(),
+            get_tetromino_start_position()
+        );
+    }
+
+    #[test]
+    fn handle_input_hold_can_be_used_once_per_tetromino() {
+        // Arrange
+        let mut sut = create_test_playfield_with_sequence(&[TetrominoType::T, TetrominoType::I]);
+        sut.spawn_tetromino();
+        sut.handle_input(GameInput::Hold);
+
+        // Act
+        sut.handle_input(GameInput::Hold);
+
+        // Assert
+        assert_eq!(sut.get_hold_tetromino_type(), Some(TetrominoType::T));
+        let current_tetromino = sut.get_current_tetromino().unwrap();
+        assert_eq!(current_tetromino.get_type(), TetrominoType::I);
+    }
+
+    #[test]
+    fn spawn_tetromino_applies_initial_hold_of_held_input() {
+        // Arrange
+        let mut sut = create_test_playfield_with_sequence(&[TetrominoType::T, TetrominoType::I]);
+        sut.set_held_inputs(&[GameInput::Hold, GameInput::RotateClockwise]);
+
+        // Act
+        let result = sut.spawn_tetromino();
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        assert_eq!(sut.get_hold_tetromino_type(), Some(TetrominoType::T));
+        let current_tetromino = sut.get_current_tetromino().unwrap();
+        assert_eq!(current_tetromino.get_type(), TetrominoType::I);
+        assert_eq!(usize::from(current_tetromino.get_rotation_index()), 1);
+    }
+
+    #[test]
+    fn lock_tetromino_without_cleared_lines_adds_pending_garbage() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.set_rules(GameRules::without_delays());
+        sut.add_pending_garbage(2, 0);
+        sut.spawn_tetromino();
+
+        // Act
+        let result = sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let bottom_y = PLAYFIELD_HEIGHT as i32 - 1;
+        for y in [bottom_y, bottom_y - 1] {
+            assert!(!sut.is_position_occupied(Position::new(0, y)));
+            assert_eq!(
+                sut.get_tetromino_type_at(Position::new(9, y)),
+                Some(GARBAGE_TETROMINO_TYPE)
+            );
+        }
+        assert_eq!(
+            sut.get_tetromino_type_at(Position::new(TETRIS_SPAWN_X + 1, bottom_y - 2)),
+            Some(TetrominoType::O)
+        );
+    }
+
+    #[test]
+    fn lock_tetromino_with_cleared_lines_keeps_garbage_pending() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.set_rules(GameRules::without_delays());
+        sut.spawn_tetromino();
+        let landing_columns: Vec<i32> = sut
+            .get_current_tetromino()
+            .unwrap()
+            .get_world_blocks()
+            .iter()
+            .map(|position| position.x)
+            .collect();
+        for y in [PLAYFIELD_HEIGHT as i32 - 1, PLAYFIELD_HEIGHT as i32 - 2] {
+            sut.fill_row(y, TetrominoType::I);
+            for x in &landing_columns {
+                sut.grid.set(Position::new(*x, y), None);
+            }
+        }
+        sut.add_pending_garbage(1, 0);
+
+        // Act
+        sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert!(!sut.is_position_occupied(Position::new(9, PLAYFIELD_HEIGHT as i32 - 1)));
+        assert_eq!(sut.pending_garbage, vec![(1, 0)]);
+    }
+
+    #[test]
+    fn lock_tetromino_with_garbage_pushing_blocks_out_of_the_top_is_game_over() {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.set_rules(GameRules::without_delays());
+        sut.grid.set(Position::new(9, 0), Some(TetrominoType::I));
+        sut.add_pending_garbage(1, 0);
+        sut.spawn_tetromino();
+
+        // Act
+        let result = sut.handle_input(GameInput::Drop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::GameOver);
+    }
+
+    #[rstest]
+    #[case(1, vec![(1, 3), (3, 5)], 0)]
+    #[case(2, vec![(3, 5)], 0)]
+    #[case(4, vec![(1, 5)], 0)]
+    #[case(7, vec![], 2)]
+    fn cancel_pending_garbage_cancels_oldest_garbage_first(
+        #[case] nr_lines: u32,
+        #[case] expected_pending_garbage: Vec<(u32, u32)>,
+        #[case] expected_nr_lines_left: u32,
+    ) {
+        // Arrange
+        let mut sut = create_test_playfield();
+        sut.add_pending_garbage(2, 3);
+        sut.add_pending_garbage(3, 5);
+
+        // Act
+        let result = sut.cancel_pending_garbage(nr_lines);
+
+        // Assert
+        assert_eq!(result, expected_nr_lines_left);
+        assert_eq!(
+            sut.get_pending_garbage(),
+            expected_pending_garbage.as_slice()
+        );
+    }
+
+    #[test]
+    fn start_level_spawns_tetromino_for_each_player_at_own_spawn_position() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+
+        // Act
+        sut.start_level(0);
+
+        // Assert
+        for (player, spawn_position) in get_test_coop_spawn_positions().iter().enumerate() {
+            let tetromino = sut.get_player_tetromino(player).unwrap();
+            assert_eq!(tetromino.get_position(), *spawn_position);
+        }
+    }
+
+    #[test]
+    fn handle_player_input_moves_only_tetromino_of_that_player() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+        sut.start_level(0);
+        let spawn_positions = get_test_coop_spawn_positions();
+
+        // Act
+        sut.handle_player_input(1, GameInput::MoveRight);
+
+        // Assert
+        let first_position = sut.get_player_tetromino(0).unwrap().get_position();
+        let second_position = sut.get_player_tetromino(1).unwrap().get_position();
+        assert_eq!(first_position, spawn_positions[0]);
+        assert_eq!(second_position, spawn_positions[1] + Position::new(1, 0));
+    }
+
+    #[test]
+    fn handle_player_input_cannot_move_into_tetromino_of_other_player() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+        sut.start_level(0);
+        let spawn_positions = get_test_coop_spawn_positions();
+
+        // Act
+        sut.handle_player_input(1, GameInput::MoveLeft);
+
+        // Assert
+        let second_position = sut.get_player_tetromino(1).unwrap().get_position();
+        assert_eq!(second_position, spawn_positions[1]);
+    }
+
+    #[test]
+    fn lock_player_tetromino_spawns_new_tetromino_for_that_player_only() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+        sut.set_rules(GameRules::without_delays());
+        sut.start_level(0);
+        sut.handle_player_input(1, GameInput::MoveRight);
+        let moved_position = sut.get_player_tetromino(1).unwrap().get_position();
+
+        // Act
+        let result = sut.handle_player_input(0, GameInput::Drop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let spawn_positions = get_test_coop_spawn_positions();
+        let first_position = sut.get_player_tetromino(0).unwrap().get_position();
+        let second_position = sut.get_player_tetromino(1).unwrap().get_position();
+        assert_eq!(first_position, spawn_positions[0]);
+        assert_eq!(second_position, moved_position);
+    }
+
+    #[test]
+    fn spawn_player_tetromino_onto_tetromino_of_other_player_is_game_over() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+        sut.start_level(0);
+        sut.players[1].tetromino = None;
+        sut.handle_player_input(0, GameInput::MoveRight);
+
+        // Act
+        let result = sut.spawn_player_tetromino(1);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::GameOver);
+    }
+
+    #[test]
+    fn lock_player_tetromino_pushes_up_tetromino_of_other_player_out_of_garbage() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+        sut.set_rules(GameRules::without_delays());
+        sut.start_level(0);
+        sut.handle_player_input(1, GameInput::SonicDrop);
+        let landed_position = sut.get_player_tetromino(1).unwrap().get_position();
+        sut.add_pending_garbage(1, COOP_PLAYFIELD_WIDTH - 1);
+
+        // Act
+        let result = sut.handle_player_input(0, GameInput::Drop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let position = sut.get_player_tetromino(1).unwrap().get_position();
+        assert_eq!(position, landed_position + Position::new(0, -1));
+    }
+
+    #[test]
+    fn lock_player_tetromino_is_game_over_when_garbage_pushes_tetromino_of_other_player_out() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+        sut.set_rules(GameRules::without_delays());
+        sut.start_level(0);
+        // Two garbage lines push the block right under the second tetromino into its top row
+        let below_second_tetromino =
+            get_top_block(sut.get_player_tetromino(1).unwrap()).translate(0, 2);
+        sut.grid
+            .set(below_second_tetromino, Some(GARBAGE_TETROMINO_TYPE));
+        sut.add_pending_garbage(2, COOP_PLAYFIELD_WIDTH - 1);
+
+        // Act
+        let result = sut.handle_player_input(0, GameInput::Drop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::GameOver);
+    }
+
+    #[test]
+    fn lock_player_tetromino_pushes_up_tetromino_of_other_player_out_of_dropped_blocks() {
+        // Arrange
+        let mut sut = create_test_coop_playfield();
+        sut.set_rules(GameRules::without_delays());
+        sut.start_level(0);
+        let first_columns: Vec<i32> = sut
+            .get_player_tetromino(0)
+            .unwrap()
+            .get_world_blocks()
+            .iter()
+            .map(|position| position.x)
+            .collect();
+        let bottom = PLAYFIELD_HEIGHT as i32 - 1;
+        for y in [bottom - 1, bottom] {
+            for x in 0..COOP_PLAYFIELD_WIDTH as i32 {
+                if !first_columns.contains(&x) {
+                    sut.grid.set(Position::new(x, y), Some(TetrominoType::I));
+                }
+            }
+        }
+        // The tetromino of the second player rests on the full lines, under a block
+        let second_tetromino = sut.players[1].tetromino.as_mut().unwrap();
+        while get_top_block(second_tetromino).y < bottom - 3 {
+            second_tetromino.move_down();
+        }
+        let resting_position = second_tetromino.get_position();
+        let above_second_tetromino = get_top_block(second_tetromino).translate(0, -1);
+        sut.grid.set(above_second_tetromino, Some(TetrominoType::I));
+
+        // Act
+        let result = sut.handle_player_input(0, GameInput::Drop);
+
+        // Assert
+        assert_eq!(result, PlayfieldState::Playing);
+        let position = sut.get_player_tetromino(1).unwrap().get_position();
+        assert_eq!(position, resting_position + Position::new(0, -1));
+    }
+
+    fn get_top_block(tetromino: &TetrominoInstance) -> Position {
+        tetromino
+            .get_world_blocks()
+            .into_iter()
+            .min_by_key(|position| position.y)
+            .unwrap()
+    }
+
+    /// Spawn positions that are next to each other, so the tetrominoes of the players touch.
+    fn get_test_coop_spawn_positions() -> [Position; 2] {
+        [Position::new(0, 0), Position::new(2, 0)]
+    }
+
+    fn create_test_coop_playfield() -> Playfield<FixedTetrominoGenerator> {
+        let dimensions = Dimensions::new(COOP_PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT);
+        Playfield::with_spawn_positions(
+            dimensions,
+            FixedTetrominoGenerator::new(TetrominoType::O),
+            Arc::new(EventQueue::new()),
+            &get_test_coop_spawn_positions(),
+        )
+    }
 }
@@ -0,0 +1,107 @@
+/// The snapshots of a practice game, one for the start and one for every locked tetromino, and
+/// the one the game is currently at. Undo and redo move between them, a new snapshot after an
+/// undo replaces the ones that could be redone.
+pub struct PracticeHistory<S> {
+    snapshots: Vec<S>,
+    current_index: usize,
+}
+
+impl<S> PracticeHistory<S> {
+    pub fn new(initial_snapshot: S) -> Self {
+        Self {
+            snapshots: vec![initial_snapshot],
+            current_index: 0,
+        }
+    }
+
+    pub fn get_current(&self) -> &S {
+        &self.snapshots[self.current_index]
+    }
+
+    pub fn push(&mut self, snapshot: S) {
+        self.snapshots.truncate(self.current_index + 1);
+        self.snapshots.push(snapshot);
+        self.current_index += 1;
+    }
+
+    /// Steps back to the previous snapshot, returns None when there is none.
+    pub fn undo(&mut self) -> Option<&S> {
+        if self.current_index == 0 {
+            return None;
+        }
+
+        self.current_index -= 1;
+        Some(self.get_current())
+    }
+
+    /// Steps forward to the snapshot that was undone last, returns None when there is none.
+    pub fn redo(&mut self) -> Option<&S> {
+        if self.current_index + 1 >= self.snapshots.len() {
+            return None;
+        }
+
+        self.current_index += 1;
+        Some(self.get_current())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_returns_previous_snapshots_until_the_initial_one() {
+        // Arrange
+        let mut sut = PracticeHistory::new(0);
+        sut.push(1);
+        sut.push(2);
+
+        // Act
+        let results = [
+            sut.undo().copied(),
+            sut.undo().copied(),
+            sut.undo().copied(),
+        ];
+
+        // Assert
+        assert_eq!(results, [Some(1), Some(0), None]);
+        assert_eq!(*sut.get_current(), 0);
+    }
+
+    #[test]
+    fn redo_returns_undone_snapshots() {
+        // Arrange
+        let mut sut = PracticeHistory::new(0);
+        sut.push(1);
+        sut.push(2);
+        sut.undo();
+        sut.undo();
+
+        // Act
+        let results = [
+            sut.redo().copied(),
+            sut.redo().copied(),
+            sut.redo().copied(),
+        ];
+
+        // Assert
+        assert_eq!(results, [Some(1), Some(2), None]);
+        assert_eq!(*sut.get_current(), 2);
+    }
+
+    #[test]
+    fn push_after_undo_discards_snapshots_that_could_be_redone() {
+        // Arrange
+        let mut sut = PracticeHistory::new(0);
+        sut.push(1);
+        sut.push(2);
+        sut.undo();
+
+        // Act
+        sut.push(3);
+
+        // Assert
+        assert_eq!(sut.redo(), None);
+        assert_eq!(sut.undo(), Some(&1));
+    }
+}
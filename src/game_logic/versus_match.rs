@@ -0,0 +1,297 @@
+use crate::common::SeededRng;
+use crate::constants::PLAYFIELD_WIDTH;
+use crate::events::Event;
+use crate::game_logic::{get_garbage_lines_for_events, Game, GameState};
+use crate::graphics::{Display, HudRenderer, PlayfieldRenderer, PlayfieldView};
+use crate::gui::GameInput;
+use crate::tetromino::TetrominoGenerator;
+use std::time::Duration;
+
+pub const NR_VERSUS_PLAYERS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersusState {
+    Playing,
+    Won(usize), // Index of the winning player
+    Draw,
+}
+
+/// Two games played against each other. Lines cleared by one player first cancel the garbage
+/// pending for that player, the rest is sent as garbage to the other player. The first player to
+/// top out loses.
+pub struct VersusMatch<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> {
+    games: [Game<R, H, T>; NR_VERSUS_PLAYERS],
+    garbage_hole_rng: SeededRng,
+    state: VersusState,
+}
+
+impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator> VersusMatch<R, H, T> {
+    /// The seed determines the columns of the holes in the garbage lines.
+    pub fn new(games: [Game<R, H, T>; NR_VERSUS_PLAYERS], seed: u64) -> Self {
+        Self {
+            games,
+            garbage_hole_rng: SeededRng::new(seed),
+            state: VersusState::Playing,
+        }
+    }
+
+    pub fn get_state(&self) -> VersusState {
+        self.state
+    }
+
+    pub fn get_playfield_view(&self, player: usize) -> PlayfieldView<'_> {
+        self.games[player].get_playfield_view()
+    }
+
+    #[cfg(test)]
+    pub fn get_game(&self, player: usize) -> &Game<R, H, T> {
+        &self.games[player]
+    }
+
+    #[cfg(test)]
+    pub fn get_game_mut(&mut self, player: usize) -> &mut Game<R, H, T> {
+        &mut self.games[player]
+    }
+
+    pub fn handle_input(&mut self, player: usize, input: GameInput) {
+        if self.state == VersusState::Playing {
+            self.games[player].handle_input(input);
+        }
+    }
+
+    pub fn set_held_inputs(&mut self, player: usize, held_inputs: &[GameInput]) {
+        self.games[player].set_held_inputs(held_inputs);
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        if self.state != VersusState::Playing {
+            return;
+        }
+
+        for sender in 0..NR_VERSUS_PLAYERS {
+            let events = self.games[sender].update(delta_time);
+            self.send_garbage(sender, &events);
+        }
+        self.state = self.determine_state();
+    }
+
+    fn send_garbage(&mut self, sender: usize, events: &[Event]) {
+        let nr_attack_lines = get_garbage_lines_for_events(events);
+        if nr_attack_lines > 0 {
+            let nr_lines = self.games[sender].cancel_garbage(nr_attack_lines);
+            if nr_lines > 0 {
+                let hole_column = self.garbage_hole_rng.next_index(PLAYFIELD_WIDTH);
+                let receiver = (sender + 1) % NR_VERSUS_PLAYERS;
+                self.games[receiver].receive_garbage(nr_lines, hole_column);
+            }
+        }
+    }
+
+    fn determine_state(&self) -> VersusState {
+        let topped_out: Vec<bool> = self
+            .games
+            .iter()
+            .map(|game| *game.get_game_state() == GameState::GameOver)
+            .collect();
+
+        match (topped_out[0], topped_out[1]) {
+            (false, false) => VersusState::Playing,
+            (true, true) => VersusState::Draw,
+            (true, false) => VersusState::Won(1),
+            (false, true) => VersusState::Won(0),
+        }
+    }
+
+    /// Draws the boards of both players without clearing or presenting the display.
+    pub fn draw_boards(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        for game in &mut self.games {
+            game.draw_board(display)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event, EventQueue};
+    use crate::game_logic::PlayfieldState;
+    use crate::graphics::{MockDisplay, MockHudRenderer, MockPlayfieldRenderer};
+    use crate::test_helpers::*;
+    use crate::tetromino::FixedTetrominoGenerator;
+    use std::sync::Arc;
+
+    type TestVersusMatch =
+        VersusMatch<MockPlayfieldRenderer, MockHudRenderer, FixedTetrominoGenerator>;
+
+    #[test]
+    fn update_sends_garbage_for_cleared_lines_to_opponent() {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let mut sut = create_test_versus_match_with_event_queue(event_queue.clone());
+        event_queue.push_back(Event::LinesCleared(4));
+
+        // Act
+        sut.update(Duration::from_millis(1));
+
+        // Assert
+        let pending_garbage = sut.get_game(1).get_playfield().get_pending_garbage();
+        assert_eq!(pending_garbage.len(), 1);
+        assert_eq!(pending_garbage[0].0, 4);
+        assert!(sut
+            .get_game(0)
+            .get_playfield()
+            .get_pending_garbage()
+            .is_empty());
+    }
+
+    #[rstest::rstest]
+    #[case(1, 3, &[])]
+    #[case(6, 0, &[(2, 2)])]
+    fn update_cancels_pending_garbage_before_sending_garbage(
+        #[case] nr_pending_lines: u32,
+        #[case] expected_nr_lines_sent: u32,
+        #[case] expected_pending_garbage: &[(u32, u32)],
+    ) {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let mut sut = create_test_versus_match_with_event_queue(event_queue.clone());
+        sut.get_game_mut(0).receive_garbage(nr_pending_lines, 2);
+        event_queue.push_back(Event::LinesCleared(4));
+
+        // Act
+        sut.update(Duration::from_millis(1));
+
+        // Assert
+        let pending_garbage = sut.get_game(0).get_playfield().get_pending_garbage();
+        assert_eq!(pending_garbage, expected_pending_garbage);
+        let sent_garbage: u32 = sut
+            .get_game(1)
+            .get_playfield()
+            .get_pending_garbage()
+            .iter()
+            .map(|(nr_lines, _)| nr_lines)
+            .sum();
+        assert_eq!(sent_garbage, expected_nr_lines_sent);
+    }
+
+    #[test]
+    fn update_does_not_send_garbage_for_single_line() {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let mut sut = create_test_versus_match_with_event_queue(event_queue.clone());
+        event_queue.push_back(Event::LinesCleared(1));
+
+        // Act
+        sut.update(Duration::from_millis(1));
+
+        // Assert
+        assert!(sut
+            .get_game(1)
+            .get_playfield()
+            .get_pending_garbage()
+            .is_empty());
+    }
+
+    #[rstest::rstest]
+    #[case(0, VersusState::Won(1))]
+    #[case(1, VersusState::Won(0))]
+    fn update_when_player_tops_out_opponent_wins(
+        #[case] losing_player: usize,
+        #[case] expected: VersusState,
+    ) {
+        // Arrange
+        let mut sut = create_test_versus_match();
+        sut.get_game_mut(losing_player)
+            .get_playfield_mut()
+            .set_state(PlayfieldState::GameOver);
+
+        // Act
+        sut.update(Duration::from_millis(1));
+
+        // Assert
+        assert_eq!(sut.get_state(), expected);
+    }
+
+    #[test]
+    fn update_when_both_players_top_out_is_draw() {
+        // Arrange
+        let mut sut = create_test_versus_match();
+        for player in 0..NR_VERSUS_PLAYERS {
+            sut.get_game_mut(player)
+                .get_playfield_mut()
+                .set_state(PlayfieldState::GameOver);
+        }
+
+        // Act
+        sut.update(Duration::from_millis(1));
+
+        // Assert
+        assert_eq!(sut.get_state(), VersusState::Draw);
+    }
+
+    #[test]
+    fn handle_input_only_affects_game_of_player() {
+        // Arrange
+        let mut sut = create_test_versus_match();
+        let start_position = get_tetromino_start_position();
+
+        // Act
+        sut.handle_input(1, GameInput::MoveLeft);
+
+        // Assert
+        let position_of = |sut: &TestVersusMatch, player: usize| {
+            sut.get_game(player)
+                .get_playfield()
+                .get_current_tetromino()
+                .unwrap()
+                .get_position()
+        };
+        assert_eq!(position_of(&sut, 0), start_position);
+        assert_eq!(position_of(&sut, 1).x, start_position.x - 1);
+    }
+
+    #[test]
+    fn draw_boards_draws_both_games() {
+        // Arrange
+        let mut sut = create_test_versus_match();
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw_boards(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        for player in 0..NR_VERSUS_PLAYERS {
+            let game = sut.get_game(player);
+            assert_eq!(game.get_playfield_renderer().get_draw_calls().len(), 1);
+        }
+    }
+
+    fn create_test_versus_match() -> TestVersusMatch {
+        create_test_versus_match_with_event_queue(Arc::new(EventQueue::new()))
+    }
+
+    fn create_test_versus_match_with_event_queue(
+        first_player_event_queue: Arc<EventQueue>,
+    ) -> TestVersusMatch {
+        let games = [
+            create_started_test_game(first_player_event_queue),
+            create_started_test_game(Arc::new(EventQueue::new())),
+        ];
+        VersusMatch::new(games, 42)
+    }
+
+    fn create_started_test_game(event_queue: Arc<EventQueue>) -> TestGame {
+        let playfield = create_test_playfield_with_event_queue(event_queue.clone());
+        let mut game = Game::new(
+            playfield,
+            MockPlayfieldRenderer::new(),
+            MockHudRenderer::new(),
+            event_queue,
+            create_test_high_score_manager(),
+        );
+        game.start_level(0);
+        game
+    }
+}
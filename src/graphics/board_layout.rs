@@ -0,0 +1,74 @@
+use crate::constants::*;
+
+/// Window positions in pixels of the parts that make up the board of a single player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardLayout {
+    pub playfield_x: u32,
+    pub playfield_y: u32,
+    pub playfield_width: u32, // In blocks
+    pub next_tetromino_x: u32,
+    pub next_tetromino_y: u32,
+    pub hud_text_x: u32,
+    pub hud_text_y: u32,
+    /// Whether the HUD shows the statistics panel, which needs the room below the HUD text and
+    /// the next tetromino.
+    pub show_statistics: bool,
+}
+
+impl BoardLayout {
+    /// The layout for a single game, with the playfield centered in the window.
+    pub fn single_player() -> Self {
+        Self {
+            playfield_x: PLAYFIELD_OFFSET_X,
+            playfield_y: PLAYFIELD_OFFSET_Y,
+            playfield_width: PLAYFIELD_WIDTH,
+            next_tetromino_x: NEXT_TETROMINO_OFFSET_X,
+            next_tetromino_y: NEXT_TETROMINO_OFFSET_Y,
+            hud_text_x: HUD_TEXT_OFFSET_X,
+            hud_text_y: HUD_TEXT_OFFSET_Y,
+            show_statistics: true,
+        }
+    }
+
+    /// The layout for the player on the left side of the window in a versus game.
+    pub fn versus_left() -> Self {
+        Self {
+            playfield_x: VERSUS_LEFT_PLAYFIELD_OFFSET_X,
+            playfield_y: PLAYFIELD_OFFSET_Y,
+            playfield_width: PLAYFIELD_WIDTH,
+            next_tetromino_x: VERSUS_LEFT_NEXT_TETROMINO_OFFSET_X,
+            next_tetromino_y: NEXT_TETROMINO_OFFSET_Y,
+            hud_text_x: VERSUS_LEFT_HUD_TEXT_OFFSET_X,
+            hud_text_y: VERSUS_LEFT_HUD_TEXT_OFFSET_Y,
+            show_statistics: false,
+        }
+    }
+
+    /// The layout for the player on the right side of the window in a versus game.
+    pub fn versus_right() -> Self {
+        Self {
+            playfield_x: VERSUS_RIGHT_PLAYFIELD_OFFSET_X,
+            playfield_y: PLAYFIELD_OFFSET_Y,
+            playfield_width: PLAYFIELD_WIDTH,
+            next_tetromino_x: VERSUS_RIGHT_NEXT_TETROMINO_OFFSET_X,
+            next_tetromino_y: NEXT_TETROMINO_OFFSET_Y,
+            hud_text_x: VERSUS_RIGHT_HUD_TEXT_OFFSET_X,
+            hud_text_y: VERSUS_RIGHT_HUD_TEXT_OFFSET_Y,
+            show_statistics: false,
+        }
+    }
+
+    /// The layout for a co-op game, with the wide shared playfield centered in the window.
+    pub fn coop() -> Self {
+        Self {
+            playfield_x: COOP_PLAYFIELD_OFFSET_X,
+            playfield_y: PLAYFIELD_OFFSET_Y,
+            playfield_width: COOP_PLAYFIELD_WIDTH,
+            next_tetromino_x: COOP_NEXT_TETROMINO_OFFSET_X,
+            next_tetromino_y: NEXT_TETROMINO_OFFSET_Y,
+            hud_text_x: HUD_TEXT_OFFSET_X,
+            hud_text_y: HUD_TEXT_OFFSET_Y,
+            show_statistics: false,
+        }
+    }
+}
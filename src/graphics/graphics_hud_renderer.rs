@@ -1,13 +1,20 @@
 use crate::common::{Position, RotationIndex};
 use crate::constants::*;
-use crate::graphics::{Color, Display, HudRenderer, HudView};
-use crate::tetromino::TetrominoDefinitions;
+use crate::graphics::{BoardLayout, Color, Display, HudRenderer, HudView};
+use crate::tetromino::{TetrominoDefinitions, TetrominoType};
+use strum::IntoEnumIterator;
 
-pub struct GraphicsHudRenderer;
+pub struct GraphicsHudRenderer {
+    layout: BoardLayout,
+}
 
 impl GraphicsHudRenderer {
     pub fn new() -> Self {
-        Self
+        Self::with_layout(BoardLayout::single_player())
+    }
+
+    pub fn with_layout(layout: BoardLayout) -> Self {
+        Self { layout }
     }
 
     fn draw_level<D: Display + ?Sized>(
@@ -18,8 +25,8 @@ impl GraphicsHudRenderer {
         let level = hud_view.current_level + 1; // Display as 1-based
         display.draw_text(
             &format!("Level: {level}"),
-            LEVEL_OFFSET_X,
-            LEVEL_OFFSET_Y,
+            self.layout.hud_text_x,
+            self.layout.hud_text_y + 2 * HUD_TEXT_LINE_HEIGHT,
             Color::WHITE,
         )
     }
@@ -31,17 +38,100 @@ impl GraphicsHudRenderer {
     ) -> Result<(), String> {
         display.draw_text(
             &format!("Lines: {}", hud_view.total_lines_cleared),
-            LINES_OFFSET_X,
-            LINES_OFFSET_Y,
+            self.layout.hud_text_x,
+            self.layout.hud_text_y + HUD_TEXT_LINE_HEIGHT,
+            Color::WHITE,
+        )
+    }
+
+    fn draw_finesse_faults<D: Display + ?Sized>(
+        &self,
+        hud_view: &HudView,
+        display: &mut D,
+    ) -> Result<(), String> {
+        display.draw_text(
+            &format!("Faults: {}", hud_view.statistics.nr_finesse_faults),
+            self.layout.hud_text_x,
+            self.layout.hud_text_y + 3 * HUD_TEXT_LINE_HEIGHT,
+            Color::WHITE,
+        )
+    }
+
+    fn draw_hold_tetromino<D: Display + ?Sized>(
+        &self,
+        hud_view: &HudView,
+        display: &mut D,
+    ) -> Result<(), String> {
+        let hold = hud_view
+            .hold_tetromino_type
+            .map_or('-', |tetromino_type| tetromino_type.to_char());
+        display.draw_text(
+            &format!("Hold: {hold}"),
+            self.layout.hud_text_x,
+            self.layout.hud_text_y + 4 * HUD_TEXT_LINE_HEIGHT,
             Color::WHITE,
         )
     }
 
+    /// Draws the statistics of the game below the HUD text and a histogram of the spawned
+    /// tetrominoes below the next tetromino.
+    fn draw_statistics<D: Display + ?Sized>(
+        &self,
+        hud_view: &HudView,
+        display: &mut D,
+    ) -> Result<(), String> {
+        let statistics = &hud_view.statistics;
+        let lines = [
+            format!("Pieces: {}", statistics.nr_pieces),
+            format!("PPS: {:.2}", statistics.get_pieces_per_second()),
+            format!("KPP: {:.2}", statistics.get_inputs_per_piece()),
+            format!("Singles: {}", statistics.get_nr_line_clears(1)),
+            format!("Doubles: {}", statistics.get_nr_line_clears(2)),
+            format!("Triples: {}", statistics.get_nr_line_clears(3)),
+            format!("Tetrises: {}", statistics.get_nr_line_clears(4)),
+            format!("TRT: {:.0}%", statistics.get_tetris_rate() * 100.0),
+        ];
+        for (i, line) in lines.iter().enumerate() {
+            let y = self.layout.hud_text_y + (i as u32 + 5) * HUD_TEXT_LINE_HEIGHT;
+            display.draw_text(line, self.layout.hud_text_x, y, Color::WHITE)?;
+        }
+
+        self.draw_spawned_histogram(hud_view, display)
+    }
+
+    fn draw_spawned_histogram<D: Display + ?Sized>(
+        &self,
+        hud_view: &HudView,
+        display: &mut D,
+    ) -> Result<(), String> {
+        let statistics = &hud_view.statistics;
+        let max_nr_spawned = TetrominoType::iter()
+            .map(|tetromino_type| statistics.get_nr_spawned(tetromino_type))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let x = self.layout.next_tetromino_x;
+        let count_x = x + 2 * BLOCK_SIZE;
+        let bar_x = count_x + 4 * CHAR_WIDTH;
+        let top_y = self.layout.next_tetromino_y + NEXT_TETROMINO_AREA_HEIGHT + 2 * BLOCK_SIZE;
+        for (i, tetromino_type) in TetrominoType::iter().enumerate() {
+            let y = top_y + i as u32 * HUD_TEXT_LINE_HEIGHT;
+            let nr_spawned = statistics.get_nr_spawned(tetromino_type);
+            display.draw_block(Position::new(x as i32, y as i32), tetromino_type)?;
+            display.draw_text(&nr_spawned.to_string(), count_x, y, Color::WHITE)?;
+            let bar_width = nr_spawned * STATISTICS_BAR_MAX_WIDTH / max_nr_spawned;
+            if bar_width > 0 {
+                display.draw_rectangle(bar_x, y, bar_width, BLOCK_SIZE, Color::GRAY)?;
+            }
+        }
+        Ok(())
+    }
+
     fn draw_game_over<D: Display + ?Sized>(&self, display: &mut D) -> Result<(), String> {
-        let x: u32 = (PLAYFIELD_OFFSET_X as i32
-            + (PLAYFIELD_WIDTH as i32 * BLOCK_SIZE as i32 - GAME_OVER_WIDTH as i32) / 2)
+        let x: u32 = (self.layout.playfield_x as i32
+            + (self.layout.playfield_width as i32 * BLOCK_SIZE as i32 - GAME_OVER_WIDTH as i32) / 2)
             as u32;
-        let y: u32 = (PLAYFIELD_OFFSET_Y as i32
+        let y: u32 = (self.layout.playfield_y as i32
             + (PLAYFIELD_HEIGHT as i32 * BLOCK_SIZE as i32 - GAME_OVER_HEIGHT as i32) / 2)
             as u32;
 
@@ -62,8 +152,8 @@ impl GraphicsHudRenderer {
         hud_view: &HudView,
         display: &mut D,
     ) -> Result<(), String> {
-        draw_label_for_next_tetromino(display)?;
-        draw_border_for_next_tetromino(display)?;
+        draw_label_for_next_tetromino(&self.layout, display)?;
+        draw_border_for_next_tetromino(&self.layout, display)?;
         self.draw_tetromino_for_next_tetromino(hud_view, display)
     }
 
@@ -96,8 +186,8 @@ impl GraphicsHudRenderer {
             - (min_y * BLOCK_SIZE as i32);
 
         let preview_base_position = Position::new(
-            NEXT_TETROMINO_OFFSET_X as i32 + offset_x_px,
-            NEXT_TETROMINO_OFFSET_Y as i32 + offset_y_px,
+            self.layout.next_tetromino_x as i32 + offset_x_px,
+            self.layout.next_tetromino_y as i32 + offset_y_px,
         );
 
         // Draw each block of the tetromino
@@ -115,47 +205,61 @@ impl GraphicsHudRenderer {
     ) -> Result<(), String> {
         display.draw_text(
             &format!("Score: {}", hud_view.score),
-            SCORE_OFFSET_X,
-            SCORE_OFFSET_Y,
+            self.layout.hud_text_x,
+            self.layout.hud_text_y,
             Color::WHITE,
         )
     }
 }
 
-fn draw_label_for_next_tetromino<D: Display + ?Sized>(display: &mut D) -> Result<(), String> {
+impl Default for GraphicsHudRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn draw_label_for_next_tetromino<D: Display + ?Sized>(
+    layout: &BoardLayout,
+    display: &mut D,
+) -> Result<(), String> {
     const TEXT: &str = "NEXT";
     const TEXT_WIDTH: u32 = TEXT.len() as u32 * CHAR_WIDTH;
-    let x = NEXT_TETROMINO_OFFSET_X + (NEXT_TETROMINO_AREA_WIDTH - TEXT_WIDTH) / 2;
-    let y = NEXT_TETROMINO_OFFSET_Y - CHAR_HEIGHT;
+    let x = layout.next_tetromino_x + (NEXT_TETROMINO_AREA_WIDTH - TEXT_WIDTH) / 2;
+    let y = layout.next_tetromino_y - CHAR_HEIGHT;
     display.draw_text(TEXT, x, y, Color::WHITE)
 }
 
-fn draw_border_for_next_tetromino<D: Display + ?Sized>(display: &mut D) -> Result<(), String> {
+fn draw_border_for_next_tetromino<D: Display + ?Sized>(
+    layout: &BoardLayout,
+    display: &mut D,
+) -> Result<(), String> {
     let border_color = Color::WHITE;
+    let next_x = layout.next_tetromino_x;
+    let next_y = layout.next_tetromino_y;
     display.draw_rectangle(
-        NEXT_TETROMINO_OFFSET_X - 1,
-        NEXT_TETROMINO_OFFSET_Y - 1,
+        next_x - 1,
+        next_y - 1,
         NEXT_TETROMINO_AREA_WIDTH + 2,
         1,
         border_color,
     )?;
     display.draw_rectangle(
-        NEXT_TETROMINO_OFFSET_X - 1,
-        NEXT_TETROMINO_OFFSET_Y + NEXT_TETROMINO_AREA_HEIGHT,
+        next_x - 1,
+        next_y + NEXT_TETROMINO_AREA_HEIGHT,
         NEXT_TETROMINO_AREA_WIDTH + 2,
         1,
         border_color,
     )?;
     display.draw_rectangle(
-        NEXT_TETROMINO_OFFSET_X - 1,
-        NEXT_TETROMINO_OFFSET_Y - 1,
+        next_x - 1,
+        next_y - 1,
         1,
         NEXT_TETROMINO_AREA_HEIGHT + 2,
         border_color,
     )?;
     display.draw_rectangle(
-        NEXT_TETROMINO_OFFSET_X + NEXT_TETROMINO_AREA_WIDTH,
-        NEXT_TETROMINO_OFFSET_Y - 1,
+        next_x + NEXT_TETROMINO_AREA_WIDTH,
+        next_y - 1,
         1,
         NEXT_TETROMINO_AREA_HEIGHT + 2,
         border_color,
@@ -167,7 +271,12 @@ impl HudRenderer for GraphicsHudRenderer {
         self.draw_score(hud_view, display)?;
         self.draw_lines_cleared(hud_view, display)?;
         self.draw_level(hud_view, display)?;
+        self.draw_finesse_faults(hud_view, display)?;
+        self.draw_hold_tetromino(hud_view, display)?;
         self.draw_widget_for_next_tetromino(hud_view, display)?;
+        if self.layout.show_statistics {
+            self.draw_statistics(hud_view, display)?;
+        }
 
         if hud_view.show_game_over {
             self.draw_game_over(display)?;
@@ -179,8 +288,10 @@ impl HudRenderer for GraphicsHudRenderer {
 
 #[cfg(test)]
 mod tests {
+    use crate::game_logic::GameStatistics;
     use crate::graphics::MockDisplay;
     use crate::tetromino::TetrominoType;
+    use rstest::rstest;
 
     use super::*;
 
@@ -190,9 +301,11 @@ mod tests {
         let sut = GraphicsHudRenderer::new();
         let hud_view = HudView {
             next_tetromino_type: TetrominoType::J,
+            hold_tetromino_type: None,
             current_level: 1,
             total_lines_cleared: 0,
             score: 0,
+            statistics: GameStatistics::new(),
             show_game_over: false,
         };
         let mut display = MockDisplay::new();
@@ -215,9 +328,11 @@ mod tests {
         let sut = GraphicsHudRenderer::new();
         let hud_view = HudView {
             next_tetromino_type: TetrominoType::T,
+            hold_tetromino_type: None,
             current_level: 3,
             total_lines_cleared: 25,
             score: 0,
+            statistics: GameStatistics::new(),
             show_game_over: false,
         };
         let mut display = MockDisplay::new();
@@ -234,15 +349,79 @@ mod tests {
         assert!(level_text_drawn);
     }
 
+    #[test]
+    fn hud_renderer_draws_finesse_faults_text() {
+        // Arrange
+        let sut = GraphicsHudRenderer::new();
+        let hud_view = HudView {
+            next_tetromino_type: TetrominoType::T,
+            hold_tetromino_type: None,
+            current_level: 0,
+            total_lines_cleared: 0,
+            score: 0,
+            statistics: GameStatistics {
+                nr_finesse_faults: 7,
+                ..GameStatistics::new()
+            },
+            show_game_over: false,
+        };
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&hud_view, &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let faults_text_drawn = display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text == "Faults: 7");
+        assert!(faults_text_drawn);
+    }
+
+    #[rstest]
+    #[case(Some(TetrominoType::L), "Hold: L")]
+    #[case(None, "Hold: -")]
+    fn hud_renderer_draws_hold_text(
+        #[case] hold_tetromino_type: Option<TetrominoType>,
+        #[case] expected_text: &str,
+    ) {
+        // Arrange
+        let sut = GraphicsHudRenderer::new();
+        let hud_view = HudView {
+            next_tetromino_type: TetrominoType::T,
+            hold_tetromino_type,
+            current_level: 0,
+            total_lines_cleared: 0,
+            score: 0,
+            statistics: GameStatistics::new(),
+            show_game_over: false,
+        };
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&hud_view, &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let hold_text_drawn = display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text == expected_text);
+        assert!(hold_text_drawn);
+    }
+
     #[test]
     fn hud_renderer_draws_lines_text() {
         // Arrange
         let sut = GraphicsHudRenderer::new();
         let hud_view = HudView {
             next_tetromino_type: TetrominoType::J,
+            hold_tetromino_type: None,
             current_level: 1,
             total_lines_cleared: 15,
             score: 0,
+            statistics: GameStatistics::new(),
             show_game_over: false,
         };
         let mut display = MockDisplay::new();
@@ -265,9 +444,11 @@ mod tests {
         let sut = GraphicsHudRenderer::new();
         let hud_view = HudView {
             next_tetromino_type: TetrominoType::O,
+            hold_tetromino_type: None,
             current_level: 2,
             total_lines_cleared: 8,
             score: 500,
+            statistics: GameStatistics::new(),
             show_game_over: true,
         };
         let mut display = MockDisplay::new();
@@ -297,9 +478,11 @@ mod tests {
         let sut = GraphicsHudRenderer::new();
         let hud_view = HudView {
             next_tetromino_type: TetrominoType::I,
+            hold_tetromino_type: None,
             current_level: 2,
             total_lines_cleared: 8,
             score: 1240,
+            statistics: GameStatistics::new(),
             show_game_over: false,
         };
         let mut display = MockDisplay::new();
@@ -322,9 +505,11 @@ mod tests {
         let sut = GraphicsHudRenderer::new();
         let hud_view = HudView {
             next_tetromino_type: TetrominoType::T,
+            hold_tetromino_type: None,
             current_level: 1,
             total_lines_cleared: 5,
             score: 200,
+            statistics: GameStatistics::new(),
             show_game_over: true,
         };
         let mut display = MockDisplay::new();
@@ -335,10 +520,145 @@ mod tests {
         // Assert
         assert!(result.is_ok());
 
-        let tetromino_blocks_drawn = display
-            .drawn_blocks
-            .iter()
-            .any(|(_, tetromino_type)| *tetromino_type == TetrominoType::T);
+        let next_area_bottom = (NEXT_TETROMINO_OFFSET_Y + NEXT_TETROMINO_AREA_HEIGHT) as i32;
+        let tetromino_blocks_drawn =
+            display
+                .drawn_blocks
+                .iter()
+                .any(|(position, tetromino_type)| {
+                    *tetromino_type == TetrominoType::T && position.y < next_area_bottom
+                });
         assert!(!tetromino_blocks_drawn);
     }
+
+    #[test]
+    fn hud_renderer_draws_score_at_text_position_of_layout() {
+        // Arrange
+        let layout = BoardLayout::versus_left();
+        let sut = GraphicsHudRenderer::with_layout(layout);
+        let hud_view = HudView {
+            next_tetromino_type: TetrominoType::I,
+            hold_tetromino_type: None,
+            current_level: 0,
+            total_lines_cleared: 0,
+            score: 100,
+            statistics: GameStatistics::new(),
+            show_game_over: false,
+        };
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&hud_view, &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let score_text_drawn = display.drawn_text.iter().any(|(text, x, y, _)| {
+            text == "Score: 100" && *x == layout.hud_text_x && *y == layout.hud_text_y
+        });
+        assert!(score_text_drawn);
+    }
+
+    #[test]
+    fn hud_renderer_draws_statistics_panel() {
+        // Arrange
+        let sut = GraphicsHudRenderer::new();
+        let mut statistics = GameStatistics::new();
+        statistics.play_time = std::time::Duration::from_secs(4);
+        statistics.nr_pieces = 6;
+        statistics.nr_inputs = 15;
+        statistics.nr_line_clears = [2, 0, 0, 1];
+        let hud_view = HudView {
+            next_tetromino_type: TetrominoType::T,
+            hold_tetromino_type: None,
+            current_level: 0,
+            total_lines_cleared: 6,
+            score: 0,
+            statistics,
+            show_game_over: false,
+        };
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&hud_view, &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let drawn_texts: Vec<&str> = display
+            .drawn_text
+            .iter()
+            .map(|(text, _, _, _)| text.as_str())
+            .collect();
+        for expected in [
+            "Pieces: 6",
+            "PPS: 1.50",
+            "KPP: 2.50",
+            "Singles: 2",
+            "TRT: 67%",
+        ] {
+            assert!(drawn_texts.contains(&expected), "{expected} not drawn");
+        }
+    }
+
+    #[test]
+    fn hud_renderer_draws_longest_bar_for_most_spawned_tetromino() {
+        // Arrange
+        let sut = GraphicsHudRenderer::new();
+        let mut statistics = GameStatistics::new();
+        statistics.handle_tetromino_spawned(TetrominoType::S);
+        statistics.handle_tetromino_spawned(TetrominoType::S);
+        statistics.handle_tetromino_spawned(TetrominoType::L);
+        let hud_view = HudView {
+            next_tetromino_type: TetrominoType::T,
+            hold_tetromino_type: None,
+            current_level: 0,
+            total_lines_cleared: 0,
+            score: 0,
+            statistics,
+            show_game_over: false,
+        };
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&hud_view, &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let bar_widths: Vec<u32> = display
+            .drawn_rectangles
+            .iter()
+            .filter(|(_, _, _, _, color)| *color == Color::GRAY)
+            .map(|(_, _, width, _, _)| *width)
+            .collect();
+        assert_eq!(
+            bar_widths,
+            vec![STATISTICS_BAR_MAX_WIDTH, STATISTICS_BAR_MAX_WIDTH / 2]
+        );
+    }
+
+    #[test]
+    fn hud_renderer_without_statistics_in_layout_does_not_draw_panel() {
+        // Arrange
+        let sut = GraphicsHudRenderer::with_layout(BoardLayout::versus_left());
+        let hud_view = HudView {
+            next_tetromino_type: TetrominoType::T,
+            hold_tetromino_type: None,
+            current_level: 0,
+            total_lines_cleared: 0,
+            score: 0,
+            statistics: GameStatistics::new(),
+            show_game_over: false,
+        };
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&hud_view, &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let pieces_text_drawn = display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text.starts_with("Pieces"));
+        assert!(!pieces_text_drawn);
+    }
 }
@@ -1,37 +1,51 @@
-use crate::common::Position;
+use crate::common::{Dimensions, Position};
 use crate::constants::*;
-use crate::graphics::{Color, Display, PlayfieldRenderer, PlayfieldView};
+use crate::graphics::{BoardLayout, Color, Display, PlayfieldRenderer, PlayfieldView};
 use crate::tetromino::TetrominoInstance;
 
-pub struct GraphicsPlayfieldRenderer;
+pub struct GraphicsPlayfieldRenderer {
+    layout: BoardLayout,
+}
 
 impl GraphicsPlayfieldRenderer {
     pub fn new() -> Self {
-        Self
+        Self::with_layout(BoardLayout::single_player())
+    }
+
+    pub fn with_layout(layout: BoardLayout) -> Self {
+        Self { layout }
     }
 
-    fn draw_border<D: Display + ?Sized>(&self, display: &mut D) -> Result<(), String> {
+    fn draw_border<D: Display + ?Sized>(
+        &self,
+        dimensions: Dimensions,
+        display: &mut D,
+    ) -> Result<(), String> {
         let border_color = Color::WHITE;
+        let playfield_x = self.layout.playfield_x;
+        let playfield_y = self.layout.playfield_y;
+        let playfield_width = dimensions.width;
+        let playfield_height = dimensions.height;
 
         // Left border
-        let mut x = PLAYFIELD_OFFSET_X - PLAYFIELD_BORDER_WIDTH;
-        let mut y = PLAYFIELD_OFFSET_Y;
+        let mut x = playfield_x - PLAYFIELD_BORDER_WIDTH;
+        let mut y = playfield_y;
         let mut width = PLAYFIELD_BORDER_WIDTH;
-        let mut height = PLAYFIELD_HEIGHT * BLOCK_SIZE;
+        let mut height = playfield_height * BLOCK_SIZE;
         display.draw_rectangle(x, y, width, height, border_color)?;
 
         // Bottom border
-        x = PLAYFIELD_OFFSET_X - PLAYFIELD_BORDER_WIDTH;
-        y = PLAYFIELD_OFFSET_Y + PLAYFIELD_HEIGHT * BLOCK_SIZE;
-        width = PLAYFIELD_BORDER_WIDTH + PLAYFIELD_WIDTH * BLOCK_SIZE + PLAYFIELD_BORDER_WIDTH;
+        x = playfield_x - PLAYFIELD_BORDER_WIDTH;
+        y = playfield_y + playfield_height * BLOCK_SIZE;
+        width = PLAYFIELD_BORDER_WIDTH + playfield_width * BLOCK_SIZE + PLAYFIELD_BORDER_WIDTH;
         height = PLAYFIELD_BORDER_WIDTH;
         display.draw_rectangle(x, y, width, height, border_color)?;
 
         // Right border
-        x = PLAYFIELD_OFFSET_X + PLAYFIELD_WIDTH * BLOCK_SIZE;
-        y = PLAYFIELD_OFFSET_Y;
+        x = playfield_x + playfield_width * BLOCK_SIZE;
+        y = playfield_y;
         width = PLAYFIELD_BORDER_WIDTH;
-        height = PLAYFIELD_HEIGHT * BLOCK_SIZE;
+        height = playfield_height * BLOCK_SIZE;
         display.draw_rectangle(x, y, width, height, border_color)?;
 
         Ok(())
@@ -42,8 +56,8 @@ impl GraphicsPlayfieldRenderer {
         playfield_view: &PlayfieldView,
         display: &mut D,
     ) -> Result<(), String> {
-        let x = PLAYFIELD_OFFSET_X as i32;
-        let y = PLAYFIELD_OFFSET_Y as i32;
+        let x = self.layout.playfield_x as i32;
+        let y = self.layout.playfield_y as i32;
         let playfield_position = Position::new(x, y);
 
         for y in 0..playfield_view.dimensions.height {
@@ -80,16 +94,16 @@ impl GraphicsPlayfieldRenderer {
         Ok(())
     }
 
-    fn draw_current_tetromino<D: Display + ?Sized>(
+    fn draw_current_tetrominoes<D: Display + ?Sized>(
         &self,
-        current_tetromino: Option<&TetrominoInstance>,
+        current_tetrominoes: &[&TetrominoInstance],
         display: &mut D,
     ) -> Result<(), String> {
-        let x = PLAYFIELD_OFFSET_X as i32;
-        let y = PLAYFIELD_OFFSET_Y as i32;
+        let x = self.layout.playfield_x as i32;
+        let y = self.layout.playfield_y as i32;
         let playfield_position = Position::new(x, y);
 
-        if let Some(tetromino) = current_tetromino {
+        for tetromino in current_tetrominoes {
             let blocks = tetromino.get_world_blocks();
             let tetromino_type = tetromino.get_type();
 
@@ -102,15 +116,21 @@ impl GraphicsPlayfieldRenderer {
     }
 }
 
+impl Default for GraphicsPlayfieldRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PlayfieldRenderer for GraphicsPlayfieldRenderer {
     fn draw<D: Display + ?Sized>(
         &self,
         playfield_view: &PlayfieldView,
         display: &mut D,
     ) -> Result<(), String> {
-        self.draw_border(display)?;
+        self.draw_border(playfield_view.dimensions, display)?;
         self.draw_playfield_blocks(playfield_view, display)?;
-        self.draw_current_tetromino(playfield_view.current_tetromino, display)?;
+        self.draw_current_tetrominoes(&playfield_view.current_tetrominoes, display)?;
         Ok(())
     }
 }
@@ -120,7 +140,6 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
-    use crate::common::Dimensions;
     use crate::events::EventQueue;
     use crate::game_logic::Playfield;
     use crate::graphics::MockDisplay;
@@ -208,7 +227,10 @@ mod tests {
         let mut display = MockDisplay::new();
 
         // Act
-        let result = sut.draw_border(&mut display);
+        let result = sut.draw_border(
+            Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+            &mut display,
+        );
 
         // Assert
         assert!(result.is_ok());
@@ -220,6 +242,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn draw_border_uses_playfield_dimensions() {
+        // Arrange
+        let sut = GraphicsPlayfieldRenderer::new();
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw_border(Dimensions::new(COOP_PLAYFIELD_WIDTH, 20), &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let (_, _, bottom_width, _, _) = display.drawn_rectangles[1];
+        assert_eq!(
+            bottom_width,
+            2 * PLAYFIELD_BORDER_WIDTH + COOP_PLAYFIELD_WIDTH * BLOCK_SIZE
+        );
+    }
+
+    #[test]
+    fn draw_renders_tetrominoes_of_all_players() {
+        // Arrange
+        let playfield = create_test_coop_playfield();
+        let sut = GraphicsPlayfieldRenderer::new();
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&playfield.get_view(), &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(display.drawn_blocks.len(), 8);
+    }
+
     #[test]
     fn draw_with_hidden_blinking_lines_does_not_draw_them() {
         // Arrange
@@ -243,6 +298,26 @@ mod tests {
         assert!(display.drawn_blocks.is_empty());
     }
 
+    #[test]
+    fn draw_border_uses_playfield_position_of_layout() {
+        // Arrange
+        let layout = BoardLayout::versus_right();
+        let sut = GraphicsPlayfieldRenderer::with_layout(layout);
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw_border(
+            Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+            &mut display,
+        );
+
+        // Assert
+        assert!(result.is_ok());
+        let (left_x, left_y, _, _, _) = display.drawn_rectangles[0];
+        assert_eq!(left_x, layout.playfield_x - PLAYFIELD_BORDER_WIDTH);
+        assert_eq!(left_y, layout.playfield_y);
+    }
+
     fn create_test_playfield() -> Playfield<FixedTetrominoGenerator> {
         let dimensions = Dimensions::new(10, 20);
         let event_bus = Arc::new(EventQueue::new());
@@ -253,6 +328,19 @@ mod tests {
         )
     }
 
+    fn create_test_coop_playfield() -> Playfield<FixedTetrominoGenerator> {
+        let dimensions = Dimensions::new(COOP_PLAYFIELD_WIDTH, 20);
+        let event_bus = Arc::new(EventQueue::new());
+        let mut playfield = Playfield::with_spawn_positions(
+            dimensions,
+            FixedTetrominoGenerator::new(TetrominoType::O),
+            event_bus,
+            &[Position::new(2, 0), Position::new(10, 0)],
+        );
+        playfield.start_level(0);
+        playfield
+    }
+
     /// Creates a TetrominoInstance with the specified type and position (4, 4).
     fn create_tetromino_instance(tetromino_type: TetrominoType) -> TetrominoInstance {
         let tetromino_definitions = TetrominoDefinitions::new();
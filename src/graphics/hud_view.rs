@@ -1,10 +1,13 @@
+use crate::game_logic::GameStatistics;
 use crate::tetromino::TetrominoType;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct HudView {
     pub next_tetromino_type: TetrominoType,
+    pub hold_tetromino_type: Option<TetrominoType>,
     pub current_level: u32,
     pub total_lines_cleared: u32,
     pub score: u32,
+    pub statistics: GameStatistics,
     pub show_game_over: bool,
 }
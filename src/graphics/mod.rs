@@ -1,4 +1,5 @@
 // src/graphics/mod.rs
+mod board_layout;
 mod color;
 mod display;
 mod graphics_hud_renderer;
@@ -11,11 +12,14 @@ mod mock_display;
 mod mock_hud_renderer;
 #[cfg(test)]
 mod mock_playfield_renderer;
+mod null_display;
 mod playfield_renderer;
 mod playfield_view;
+#[cfg(feature = "sdl")]
 mod sdl_display;
 mod tetromino_pattern;
 
+pub use board_layout::BoardLayout;
 pub use color::Color;
 pub use display::Display;
 pub use graphics_hud_renderer::GraphicsHudRenderer;
@@ -28,7 +32,9 @@ pub use mock_display::MockDisplay;
 pub use mock_hud_renderer::MockHudRenderer;
 #[cfg(test)]
 pub use mock_playfield_renderer::MockPlayfieldRenderer;
+pub use null_display::NullDisplay;
 pub use playfield_renderer::PlayfieldRenderer;
 pub use playfield_view::PlayfieldView;
+#[cfg(feature = "sdl")]
 pub use sdl_display::SdlDisplay;
 pub use tetromino_pattern::*;
@@ -0,0 +1,47 @@
+use crate::common::Position;
+use crate::graphics::Color;
+use crate::graphics::Display;
+use crate::tetromino::TetrominoType;
+
+/// Display that draws nothing, for running games without a window.
+#[derive(Debug, Default)]
+pub struct NullDisplay;
+
+impl NullDisplay {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Display for NullDisplay {
+    fn clear(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn draw_block(
+        &mut self,
+        _position: Position,
+        _tetromino_type: TetrominoType,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn draw_rectangle(
+        &mut self,
+        _x: u32,
+        _y: u32,
+        _width: u32,
+        _height: u32,
+        _color: Color,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn draw_text(&mut self, _text: &str, _x: u32, _y: u32, _color: Color) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use crate::tetromino::{TetrominoInstance, TetrominoType};
 pub struct PlayfieldView<'a> {
     pub dimensions: Dimensions,
     pub grid: &'a PlayfieldGrid,
-    pub current_tetromino: Option<&'a TetrominoInstance>,
+    pub current_tetrominoes: Vec<&'a TetrominoInstance>,
     pub next_tetromino_type: TetrominoType,
     pub full_lines: Vec<u32>,
     pub show_blinking_lines: bool,
@@ -39,7 +39,7 @@ mod tests {
         let sut = PlayfieldView {
             dimensions,
             grid: &grid,
-            current_tetromino: None,
+            current_tetrominoes: vec![],
             next_tetromino_type: TetrominoType::T,
             full_lines: vec![],
             show_blinking_lines: false,
@@ -60,7 +60,7 @@ mod tests {
         let sut = PlayfieldView {
             dimensions,
             grid: &grid,
-            current_tetromino: None,
+            current_tetrominoes: vec![],
             next_tetromino_type: TetrominoType::T,
             full_lines: vec![],
             show_blinking_lines: false,
@@ -82,7 +82,7 @@ mod tests {
         let sut = PlayfieldView {
             dimensions,
             grid: &grid,
-            current_tetromino: None,
+            current_tetrominoes: vec![],
             next_tetromino_type: TetrominoType::T,
             full_lines: vec![],
             show_blinking_lines: false,
@@ -103,7 +103,7 @@ mod tests {
         let sut = PlayfieldView {
             dimensions,
             grid: &grid,
-            current_tetromino: None,
+            current_tetrominoes: vec![],
             next_tetromino_type: TetrominoType::T,
             full_lines: vec![],
             show_blinking_lines: false,
@@ -126,7 +126,7 @@ mod tests {
         let sut = PlayfieldView {
             dimensions,
             grid: &grid,
-            current_tetromino: None,
+            current_tetrominoes: vec![],
             full_lines: vec![],
             show_blinking_lines: false,
             next_tetromino_type: TetrominoType::T,
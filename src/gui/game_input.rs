@@ -1,10 +1,15 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, strum::EnumString, strum::Display)]
 pub enum GameInput {
     MoveLeft,
     MoveRight,
     MoveDown,
     RotateClockwise,
     RotateCounterclockwise,
+    Rotate180,
     Drop,
+    SonicDrop,
+    Hold,
     StartGame,
+    Undo,
+    Redo,
 }
@@ -1,147 +1,408 @@
-use crate::game_logic::GameResult;
+use crate::common::DateTime;
+use crate::game_logic::{GameMode, GameResult, GameRules};
 // src/high_scores/file_repository.rs
-use crate::high_scores::{HighScore, HighScores, HighScoresRepository};
+use crate::high_scores::{
+    HighScore, HighScoreTableKey, HighScoreTables, HighScores, HighScoresLoadError,
+    HighScoresRepository,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
-
+use std::str::FromStr;
+use std::time::Duration;
+
+const HIGH_SCORES_HEADER: &str = "SAMTRIS-HIGHSCORES";
+const HIGH_SCORES_FORMAT_VERSION: u32 = 2;
+/// The version of files without a header line, which were saved before the format had versions.
+const LEGACY_FORMAT_VERSION: u32 = 1;
+
+const MAC_PREFIX: &str = "HMAC-SHA256:";
+/// Appended to the path of the high scores file for the marker that a signed file was written.
+const SIGNED_MARKER_EXTENSION: &str = ".signed";
+const SECRET_LENGTH: usize = 32;
+
+/// Files saved before the MAC were XOR'd with this key and had a checksum instead.
+const LEGACY_ENCRYPTION_KEY: u8 = 42;
+const LEGACY_CHECKSUM_SECRET: u32 = 0xDEADBEEF;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Stores the high scores as text, signed with an HMAC-SHA256 of the content so they can't be
+/// edited by hand. The key is a secret of this install, kept in its own file that is created the
+/// first time it's needed. Files saved before the MAC are only accepted until a signed file has
+/// been written, which leaves a marker file next to the high scores. After that an unsigned file
+/// can only be a forgery, even when the secret was deleted.
 pub struct FileHighScoresRepository {
     file_path: String,
-    encryption_key: u8,
+    secret_file_path: String,
+    signed_marker_path: String,
 }
 
 impl FileHighScoresRepository {
-    pub fn new(file_path: String) -> Self {
+    pub fn new(file_path: String, secret_file_path: String) -> Self {
+        let signed_marker_path = format!("{file_path}{SIGNED_MARKER_EXTENSION}");
         Self {
             file_path,
-            encryption_key: 42, // Simple XOR key - not secure but deters casual editing
+            secret_file_path,
+            signed_marker_path,
         }
     }
 
-    fn encrypt_decrypt(&self, data: &[u8]) -> Vec<u8> {
-        data.iter().map(|b| b ^ self.encryption_key).collect()
+    /// Whether a signed file was ever written. The secret is checked too, because it was the only
+    /// sign of that before there was a marker.
+    fn has_written_signed_file(&self) -> bool {
+        Path::new(&self.signed_marker_path).exists() || Path::new(&self.secret_file_path).exists()
     }
 
-    fn calculate_checksum(&self, data: &str) -> u32 {
-        // Simple checksum - sum of all bytes XOR'd with a secret
-        let secret = 0xDEADBEEF_u32;
-        let sum: u32 = data.bytes().map(|b| b as u32).sum();
-        sum ^ secret
+    fn write_signed_marker(&self) -> Result<(), String> {
+        if Path::new(&self.signed_marker_path).exists() {
+            return Ok(());
+        }
+        fs::write(&self.signed_marker_path, "")
+            .map_err(|e| format!("Failed to write signed marker: {e}"))
     }
 
-    fn add_checksum(&self, data: &str) -> String {
-        let checksum = self.calculate_checksum(data);
-        format!("CHKSUM:{checksum}\n{data}")
+    fn load_or_create_secret(&self) -> Result<Vec<u8>, String> {
+        if Path::new(&self.secret_file_path).exists() {
+            let text = fs::read_to_string(&self.secret_file_path)
+                .map_err(|e| format!("Failed to read secret: {e}"))?;
+            return decode_hex(text.trim())
+                .filter(|secret| secret.len() == SECRET_LENGTH)
+                .ok_or("Invalid secret".to_string());
+        }
+
+        let secret: [u8; SECRET_LENGTH] = rand::random();
+        fs::write(&self.secret_file_path, encode_hex(&secret))
+            .map_err(|e| format!("Failed to write secret: {e}"))?;
+        Ok(secret.to_vec())
     }
 
-    fn verify_and_extract(&self, data: &str) -> Result<String, String> {
-        let lines: Vec<&str> = data.splitn(2, '\n').collect();
-        if lines.len() != 2 {
-            return Err("Invalid file format - missing checksum".to_string());
-        }
+    fn add_mac(&self, content: &str, secret: &[u8]) -> String {
+        let mac = create_mac(content, secret).finalize().into_bytes();
+        format!("{MAC_PREFIX}{}\n{content}", encode_hex(&mac))
+    }
 
-        let checksum_line = lines[0];
-        let content = lines[1];
+    /// Returns the content of the file when its MAC matches, a file that can't be read is
+    /// corrupted and a file with another MAC is tampered with.
+    fn verify_and_extract(&self, data: &str, secret: &[u8]) -> Result<String, HighScoresLoadError> {
+        let (mac_line, content) = data.split_once('\n').ok_or(corrupted("missing MAC"))?;
+        let stored_mac = mac_line
+            .strip_prefix(MAC_PREFIX)
+            .and_then(decode_hex)
+            .ok_or(corrupted("invalid MAC"))?;
+
+        create_mac(content, secret)
+            .verify_slice(&stored_mac)
+            .map_err(|_| HighScoresLoadError::Tampered)?;
+        Ok(content.to_string())
+    }
 
-        if !checksum_line.starts_with("CHKSUM:") {
-            return Err("Invalid file format - no checksum header".to_string());
-        }
+    /// Returns the content of a file saved before the MAC, so it can be migrated.
+    fn verify_and_extract_legacy(&self, data: &[u8]) -> Result<String, HighScoresLoadError> {
+        let decrypted_data = encrypt_decrypt_legacy(data);
+        let data = String::from_utf8(decrypted_data).map_err(corrupted)?;
 
-        let stored_checksum: u32 = checksum_line[7..]
-            .parse()
-            .map_err(|_| "Invalid checksum format".to_string())?;
+        let (checksum_line, content) =
+            data.split_once('\n').ok_or(corrupted("missing checksum"))?;
+        let stored_checksum: u32 = checksum_line
+            .strip_prefix("CHKSUM:")
+            .and_then(|checksum| checksum.parse().ok())
+            .ok_or(corrupted("invalid checksum"))?;
 
-        let calculated_checksum = self.calculate_checksum(content);
-        if stored_checksum != calculated_checksum {
-            return Err("File has been tampered with - checksum mismatch".to_string());
+        if stored_checksum != calculate_legacy_checksum(content) {
+            return Err(HighScoresLoadError::Tampered);
         }
-
         Ok(content.to_string())
     }
 
-    fn serialize_high_scores(&self, high_scores: &HighScores) -> String {
-        let scores = high_scores.get_scores();
-        let mut lines = Vec::new();
+    /// Writes a header line with the format version, followed by every table that has high
+    /// scores as a '[mode]' line, or '[mode entry_delay_ms line_clear_delay_ms]' for rules other
+    /// than the default ones, and one line per high score.
+    fn serialize_high_scores(&self, high_score_tables: &HighScoreTables) -> String {
+        let mut lines = vec![format!("{HIGH_SCORES_HEADER} {HIGH_SCORES_FORMAT_VERSION}")];
+
+        for (key, high_scores) in high_score_tables.iter() {
+            if high_scores.get_scores().is_empty() {
+                continue;
+            }
 
-        for score in scores {
-            lines.push(format!(
-                "{}|{}|{}",
-                score.name, score.game_result.score, score.game_result.level
-            ));
+            lines.push(format!("[{}]", encode_table_key(&key)));
+            lines.extend(high_scores.get_scores().iter().map(encode_high_score));
         }
 
         lines.join("\n")
     }
 
-    fn deserialize_high_scores(&self, data: &str) -> Result<HighScores, String> {
-        let mut scores = Vec::new();
+    /// Reads the tables in any version of the format. High scores before the first '[mode]' line
+    /// are marathon high scores with the default rules, because files saved before there were
+    /// more tables don't have these lines. Tables are ranked the way their game mode is.
+    fn deserialize_high_scores(&self, data: &str) -> Result<HighScoreTables, String> {
+        let (version, content) = split_format_version(data)?;
+        let decode_line = match version {
+            LEGACY_FORMAT_VERSION => decode_legacy_high_score,
+            _ => decode_high_score,
+        };
 
-        for line in data.lines() {
+        let mut scores_per_table: BTreeMap<HighScoreTableKey, Vec<HighScore>> = BTreeMap::new();
+        let mut key = HighScoreTableKey::with_default_rules(GameMode::Marathon);
+
+        for line in content.lines() {
             if line.trim().is_empty() {
                 continue;
             }
 
-            let parts: Vec<&str> = line.split('|').collect();
-            if parts.len() != 3 {
-                return Err(format!("Invalid line format: {line}"));
+            if let Some(name) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                key = decode_table_key(name)?;
+                continue;
             }
 
-            let name = parts[0].to_string();
-            let score = parts[1]
-                .parse::<u32>()
-                .map_err(|_| format!("Invalid score: {}", parts[1]))?;
-            let level = parts[2]
-                .parse::<u32>()
-                .map_err(|_| format!("Invalid level: {}", parts[2]))?;
+            scores_per_table
+                .entry(key)
+                .or_default()
+                .push(decode_line(line)?);
+        }
 
-            scores.push(HighScore::new(name, GameResult { score, level }));
+        let mut high_score_tables = HighScoreTables::new();
+        for (key, scores) in scores_per_table {
+            high_score_tables.set(key, HighScores::from_vec(scores, key.get_ranking()));
         }
+        Ok(high_score_tables)
+    }
+}
 
-        Ok(HighScores::from_vec(scores))
+/// Splits off the header line and returns the version of the format with the rest of the data.
+/// Files without a header line are legacy files.
+fn split_format_version(data: &str) -> Result<(u32, &str), String> {
+    let Some(header) = data.strip_prefix(HIGH_SCORES_HEADER) else {
+        return Ok((LEGACY_FORMAT_VERSION, data));
+    };
+
+    let (version, content) = header.split_once('\n').unwrap_or((header, ""));
+    match version.trim().parse() {
+        Ok(HIGH_SCORES_FORMAT_VERSION) => Ok((HIGH_SCORES_FORMAT_VERSION, content)),
+        _ => Err(format!(
+            "Unsupported high scores version '{}'",
+            version.trim()
+        )),
     }
 }
 
-impl HighScoresRepository for FileHighScoresRepository {
-    fn load(&self) -> Result<HighScores, String> {
-        if !Path::new(&self.file_path).exists() {
-            return Ok(HighScores::new());
+fn encode_table_key(key: &HighScoreTableKey) -> String {
+    if key.rules == GameRules::default() {
+        return key.game_mode.to_string();
+    }
+
+    format!(
+        "{} {} {}",
+        key.game_mode,
+        key.rules.entry_delay.as_millis(),
+        key.rules.line_clear_delay.as_millis()
+    )
+}
+
+fn decode_table_key(name: &str) -> Result<HighScoreTableKey, String> {
+    let unknown_table = || format!("Unknown high score table: {name}");
+    let parts: Vec<&str> = name.split(' ').collect();
+    let game_mode = GameMode::from_str(parts[0]).map_err(|_| unknown_table())?;
+    match parts[1..] {
+        [] => Ok(HighScoreTableKey::with_default_rules(game_mode)),
+        [entry_delay_ms, line_clear_delay_ms] => {
+            let rules = GameRules::new(
+                Duration::from_millis(parse_field(entry_delay_ms, "entry delay")?),
+                Duration::from_millis(parse_field(line_clear_delay_ms, "line clear delay")?),
+            );
+            Ok(HighScoreTableKey::new(game_mode, rules))
         }
+        _ => Err(unknown_table()),
+    }
+}
 
-        let encrypted_data =
-            fs::read(&self.file_path).map_err(|e| format!("Failed to read file: {e}"))?;
+/// Encodes the high score as name, score, lines, level, start level, date and time (empty when
+/// unknown), time played in milliseconds and replay name (empty without replay).
+fn encode_high_score(high_score: &HighScore) -> String {
+    let game_result = &high_score.game_result;
+    format!(
+        "{}|{}|{}|{}|{}|{}|{}|{}",
+        high_score.name,
+        game_result.score,
+        game_result.lines,
+        game_result.level,
+        game_result.start_level,
+        high_score
+            .date_time
+            .map(|date_time| date_time.to_string())
+            .unwrap_or_default(),
+        game_result.time.as_millis(),
+        high_score.replay_name.as_deref().unwrap_or_default()
+    )
+}
+
+fn decode_high_score(line: &str) -> Result<HighScore, String> {
+    let fields: Vec<&str> = line.split('|').collect();
+    let [name, score, lines, level, start_level, date_time, time_ms, replay_name] = fields[..]
+    else {
+        return Err(format!("Invalid line format: {line}"));
+    };
+
+    let date_time = match date_time {
+        "" => None,
+        date_time => Some(DateTime::from_str(date_time)?),
+    };
+    Ok(HighScore {
+        name: name.to_string(),
+        game_result: GameResult {
+            score: parse_field(score, "score")?,
+            lines: parse_field(lines, "lines")?,
+            level: parse_field(level, "level")?,
+            start_level: parse_field(start_level, "start level")?,
+            time: Duration::from_millis(parse_field(time_ms, "time")?),
+        },
+        replay_name: Some(replay_name.to_string()).filter(|name| !name.is_empty()),
+        date_time,
+    })
+}
+
+/// Decodes a high score of a legacy file: name, score and level, followed by the replay name and
+/// the time in milliseconds. Scores saved before replays existed don't have these, scores saved
+/// before there were more tables have no time.
+fn decode_legacy_high_score(line: &str) -> Result<HighScore, String> {
+    let parts: Vec<&str> = line.split('|').collect();
+    if !(3..=5).contains(&parts.len()) {
+        return Err(format!("Invalid line format: {line}"));
+    }
+
+    let time_ms = match parts.get(4) {
+        Some(time_ms) => parse_field(time_ms, "time")?,
+        None => 0,
+    };
+    let game_result = GameResult {
+        score: parse_field(parts[1], "score")?,
+        level: parse_field(parts[2], "level")?,
+        time: Duration::from_millis(time_ms),
+        ..Default::default()
+    };
+
+    let name = parts[0].to_string();
+    Ok(
+        match parts.get(3).filter(|replay_name| !replay_name.is_empty()) {
+            Some(replay_name) => HighScore::with_replay(name, game_result, replay_name.to_string()),
+            None => HighScore::new(name, game_result),
+        },
+    )
+}
+
+fn parse_field<N: FromStr>(text: &str, field_name: &str) -> Result<N, String> {
+    text.parse()
+        .map_err(|_| format!("Invalid {field_name}: {text}"))
+}
+
+fn create_mac(content: &str, secret: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC takes keys of any length");
+    mac.update(content.as_bytes());
+    mac
+}
+
+fn corrupted(reason: impl std::fmt::Display) -> HighScoresLoadError {
+    HighScoresLoadError::Corrupted(reason.to_string())
+}
 
-        let decrypted_data = self.encrypt_decrypt(&encrypted_data);
-        let data_str =
-            String::from_utf8(decrypted_data).map_err(|e| format!("Invalid UTF-8 data: {e}"))?;
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn encrypt_decrypt_legacy(data: &[u8]) -> Vec<u8> {
+    data.iter().map(|b| b ^ LEGACY_ENCRYPTION_KEY).collect()
+}
+
+/// The sum of all bytes XOR'd with a secret.
+fn calculate_legacy_checksum(data: &str) -> u32 {
+    let sum: u32 = data.bytes().map(|b| b as u32).sum();
+    sum ^ LEGACY_CHECKSUM_SECRET
+}
+
+impl HighScoresRepository for FileHighScoresRepository {
+    fn load(&self) -> Result<HighScoreTables, HighScoresLoadError> {
+        if !Path::new(&self.file_path).exists() {
+            return Ok(HighScoreTables::new());
+        }
 
-        let verified_content = self.verify_and_extract(&data_str)?;
-        self.deserialize_high_scores(&verified_content)
+        let data = fs::read(&self.file_path)
+            .map_err(|e| HighScoresLoadError::Io(format!("Failed to read file: {e}")))?;
+        let is_legacy_file = !data.starts_with(MAC_PREFIX.as_bytes());
+        let verified_content = if is_legacy_file {
+            if self.has_written_signed_file() {
+                return Err(HighScoresLoadError::Tampered);
+            }
+            self.verify_and_extract_legacy(&data)?
+        } else {
+            let data = String::from_utf8(data).map_err(corrupted)?;
+            let secret = self
+                .load_or_create_secret()
+                .map_err(HighScoresLoadError::Io)?;
+            let content = self.verify_and_extract(&data, &secret)?;
+            // Files signed before there was a marker get one now
+            if let Err(e) = self.write_signed_marker() {
+                eprintln!("Failed to mark high scores as signed: {e}");
+            }
+            content
+        };
+        let high_score_tables = self
+            .deserialize_high_scores(&verified_content)
+            .map_err(corrupted)?;
+
+        // Rewrite legacy files in the current format, so they're migrated once
+        if is_legacy_file || !verified_content.starts_with(HIGH_SCORES_HEADER) {
+            if let Err(e) = self.save(&high_score_tables) {
+                eprintln!("Failed to migrate high scores: {e}");
+            }
+        }
+        Ok(high_score_tables)
     }
 
-    fn save(&self, high_scores: &HighScores) -> Result<(), String> {
-        let serialized = self.serialize_high_scores(high_scores);
-        let with_checksum = self.add_checksum(&serialized);
-        let encrypted_data = self.encrypt_decrypt(with_checksum.as_bytes());
+    fn save(&self, high_score_tables: &HighScoreTables) -> Result<(), String> {
+        let serialized = self.serialize_high_scores(high_score_tables);
+        let with_mac = self.add_mac(&serialized, &self.load_or_create_secret()?);
 
-        fs::write(&self.file_path, encrypted_data).map_err(|e| format!("Failed to write file: {e}"))
+        self.write_signed_marker()?;
+        fs::write(&self.file_path, with_mac).map_err(|e| format!("Failed to write file: {e}"))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
     use std::fs;
     use std::path::Path;
 
+    const TEST_SECRET: [u8; SECRET_LENGTH] = [42; SECRET_LENGTH];
+
     #[test]
     fn serialize_deserialize_round_trip() {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
         let mut high_scores = HighScores::new();
         high_scores.add(HighScore::new(
             "SAM".to_string(),
             GameResult {
                 score: 1000,
                 level: 5,
+                ..Default::default()
             },
         ));
         high_scores.add(HighScore::new(
@@ -149,16 +410,18 @@ mod tests {
             GameResult {
                 score: 2000,
                 level: 3,
+                ..Default::default()
             },
         ));
 
         // Act
-        let serialized = sut.serialize_high_scores(&high_scores);
+        let serialized = sut.serialize_high_scores(&create_marathon_tables(&high_scores));
         let result = sut.deserialize_high_scores(&serialized);
 
         // Assert
         assert!(result.is_ok());
-        let deserialized = result.unwrap();
+        let deserialized_tables = result.unwrap();
+        let deserialized = deserialized_tables.get(key(GameMode::Marathon)).unwrap();
         assert_eq!(deserialized.len(), 2);
         assert_eq!(deserialized.get_scores()[0].name, "BOB"); // Should be sorted by score
         assert_eq!(deserialized.get_scores()[0].game_result.score, 2000);
@@ -167,146 +430,199 @@ mod tests {
     }
 
     #[test]
-    fn encrypt_decrypt_round_trip() {
+    fn serialize_deserialize_keeps_replay_name() {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
-        let original = b"Hello World!";
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+        let mut high_scores = HighScores::new();
+        let game_result = GameResult {
+            score: 1000,
+            level: 5,
+            ..Default::default()
+        };
+        high_scores.add(HighScore::with_replay(
+            "SAM".to_string(),
+            game_result,
+            "2026-10-18_12-43-05".to_string(),
+        ));
 
         // Act
-        let encrypted = sut.encrypt_decrypt(original);
-        let decrypted = sut.encrypt_decrypt(&encrypted);
+        let serialized = sut.serialize_high_scores(&create_marathon_tables(&high_scores));
+        let result = sut.deserialize_high_scores(&serialized);
 
         // Assert
-        assert_eq!(original, decrypted.as_slice());
+        assert_eq!(
+            serialized,
+            "SAMTRIS-HIGHSCORES 2\n[marathon]\nSAM|1000|0|5|0||0|2026-10-18_12-43-05"
+        );
+        assert_eq!(
+            result
+                .unwrap()
+                .get(key(GameMode::Marathon))
+                .unwrap()
+                .get_scores(),
+            high_scores.get_scores()
+        );
     }
 
     #[test]
-    fn checksum_detects_tampering() {
+    fn add_mac_and_verify_round_trip() {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
-        let original = "SAM|1000|5";
-        let tampered = "SAM|9999|5"; // Modified score
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+        let original_data = "SAM|1000|5\nBOB|2000|3";
 
         // Act
-        let original_checksum = sut.calculate_checksum(original);
-        let tampered_checksum = sut.calculate_checksum(tampered);
+        let with_mac = sut.add_mac(original_data, &TEST_SECRET);
+        let result = sut.verify_and_extract(&with_mac, &TEST_SECRET);
 
         // Assert
-        assert_ne!(original_checksum, tampered_checksum);
+        assert!(with_mac.starts_with("HMAC-SHA256:"));
+        assert_eq!(result, Ok(original_data.to_string()));
     }
 
-    #[test]
-    fn verify_and_extract_rejects_tampered_data() {
+    #[rstest]
+    #[case("1000", "9999", &TEST_SECRET)]
+    #[case("", "", &[7; SECRET_LENGTH])]
+    fn verify_and_extract_rejects_tampered_data(
+        #[case] original: &str,
+        #[case] replacement: &str,
+        #[case] secret: &[u8],
+    ) {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
-        let original_data = "SAM|1000|5";
-        let with_checksum = sut.add_checksum(original_data);
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+        let with_mac = sut.add_mac("SAM|1000|5", &TEST_SECRET);
 
-        // Tamper with the content but keep the original checksum
-        let tampered = with_checksum.replace("1000", "9999");
+        // Tamper with the content but keep the original MAC
+        let tampered = with_mac.replace(original, replacement);
 
         // Act
-        let result = sut.verify_and_extract(&tampered);
+        let result = sut.verify_and_extract(&tampered, secret);
 
         // Assert
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("checksum mismatch"));
+        assert_eq!(result, Err(HighScoresLoadError::Tampered));
+    }
+
+    #[rstest]
+    #[case("HMAC-SHA256:00", "missing MAC")]
+    #[case("CHKSUM:12345\ncontent", "invalid MAC")]
+    #[case("HMAC-SHA256:xyz\ncontent", "invalid MAC")]
+    fn verify_and_extract_reports_corrupted_data(
+        #[case] data: &str,
+        #[case] expected_reason: &str,
+    ) {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+
+        // Act
+        let result = sut.verify_and_extract(data, &TEST_SECRET);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(HighScoresLoadError::Corrupted(expected_reason.to_string()))
+        );
     }
 
     #[test]
     fn load_returns_empty_when_file_does_not_exist() {
         // Arrange
-        let sut = FileHighScoresRepository::new("nonexistent.dat".to_string());
+        let sut = FileHighScoresRepository::new(
+            "nonexistent.dat".to_string(),
+            "nonexistent.key".to_string(),
+        );
 
         // Act
         let result = sut.load();
 
         // Assert
         assert!(result.is_ok());
-        assert_eq!(result.unwrap().len(), 0);
+        assert!(result
+            .unwrap()
+            .get(key(GameMode::Marathon))
+            .unwrap()
+            .is_empty());
     }
 
     #[test]
     fn save_and_load_persistence() {
         // Arrange
-        let test_file = "test_high_scores.dat";
-        let sut = FileHighScoresRepository::new(test_file.to_string());
+        let sut = create_test_repository("high_scores");
         let mut high_scores = HighScores::new();
         high_scores.add(HighScore::new(
             "TEST".to_string(),
             GameResult {
                 score: 500,
                 level: 2,
+                ..Default::default()
             },
         ));
 
         // Act
-        let save_result = sut.save(&high_scores);
+        let save_result = sut.save(&create_marathon_tables(&high_scores));
         let load_result = sut.load();
 
         // Assert
         assert!(save_result.is_ok());
         assert!(load_result.is_ok());
 
-        let loaded = load_result.unwrap();
+        let loaded_tables = load_result.unwrap();
+        let loaded = loaded_tables.get(key(GameMode::Marathon)).unwrap();
         assert_eq!(loaded.len(), 1);
         assert_eq!(loaded.get_scores()[0].name, "TEST");
         assert_eq!(loaded.get_scores()[0].game_result.score, 500);
         assert_eq!(loaded.get_scores()[0].game_result.level, 2);
 
-        // Cleanup
-        if Path::new(test_file).exists() {
-            fs::remove_file(test_file).ok();
-        }
+        assert!(Path::new(&sut.secret_file_path).exists());
+        remove_test_files(&sut);
     }
 
     #[test]
-    fn add_checksum_and_verify_round_trip() {
+    fn load_reports_tampered_file() {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
-        let original_data = "SAM|1000|5\nBOB|2000|3";
-
-        // Act
-        let with_checksum = sut.add_checksum(original_data);
-        let result = sut.verify_and_extract(&with_checksum);
-
-        // Assert
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), original_data);
-    }
-
-    #[test]
-    fn verify_and_extract_returns_error_for_missing_newline() {
-        // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
-        let data_without_newline = "CHKSUM:12345";
+        let sut = create_test_repository("tampered_high_scores");
+        let mut high_scores = HighScores::new();
+        high_scores.add(HighScore::new(
+            "TEST".to_string(),
+            GameResult {
+                score: 500,
+                level: 2,
+                ..Default::default()
+            },
+        ));
+        sut.save(&create_marathon_tables(&high_scores)).unwrap();
+        let data = fs::read_to_string(&sut.file_path).unwrap();
+        fs::write(&sut.file_path, data.replace("|500|", "|99999|")).unwrap();
 
         // Act
-        let result = sut.verify_and_extract(data_without_newline);
+        let result = sut.load();
 
         // Assert
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("missing checksum"));
+        assert_eq!(result.unwrap_err(), HighScoresLoadError::Tampered);
+        remove_test_files(&sut);
     }
 
     #[test]
-    fn verify_and_extract_returns_error_for_missing_checksum_header() {
+    fn load_with_secret_of_other_install_reports_tampered_file() {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
-        let data_without_header = "12345\ncontent";
+        let sut = create_test_repository("other_install_high_scores");
+        fs::write(&sut.secret_file_path, encode_hex(&TEST_SECRET)).unwrap();
+        let other_install_data = sut.add_mac(
+            "SAMTRIS-HIGHSCORES 2\n[marathon]\nSAM|1000|0|5|0||0|",
+            &[7; SECRET_LENGTH],
+        );
+        fs::write(&sut.file_path, other_install_data).unwrap();
 
         // Act
-        let result = sut.verify_and_extract(data_without_header);
+        let result = sut.load();
 
         // Assert
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("no checksum header"));
+        assert_eq!(result.unwrap_err(), HighScoresLoadError::Tampered);
+        remove_test_files(&sut);
     }
 
     #[test]
     fn deserialize_high_scores_skips_empty_lines() {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
         let data_with_empty_lines = "SAM|1000|5\n\n\nBOB|2000|3\n   \n";
 
         // Act
@@ -314,7 +630,8 @@ mod tests {
 
         // Assert
         assert!(result.is_ok());
-        let high_scores = result.unwrap();
+        let high_score_tables = result.unwrap();
+        let high_scores = high_score_tables.get(key(GameMode::Marathon)).unwrap();
         assert_eq!(high_scores.len(), 2);
         assert_eq!(high_scores.get_scores()[0].name, "BOB"); // Sorted by score
         assert_eq!(high_scores.get_scores()[1].name, "SAM");
@@ -323,7 +640,7 @@ mod tests {
     #[test]
     fn deserialize_high_scores_returns_error_for_invalid_line_format() {
         // Arrange
-        let sut = FileHighScoresRepository::new("test.dat".to_string());
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
         let data_with_invalid_format = "SAM|1000|5\nINVALID_LINE\nBOB|2000|3";
 
         // Act
@@ -335,4 +652,359 @@ mod tests {
             .unwrap_err()
             .contains("Invalid line format: INVALID_LINE"));
     }
+
+    #[test]
+    fn serialize_deserialize_keeps_tables_apart() {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+        let mut high_score_tables = HighScoreTables::new();
+        let game_result = GameResult {
+            score: 1000,
+            level: 5,
+            time: Duration::from_millis(61_500),
+            ..Default::default()
+        };
+        high_score_tables
+            .get_mut(key(GameMode::Marathon))
+            .add(HighScore::new("SAM".to_string(), game_result));
+        high_score_tables
+            .get_mut(key(GameMode::Coop))
+            .add(HighScore::new("BOB".to_string(), game_result));
+
+        // Act
+        let serialized = sut.serialize_high_scores(&high_score_tables);
+        let result = sut.deserialize_high_scores(&serialized).unwrap();
+
+        // Assert
+        assert_eq!(
+            serialized,
+            "SAMTRIS-HIGHSCORES 2\n[marathon]\nSAM|1000|0|5|0||61500|\n[coop]\nBOB|1000|0|5|0||61500|"
+        );
+        for game_mode in [GameMode::Marathon, GameMode::Coop] {
+            assert_eq!(
+                result.get(key(game_mode)).unwrap().get_scores(),
+                high_score_tables.get(key(game_mode)).unwrap().get_scores()
+            );
+        }
+        assert!(result.get(key(GameMode::Puzzle)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn deserialize_high_scores_without_tables_reads_marathon_table() {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+
+        // Act
+        let result = sut.deserialize_high_scores("SAM|1000|5\nBOB|2000|3|2026-10-18_12-43-05");
+
+        // Assert
+        let high_score_tables = result.unwrap();
+        let high_scores = high_score_tables.get(key(GameMode::Marathon)).unwrap();
+        assert_eq!(high_scores.len(), 2);
+        assert_eq!(
+            high_scores.get_scores()[0].replay_name,
+            Some("2026-10-18_12-43-05".to_string())
+        );
+        assert!(high_score_tables
+            .get(key(GameMode::Coop))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn serialize_deserialize_keeps_tables_of_other_rules_apart() {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+        let mut high_score_tables = HighScoreTables::new();
+        let other_rules = HighScoreTableKey::new(
+            GameMode::Marathon,
+            GameRules::new(Duration::from_millis(100), Duration::ZERO),
+        );
+        let game_result = GameResult {
+            score: 1000,
+            level: 5,
+            ..Default::default()
+        };
+        high_score_tables
+            .get_mut(key(GameMode::Marathon))
+            .add(HighScore::new("SAM".to_string(), game_result));
+        high_score_tables
+            .get_mut(other_rules)
+            .add(HighScore::new("BOB".to_string(), game_result));
+
+        // Act
+        let serialized = sut.serialize_high_scores(&high_score_tables);
+        let result = sut.deserialize_high_scores(&serialized).unwrap();
+
+        // Assert
+        assert_eq!(
+            serialized,
+            "SAMTRIS-HIGHSCORES 2\n[marathon]\nSAM|1000|0|5|0||0|\n[marathon 100 0]\nBOB|1000|0|5|0||0|"
+        );
+        for key in [key(GameMode::Marathon), other_rules] {
+            assert_eq!(
+                result.get(key).unwrap().get_scores(),
+                high_score_tables.get(key).unwrap().get_scores()
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("[sprint]", "Unknown high score table: sprint")]
+    #[case("[marathon 100]", "Unknown high score table: marathon 100")]
+    #[case("[marathon x 0]", "Invalid entry delay: x")]
+    fn deserialize_high_scores_returns_error_for_unknown_table(
+        #[case] table_line: &str,
+        #[case] expected_error: &str,
+    ) {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+
+        // Act
+        let result = sut.deserialize_high_scores(&format!("{table_line}\nSAM|1000|5||0"));
+
+        // Assert
+        assert_eq!(result.unwrap_err(), expected_error);
+    }
+
+    #[test]
+    fn serialize_deserialize_keeps_all_fields() {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+        let mut high_scores = HighScores::new();
+        let mut high_score = HighScore::with_replay(
+            "SAM".to_string(),
+            GameResult {
+                score: 12000,
+                lines: 120,
+                level: 13,
+                start_level: 1,
+                time: Duration::from_millis(754_250),
+            },
+            "2026-10-18_12-43-05".to_string(),
+        );
+        high_score.date_time = Some(DateTime::from_unix_timestamp(1_792_327_385));
+        high_scores.add(high_score);
+
+        // Act
+        let serialized = sut.serialize_high_scores(&create_marathon_tables(&high_scores));
+        let result = sut.deserialize_high_scores(&serialized);
+
+        // Assert
+        assert_eq!(
+            serialized,
+            "SAMTRIS-HIGHSCORES 2\n[marathon]\n\
+             SAM|12000|120|13|1|2026-10-18 12:43:05|754250|2026-10-18_12-43-05"
+        );
+        assert_eq!(
+            result
+                .unwrap()
+                .get(key(GameMode::Marathon))
+                .unwrap()
+                .get_scores(),
+            high_scores.get_scores()
+        );
+    }
+
+    #[rstest]
+    #[case("SAM|1000|5", 0, None)]
+    #[case("SAM|1000|5|2026-10-18_12-43-05", 0, Some("2026-10-18_12-43-05"))]
+    #[case("[marathon]\nSAM|1000|5||61500", 61_500, None)]
+    #[case(
+        "[marathon]\nSAM|1000|5|2026-10-18_12-43-05|61500",
+        61_500,
+        Some("2026-10-18_12-43-05")
+    )]
+    fn deserialize_high_scores_reads_legacy_formats(
+        #[case] data: &str,
+        #[case] expected_time_ms: u64,
+        #[case] expected_replay_name: Option<&str>,
+    ) {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+
+        // Act
+        let result = sut.deserialize_high_scores(data);
+
+        // Assert
+        let high_score_tables = result.unwrap();
+        let high_score = &high_score_tables
+            .get(key(GameMode::Marathon))
+            .unwrap()
+            .get_scores()[0];
+        assert_eq!(high_score.name, "SAM");
+        assert_eq!(
+            high_score.game_result,
+            GameResult {
+                score: 1000,
+                level: 5,
+                time: Duration::from_millis(expected_time_ms),
+                ..Default::default()
+            }
+        );
+        assert_eq!(high_score.replay_name.as_deref(), expected_replay_name);
+        assert_eq!(high_score.date_time, None);
+    }
+
+    #[rstest]
+    #[case("SAMTRIS-HIGHSCORES 3\n", "Unsupported high scores version '3'")]
+    #[case("SAMTRIS-HIGHSCORES 2\nSAM|1000|5", "Invalid line format: SAM|1000|5")]
+    #[case("SAMTRIS-HIGHSCORES 2\nSAM|1000|x|5|0||0|", "Invalid lines: x")]
+    #[case(
+        "SAMTRIS-HIGHSCORES 2\nSAM|1000|10|5|0|yesterday|0|",
+        "Invalid date and time: 'yesterday'"
+    )]
+    fn deserialize_high_scores_rejects_invalid_data(
+        #[case] data: &str,
+        #[case] expected_error: &str,
+    ) {
+        // Arrange
+        let sut = FileHighScoresRepository::new("test.dat".to_string(), "test.key".to_string());
+
+        // Act
+        let result = sut.deserialize_high_scores(data);
+
+        // Assert
+        assert_eq!(result.unwrap_err(), expected_error);
+    }
+
+    #[test]
+    fn load_migrates_legacy_file_to_current_format() {
+        // Arrange
+        let sut = create_test_repository("legacy_high_scores");
+        let legacy_data = create_legacy_file_data("SAM|1000|5\nBOB|2000|3|2026-10-18_12-43-05");
+        fs::write(&sut.file_path, legacy_data).unwrap();
+
+        // Act
+        let result = sut.load();
+
+        // Assert
+        let high_score_tables = result.unwrap();
+        let names: Vec<&str> = high_score_tables
+            .get(key(GameMode::Marathon))
+            .unwrap()
+            .get_scores()
+            .iter()
+            .map(|high_score| high_score.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["BOB", "SAM"]);
+        let saved_data = fs::read_to_string(&sut.file_path).unwrap();
+        let secret = sut.load_or_create_secret().unwrap();
+        let content = sut.verify_and_extract(&saved_data, &secret).unwrap();
+        assert_eq!(content, sut.serialize_high_scores(&high_score_tables));
+        assert_eq!(
+            sut.load()
+                .unwrap()
+                .get(key(GameMode::Marathon))
+                .unwrap()
+                .get_scores(),
+            high_score_tables
+                .get(key(GameMode::Marathon))
+                .unwrap()
+                .get_scores()
+        );
+        remove_test_files(&sut);
+    }
+
+    #[test]
+    fn load_rejects_legacy_file_written_after_migration() {
+        // Arrange
+        let sut = create_test_repository("forged_legacy_high_scores");
+        fs::write(&sut.file_path, create_legacy_file_data("SAM|1000|5")).unwrap();
+        sut.load().unwrap();
+        fs::write(&sut.file_path, create_legacy_file_data("SAM|99999|5")).unwrap();
+
+        // Act
+        let result = sut.load();
+
+        // Assert
+        assert_eq!(result.unwrap_err(), HighScoresLoadError::Tampered);
+        remove_test_files(&sut);
+    }
+
+    #[test]
+    fn load_rejects_legacy_file_written_after_migration_and_deleting_secret() {
+        // Arrange
+        let sut = create_test_repository("forged_legacy_high_scores_without_secret");
+        fs::write(&sut.file_path, create_legacy_file_data("SAM|1000|5")).unwrap();
+        sut.load().unwrap();
+        fs::remove_file(&sut.secret_file_path).unwrap();
+        fs::write(&sut.file_path, create_legacy_file_data("SAM|99999|5")).unwrap();
+
+        // Act
+        let result = sut.load();
+
+        // Assert
+        assert_eq!(result.unwrap_err(), HighScoresLoadError::Tampered);
+        remove_test_files(&sut);
+    }
+
+    #[rstest]
+    #[case("CHKSUM:12345\nSAM|1000|5", HighScoresLoadError::Tampered)]
+    #[case(
+        "CHKSUM:x\nSAM|1000|5",
+        HighScoresLoadError::Corrupted("invalid checksum".to_string())
+    )]
+    #[case(
+        "SAM|1000|5",
+        HighScoresLoadError::Corrupted("missing checksum".to_string())
+    )]
+    fn load_rejects_invalid_legacy_file(
+        #[case] legacy_text: &str,
+        #[case] expected_error: HighScoresLoadError,
+    ) {
+        // Arrange
+        let sut = create_test_repository("invalid_legacy_high_scores");
+        fs::write(
+            &sut.file_path,
+            encrypt_decrypt_legacy(legacy_text.as_bytes()),
+        )
+        .unwrap();
+
+        // Act
+        let result = sut.load();
+
+        // Assert
+        assert_eq!(result.unwrap_err(), expected_error);
+        remove_test_files(&sut);
+    }
+
+    /// Encodes the content like files were saved before the MAC.
+    fn create_legacy_file_data(content: &str) -> Vec<u8> {
+        let checksum = calculate_legacy_checksum(content);
+        encrypt_decrypt_legacy(format!("CHKSUM:{checksum}\n{content}").as_bytes())
+    }
+
+    /// Creates a repository with its files in the temp directory, without files of earlier runs.
+    fn create_test_repository(name: &str) -> FileHighScoresRepository {
+        let temp_dir = std::env::temp_dir();
+        let sut = FileHighScoresRepository::new(
+            temp_dir
+                .join(format!("samtris_test_{name}.dat"))
+                .display()
+                .to_string(),
+            temp_dir
+                .join(format!("samtris_test_{name}.key"))
+                .display()
+                .to_string(),
+        );
+        remove_test_files(&sut);
+        sut
+    }
+
+    fn remove_test_files(sut: &FileHighScoresRepository) {
+        let _ = fs::remove_file(&sut.file_path);
+        let _ = fs::remove_file(&sut.secret_file_path);
+        let _ = fs::remove_file(&sut.signed_marker_path);
+    }
+
+    fn create_marathon_tables(high_scores: &HighScores) -> HighScoreTables {
+        let mut high_score_tables = HighScoreTables::new();
+        high_score_tables.set(key(GameMode::Marathon), high_scores.clone());
+        high_score_tables
+    }
+
+    fn key(game_mode: GameMode) -> HighScoreTableKey {
+        HighScoreTableKey::with_default_rules(game_mode)
+    }
 }
@@ -1,14 +1,33 @@
+use crate::common::DateTime;
 use crate::game_logic::GameResult;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HighScore {
     pub name: String,
     pub game_result: GameResult,
+    /// Name of the replay of the game, used to verify the score.
+    pub replay_name: Option<String>,
+    /// When the high score was set, in UTC. Unknown for high scores saved before it was kept.
+    pub date_time: Option<DateTime>,
 }
 
 impl HighScore {
     pub fn new(name: String, game_result: GameResult) -> Self {
-        Self { name, game_result }
+        Self {
+            name,
+            game_result,
+            replay_name: None,
+            date_time: None,
+        }
+    }
+
+    pub fn with_replay(name: String, game_result: GameResult, replay_name: String) -> Self {
+        Self {
+            name,
+            game_result,
+            replay_name: Some(replay_name),
+            date_time: None,
+        }
     }
 }
 
@@ -22,6 +41,7 @@ mod tests {
         let game_result = GameResult {
             score: 1000,
             level: 5,
+            ..Default::default()
         };
         let sut = HighScore::new("SAM".to_string(), game_result);
 
@@ -29,5 +49,20 @@ mod tests {
         assert_eq!(sut.name, "SAM");
         assert_eq!(sut.game_result.score, 1000);
         assert_eq!(sut.game_result.level, 5);
+        assert_eq!(sut.replay_name, None);
+    }
+
+    #[test]
+    fn with_replay_links_replay() {
+        // Act
+        let game_result = GameResult {
+            score: 1000,
+            level: 5,
+            ..Default::default()
+        };
+        let sut = HighScore::with_replay("SAM".to_string(), game_result, "replay".to_string());
+
+        // Assert
+        assert_eq!(sut.replay_name, Some("replay".to_string()));
     }
 }
@@ -1,43 +1,114 @@
 // src/high_scores/manager.rs
+use crate::game_logic::{GameMode, GameResult};
 use crate::high_scores::{
-    high_scores::MAX_NR_HIGH_SCORES, HighScore, HighScores, HighScoresRepository,
+    high_scores::MAX_NR_HIGH_SCORES, HighScore, HighScoreTableKey, HighScoreTables, HighScores,
+    HighScoresLoadError, HighScoresRepository,
 };
+use crate::replay::{verify_high_score, ReplayRepository};
 
+/// Keeps the high score tables, new high scores are added to the table of its game mode and rules.
 pub struct HighScoreManager {
     repository: Box<dyn HighScoresRepository>,
-    high_scores: HighScores,
+    high_score_tables: HighScoreTables,
+    table_key: HighScoreTableKey,
+    /// Why the tables couldn't be loaded, in which case the manager started with empty tables.
+    load_error: Option<HighScoresLoadError>,
+    /// Where the replays linked to new high scores are loaded from to verify them.
+    replay_repository: Option<Box<dyn ReplayRepository>>,
 }
 
 impl HighScoreManager {
+    /// Creates a manager for marathon games.
     pub fn new(repository: Box<dyn HighScoresRepository>) -> Self {
-        let high_scores = repository.load().expect("Failed to load high scores");
+        Self::with_game_mode(repository, GameMode::Marathon)
+    }
+
+    /// Creates a manager for games of the mode played with the default rules.
+    pub fn with_game_mode(repository: Box<dyn HighScoresRepository>, game_mode: GameMode) -> Self {
+        Self::with_table_key(repository, HighScoreTableKey::with_default_rules(game_mode))
+    }
+
+    /// Starts with empty tables when the high scores can't be loaded. Tables that couldn't be
+    /// read are never saved over, corrupted and tampered tables are replaced by the new ones.
+    pub fn with_table_key(
+        repository: Box<dyn HighScoresRepository>,
+        table_key: HighScoreTableKey,
+    ) -> Self {
+        let (high_score_tables, load_error) = match repository.load() {
+            Ok(high_score_tables) => (high_score_tables, None),
+            Err(e) => {
+                eprintln!("Failed to load high scores, starting with empty tables: {e}");
+                (HighScoreTables::new(), Some(e))
+            }
+        };
         Self {
             repository,
-            high_scores,
+            high_score_tables,
+            table_key,
+            load_error,
+            replay_repository: None,
         }
     }
 
-    pub fn is_high_score(&self, score: u32) -> bool {
-        self.high_scores.is_high_score(score)
+    /// Makes add_high_score verify that the replay linked to a new high score reproduces it. High
+    /// scores without a replay are rejected from then on.
+    pub fn set_replay_repository(&mut self, replay_repository: Box<dyn ReplayRepository>) {
+        self.replay_repository = Some(replay_repository);
+    }
+
+    /// Any game is a high score when its table has no high scores yet.
+    pub fn is_high_score(&self, game_result: &GameResult) -> bool {
+        self.get_high_scores()
+            .is_none_or(|high_scores| high_scores.is_high_score(game_result))
     }
 
+    /// Adds the high score and saves the tables when it made it into the table. When there is a
+    /// replay repository, a high score without a replay that reproduces it is rejected.
     pub fn add_high_score(&mut self, high_score: HighScore) -> Result<bool, String> {
-        let was_added = self.high_scores.add(high_score) < MAX_NR_HIGH_SCORES;
+        if let Some(HighScoresLoadError::Io(e)) = &self.load_error {
+            return Err(format!(
+                "High scores couldn't be read, so they aren't saved: {e}"
+            ));
+        }
+        if let Some(replay_repository) = &self.replay_repository {
+            verify_high_score(&high_score, replay_repository.as_ref())
+                .map_err(|e| format!("High score doesn't match its replay: {e}"))?;
+        }
+
+        let high_scores = self.high_score_tables.get_mut(self.table_key);
+        let was_added = high_scores.add(high_score) < MAX_NR_HIGH_SCORES;
         if was_added {
-            self.repository.save(&self.high_scores)?;
+            self.repository.save(&self.high_score_tables)?;
         }
         Ok(was_added)
     }
 
-    pub fn get_high_scores(&self) -> &HighScores {
-        &self.high_scores
+    /// Returns the high scores of the table of the manager, None when it has none yet.
+    pub fn get_high_scores(&self) -> Option<&HighScores> {
+        self.high_score_tables.get(self.table_key)
+    }
+
+    pub fn get_high_score_tables(&self) -> &HighScoreTables {
+        &self.high_score_tables
+    }
+
+    pub fn get_table_key(&self) -> HighScoreTableKey {
+        self.table_key
+    }
+
+    pub fn get_load_error(&self) -> Option<&HighScoresLoadError> {
+        self.load_error.as_ref()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{game_logic::GameResult, high_scores::MockHighScoresRepository};
+    use crate::game_logic::GameRules;
+    use crate::gui::GameInput;
+    use crate::high_scores::MockHighScoresRepository;
+    use crate::replay::{MockReplayRepository, Replay, ReplayPlayer};
+    use rstest::rstest;
 
     #[test]
     fn new_loads_high_scores_from_repository() {
@@ -48,6 +119,7 @@ mod tests {
             GameResult {
                 score: 1000,
                 level: 5,
+                ..Default::default()
             },
         ));
         let repository = Box::new(MockHighScoresRepository::new(initial_scores));
@@ -56,7 +128,38 @@ mod tests {
         let result = HighScoreManager::new(repository);
 
         // Assert
-        assert_eq!(result.get_high_scores().len(), 1);
+        assert_eq!(result.get_high_scores().unwrap().len(), 1);
+    }
+
+    #[rstest]
+    #[case(HighScoresLoadError::Tampered, Ok(true))]
+    #[case(HighScoresLoadError::Corrupted("missing MAC".to_string()), Ok(true))]
+    #[case(
+        HighScoresLoadError::Io("Failed to read file".to_string()),
+        Err("High scores couldn't be read, so they aren't saved: Failed to read file".to_string())
+    )]
+    fn new_when_loading_fails_starts_with_empty_tables(
+        #[case] load_error: HighScoresLoadError,
+        #[case] expected_add_result: Result<bool, String>,
+    ) {
+        // Arrange
+        let mut repository = MockHighScoresRepository::empty();
+        repository.load_error = Some(load_error.clone());
+
+        // Act
+        let mut sut = HighScoreManager::new(Box::new(repository));
+
+        // Assert
+        assert!(sut.get_high_scores().unwrap().is_empty());
+        assert_eq!(sut.get_load_error(), Some(&load_error));
+        let high_score = HighScore::new(
+            "SAM".to_string(),
+            GameResult {
+                score: 500,
+                ..Default::default()
+            },
+        );
+        assert_eq!(sut.add_high_score(high_score), expected_add_result);
     }
 
     #[test]
@@ -66,7 +169,11 @@ mod tests {
         let sut = HighScoreManager::new(repository);
 
         // Act & Assert
-        assert!(sut.is_high_score(1)); // Empty list, any score qualifies
+        assert!(sut.is_high_score(&GameResult {
+            score: 1,
+            level: 0,
+            ..Default::default()
+        })); // Empty list, any score qualifies
     }
 
     #[test]
@@ -79,6 +186,7 @@ mod tests {
             GameResult {
                 score: 500,
                 level: 2,
+                ..Default::default()
             },
         );
 
@@ -88,7 +196,7 @@ mod tests {
         // Assert
         assert!(result.is_ok());
         assert!(result.unwrap()); // Score was added
-        assert_eq!(sut.get_high_scores().len(), 1);
+        assert_eq!(sut.get_high_scores().unwrap().len(), 1);
     }
 
     #[test]
@@ -101,6 +209,7 @@ mod tests {
                 GameResult {
                     score: i * 1000,
                     level: 1,
+                    ..Default::default()
                 },
             ));
         }
@@ -111,6 +220,7 @@ mod tests {
             GameResult {
                 score: 500,
                 level: 1,
+                ..Default::default()
             },
         );
 
@@ -120,6 +230,139 @@ mod tests {
         // Assert
         assert!(result.is_ok());
         assert!(!result.unwrap()); // Score was not added
-        assert_eq!(sut.get_high_scores().len(), 10); // Unchanged
+        assert_eq!(sut.get_high_scores().unwrap().len(), 10); // Unchanged
+    }
+
+    #[test]
+    fn add_high_score_adds_to_table_of_game_mode() {
+        // Arrange
+        let repository = Box::new(MockHighScoresRepository::empty());
+        let mut sut = HighScoreManager::with_game_mode(repository, GameMode::Coop);
+        let new_score = HighScore::new(
+            "TEST".to_string(),
+            GameResult {
+                score: 500,
+                level: 2,
+                ..Default::default()
+            },
+        );
+
+        // Act
+        let result = sut.add_high_score(new_score);
+
+        // Assert
+        assert_eq!(result, Ok(true));
+        let high_score_tables = sut.get_high_score_tables();
+        let coop = HighScoreTableKey::with_default_rules(GameMode::Coop);
+        let marathon = HighScoreTableKey::with_default_rules(GameMode::Marathon);
+        assert_eq!(high_score_tables.get(coop).unwrap().len(), 1);
+        assert!(high_score_tables.get(marathon).unwrap().is_empty());
+    }
+
+    #[test]
+    fn add_high_score_adds_table_for_rules_of_manager() {
+        // Arrange
+        let repository = Box::new(MockHighScoresRepository::empty());
+        let table_key = HighScoreTableKey::new(GameMode::Marathon, GameRules::without_delays());
+        let mut sut = HighScoreManager::with_table_key(repository, table_key);
+        assert!(sut.get_high_scores().is_none());
+        let new_score = HighScore::new(
+            "TEST".to_string(),
+            GameResult {
+                score: 500,
+                level: 2,
+                ..Default::default()
+            },
+        );
+
+        // Act
+        let result = sut.add_high_score(new_score);
+
+        // Assert
+        assert_eq!(result, Ok(true));
+        assert_eq!(sut.get_high_scores().unwrap().len(), 1);
+        let marathon = HighScoreTableKey::with_default_rules(GameMode::Marathon);
+        assert!(sut
+            .get_high_score_tables()
+            .get(marathon)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn add_high_score_saves_score_reproduced_by_its_replay() {
+        // Arrange
+        let replay = create_test_replay();
+        let game_result = play_to_end(&replay);
+        let mut sut = create_verifying_manager(replay);
+        let high_score = HighScore::with_replay("SAM".to_string(), game_result, "0".to_string());
+
+        // Act
+        let result = sut.add_high_score(high_score);
+
+        // Assert
+        assert_eq!(result, Ok(true));
+        assert_eq!(sut.get_high_scores().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn add_high_score_rejects_score_not_reproduced_by_its_replay() {
+        // Arrange
+        let replay = create_test_replay();
+        let mut game_result = play_to_end(&replay);
+        game_result.score += 1000;
+        let mut sut = create_verifying_manager(replay);
+        let high_score = HighScore::with_replay("SAM".to_string(), game_result, "0".to_string());
+
+        // Act
+        let result = sut.add_high_score(high_score);
+
+        // Assert
+        assert!(result.is_err());
+        assert!(sut.get_high_scores().unwrap().is_empty());
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some("missing".to_string()))]
+    fn add_high_score_rejects_score_without_loadable_replay(#[case] replay_name: Option<String>) {
+        // Arrange
+        let replay = create_test_replay();
+        let game_result = play_to_end(&replay);
+        let mut sut = create_verifying_manager(replay);
+        let mut high_score = HighScore::new("SAM".to_string(), game_result);
+        high_score.replay_name = replay_name;
+
+        // Act
+        let result = sut.add_high_score(high_score);
+
+        // Assert
+        assert!(result.is_err());
+        assert!(sut.get_high_scores().unwrap().is_empty());
+    }
+
+    fn create_verifying_manager(replay: Replay) -> HighScoreManager {
+        let mut manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        manager.set_replay_repository(Box::new(MockReplayRepository::with_replays(vec![replay])));
+        manager
+    }
+
+    fn create_test_replay() -> Replay {
+        let mut replay = Replay::new(3, GameRules::default(), 0);
+        for tick in (0..2000).step_by(25) {
+            replay.record_input(tick, GameInput::Drop);
+        }
+        replay
+    }
+
+    fn play_to_end(replay: &Replay) -> GameResult {
+        let mut player = ReplayPlayer::new(replay.clone());
+        player.run_to_end();
+        let hud_view = player.get_game().get_hud_view();
+        GameResult {
+            score: hud_view.score,
+            level: hud_view.current_level,
+            ..Default::default()
+        }
     }
 }
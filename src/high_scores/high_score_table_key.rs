@@ -0,0 +1,67 @@
+use crate::game_logic::{GameMode, GameRules};
+use crate::high_scores::Ranking;
+
+/// Identifies a high score table: games are only ranked against games of the same mode that were
+/// played with the same rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HighScoreTableKey {
+    pub game_mode: GameMode,
+    pub rules: GameRules,
+}
+
+impl HighScoreTableKey {
+    pub fn new(game_mode: GameMode, rules: GameRules) -> Self {
+        Self { game_mode, rules }
+    }
+
+    pub fn with_default_rules(game_mode: GameMode) -> Self {
+        Self::new(game_mode, GameRules::default())
+    }
+
+    pub fn get_ranking(&self) -> Ranking {
+        Ranking::for_game_mode(self.game_mode)
+    }
+
+    /// Returns the name of the table: the game mode, followed by the entry and line clear delays
+    /// in milliseconds when they aren't the default ones.
+    pub fn get_title(&self) -> String {
+        let game_mode = self.game_mode.to_string().to_uppercase();
+        if self.rules == GameRules::default() {
+            return game_mode;
+        }
+
+        format!(
+            "{game_mode} {}/{}",
+            self.rules.entry_delay.as_millis(),
+            self.rules.line_clear_delay.as_millis()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[rstest]
+    #[case(GameRules::default(), "COOP")]
+    #[case(GameRules::without_delays(), "COOP 0/0")]
+    #[case(
+        GameRules::new(Duration::from_millis(100), Duration::from_millis(300)),
+        "COOP 100/300"
+    )]
+    fn get_title_adds_delays_of_rules_that_are_not_the_default(
+        #[case] rules: GameRules,
+        #[case] expected: &str,
+    ) {
+        // Arrange
+        let sut = HighScoreTableKey::new(GameMode::Coop, rules);
+
+        // Act
+        let result = sut.get_title();
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::game_logic::GameMode;
+use crate::high_scores::{HighScoreTableKey, HighScores};
+use std::collections::BTreeMap;
+use strum::IntoEnumIterator;
+
+/// The high score tables by game mode and rules, each ranked the way its mode is. There is a table
+/// for every game mode with the default rules, tables for other rules are added with their first
+/// high score.
+#[derive(Debug, Clone)]
+pub struct HighScoreTables {
+    tables: BTreeMap<HighScoreTableKey, HighScores>,
+}
+
+impl HighScoreTables {
+    pub fn new() -> Self {
+        let tables = GameMode::iter()
+            .map(HighScoreTableKey::with_default_rules)
+            .map(|key| (key, HighScores::with_ranking(key.get_ranking())))
+            .collect();
+        Self { tables }
+    }
+
+    pub fn get(&self, key: HighScoreTableKey) -> Option<&HighScores> {
+        self.tables.get(&key)
+    }
+
+    /// Returns the table of the key, which is added empty when there is none yet.
+    pub fn get_mut(&mut self, key: HighScoreTableKey) -> &mut HighScores {
+        self.tables
+            .entry(key)
+            .or_insert_with(|| HighScores::with_ranking(key.get_ranking()))
+    }
+
+    pub fn set(&mut self, key: HighScoreTableKey, high_scores: HighScores) {
+        self.tables.insert(key, high_scores);
+    }
+
+    /// Returns the tables with their key, ordered by game mode and then by rules.
+    pub fn iter(&self) -> impl Iterator<Item = (HighScoreTableKey, &HighScores)> {
+        self.tables
+            .iter()
+            .map(|(key, high_scores)| (*key, high_scores))
+    }
+}
+
+impl Default for HighScoreTables {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{GameResult, GameRules};
+    use crate::high_scores::HighScore;
+
+    #[test]
+    fn new_has_empty_table_for_every_game_mode() {
+        // Act
+        let sut = HighScoreTables::new();
+
+        // Assert
+        for game_mode in GameMode::iter() {
+            let key = HighScoreTableKey::with_default_rules(game_mode);
+            let high_scores = sut.get(key).unwrap();
+            assert!(high_scores.is_empty());
+            assert_eq!(high_scores.get_ranking(), key.get_ranking());
+        }
+    }
+
+    #[test]
+    fn get_mut_only_changes_table_of_game_mode() {
+        // Arrange
+        let mut sut = HighScoreTables::new();
+        let coop = HighScoreTableKey::with_default_rules(GameMode::Coop);
+        let marathon = HighScoreTableKey::with_default_rules(GameMode::Marathon);
+
+        // Act
+        sut.get_mut(coop).add(create_test_high_score());
+
+        // Assert
+        assert_eq!(sut.get(coop).unwrap().len(), 1);
+        assert!(sut.get(marathon).unwrap().is_empty());
+    }
+
+    #[test]
+    fn get_mut_adds_table_for_other_rules() {
+        // Arrange
+        let mut sut = HighScoreTables::new();
+        let key = HighScoreTableKey::new(GameMode::Marathon, GameRules::without_delays());
+        assert!(sut.get(key).is_none());
+
+        // Act
+        sut.get_mut(key).add(create_test_high_score());
+
+        // Assert
+        assert_eq!(sut.get(key).unwrap().len(), 1);
+        let keys: Vec<HighScoreTableKey> = sut.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys.len(), GameMode::iter().count() + 1);
+        assert!(sut
+            .get(HighScoreTableKey::with_default_rules(GameMode::Marathon))
+            .unwrap()
+            .is_empty());
+    }
+
+    fn create_test_high_score() -> HighScore {
+        HighScore::new(
+            "SAM".to_string(),
+            GameResult {
+                score: 1000,
+                level: 5,
+                ..Default::default()
+            },
+        )
+    }
+}
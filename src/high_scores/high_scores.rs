@@ -1,38 +1,52 @@
-use crate::high_scores::HighScore;
+use crate::game_logic::GameResult;
+use crate::high_scores::{HighScore, Ranking};
+use std::cmp::Ordering;
 
 pub const MAX_NR_HIGH_SCORES: usize = 10;
 
 #[derive(Debug, Clone)]
 pub struct HighScores {
     scores: Vec<HighScore>,
+    ranking: Ranking,
 }
 
 impl HighScores {
     pub fn new() -> Self {
-        Self { scores: Vec::new() }
+        Self::with_ranking(Ranking::HigherScore)
     }
 
-    pub fn from_vec(mut scores: Vec<HighScore>) -> Self {
-        scores.sort_by(|a, b| b.game_result.score.cmp(&a.game_result.score));
+    pub fn with_ranking(ranking: Ranking) -> Self {
+        Self {
+            scores: Vec::new(),
+            ranking,
+        }
+    }
+
+    pub fn from_vec(mut scores: Vec<HighScore>, ranking: Ranking) -> Self {
+        scores.sort_by(|a, b| ranking.compare(&a.game_result, &b.game_result));
         scores.truncate(MAX_NR_HIGH_SCORES);
-        Self { scores }
+        Self { scores, ranking }
     }
 
-    pub fn is_high_score(&self, score: u32) -> bool {
+    pub fn is_high_score(&self, game_result: &GameResult) -> bool {
         self.scores.len() < MAX_NR_HIGH_SCORES
-            || score > self.scores.last().unwrap().game_result.score
+            || self
+                .ranking
+                .compare(game_result, &self.scores.last().unwrap().game_result)
+                == Ordering::Less
     }
 
     /// Adds a new high score if it qualifies as a high score. Returns the index of high_score
     /// was indeed a high score, otherwise MAX_NR_HIGH_SCORES.
     pub fn add(&mut self, high_score: HighScore) -> usize {
-        if !self.is_high_score(high_score.game_result.score) {
+        if !self.is_high_score(&high_score.game_result) {
             return MAX_NR_HIGH_SCORES;
         }
 
         self.scores.push(high_score.clone());
+        let ranking = self.ranking;
         self.scores
-            .sort_by(|a, b| b.game_result.score.cmp(&a.game_result.score));
+            .sort_by(|a, b| ranking.compare(&a.game_result, &b.game_result));
         self.scores.truncate(MAX_NR_HIGH_SCORES);
 
         if let Some(pos) = self.scores.iter().position(|s| s == &high_score) {
@@ -46,17 +60,31 @@ impl HighScores {
         &self.scores
     }
 
+    pub fn get_ranking(&self) -> Ranking {
+        self.ranking
+    }
+
     #[cfg(test)]
     pub fn len(&self) -> usize {
         self.scores.len()
     }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+}
+
+impl Default for HighScores {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::game_logic::GameResult;
-
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn new_creates_empty_high_scores() {
@@ -73,7 +101,7 @@ mod tests {
         let sut = HighScores::new();
 
         // Act & Assert
-        assert!(sut.is_high_score(100));
+        assert!(sut.is_high_score(&create_test_game_result(100)));
     }
 
     #[test]
@@ -86,14 +114,15 @@ mod tests {
                     GameResult {
                         score: i * 1000,
                         level: 1,
+                        ..Default::default()
                     },
                 )
             })
             .collect();
-        let sut = HighScores::from_vec(scores);
+        let sut = HighScores::from_vec(scores, Ranking::HigherScore);
 
         // Act
-        let result: bool = sut.is_high_score(500);
+        let result: bool = sut.is_high_score(&create_test_game_result(500));
 
         // Assert
         assert!(!result);
@@ -109,14 +138,15 @@ mod tests {
                     GameResult {
                         score: i * 1000,
                         level: 1,
+                        ..Default::default()
                     },
                 )
             })
             .collect();
-        let sut = HighScores::from_vec(scores);
+        let sut = HighScores::from_vec(scores, Ranking::HigherScore);
 
         // Act
-        let result: bool = sut.is_high_score(5500);
+        let result: bool = sut.is_high_score(&create_test_game_result(5500));
 
         // Assert
         assert!(result);
@@ -131,6 +161,7 @@ mod tests {
             GameResult {
                 score: 1000,
                 level: 1,
+                ..Default::default()
             },
         );
 
@@ -153,6 +184,7 @@ mod tests {
                 GameResult {
                     score: i * 100,
                     level: 1,
+                    ..Default::default()
                 },
             );
             sut.add(score);
@@ -162,6 +194,7 @@ mod tests {
             GameResult {
                 score: 550,
                 level: 1,
+                ..Default::default()
             },
         );
 
@@ -184,16 +217,18 @@ mod tests {
                     GameResult {
                         score: i * 1000,
                         level: 1,
+                        ..Default::default()
                     },
                 )
             })
             .collect();
-        let mut sut = HighScores::from_vec(scores);
+        let mut sut = HighScores::from_vec(scores, Ranking::HigherScore);
         let low_score = HighScore::new(
             "LOW".to_string(),
             GameResult {
                 score: 500,
                 level: 1,
+                ..Default::default()
             },
         );
 
@@ -214,6 +249,7 @@ mod tests {
                 GameResult {
                     score: 1000,
                     level: 1,
+                    ..Default::default()
                 },
             ),
             HighScore::new(
@@ -221,6 +257,7 @@ mod tests {
                 GameResult {
                     score: 3000,
                     level: 1,
+                    ..Default::default()
                 },
             ),
             HighScore::new(
@@ -228,12 +265,13 @@ mod tests {
                 GameResult {
                     score: 2000,
                     level: 1,
+                    ..Default::default()
                 },
             ),
         ];
 
         // Act
-        let sut = HighScores::from_vec(scores);
+        let sut = HighScores::from_vec(scores, Ranking::HigherScore);
 
         // Assert
         let scores = sut.get_scores();
@@ -241,4 +279,40 @@ mod tests {
         assert_eq!(scores[1].game_result.score, 2000);
         assert_eq!(scores[2].game_result.score, 1000);
     }
+
+    #[test]
+    fn add_with_lower_time_ranking_ranks_faster_game_first() {
+        // Arrange
+        let mut sut = HighScores::with_ranking(Ranking::LowerTime);
+        for seconds in [90, 60] {
+            let game_result = GameResult {
+                time: Duration::from_secs(seconds),
+                ..create_test_game_result(1000)
+            };
+            sut.add(HighScore::new(format!("P{seconds}"), game_result));
+        }
+        let new_score = HighScore::new(
+            "SAM".to_string(),
+            GameResult {
+                time: Duration::from_secs(75),
+                ..create_test_game_result(500)
+            },
+        );
+
+        // Act
+        let result = sut.add(new_score);
+
+        // Assert
+        assert_eq!(result, 1);
+        let names: Vec<&str> = sut.get_scores().iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["P60", "SAM", "P90"]);
+    }
+
+    fn create_test_game_result(score: u32) -> GameResult {
+        GameResult {
+            score,
+            level: 1,
+            ..Default::default()
+        }
+    }
 }
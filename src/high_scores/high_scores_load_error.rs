@@ -0,0 +1,24 @@
+use std::fmt;
+
+/// Why the high score tables couldn't be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HighScoresLoadError {
+    /// The file or its secret couldn't be read, it may still be fine.
+    Io(String),
+    /// The file can't be read as high scores, with the reason.
+    Corrupted(String),
+    /// The file was changed outside the game or signed by another install.
+    Tampered,
+}
+
+impl fmt::Display for HighScoresLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HighScoresLoadError::Io(error) => write!(f, "{error}"),
+            HighScoresLoadError::Corrupted(reason) => {
+                write!(f, "High scores file is corrupted: {reason}")
+            }
+            HighScoresLoadError::Tampered => write!(f, "High scores file has been tampered with"),
+        }
+    }
+}
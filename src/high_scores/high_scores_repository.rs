@@ -1,6 +1,8 @@
-use crate::high_scores::HighScores;
+use crate::high_scores::{HighScoreTables, HighScoresLoadError};
 
+/// Keeps the high score tables of all game modes.
 pub trait HighScoresRepository {
-    fn load(&self) -> Result<HighScores, String>;
-    fn save(&self, high_scores: &HighScores) -> Result<(), String>;
+    /// Returns the tables, empty for modes without high scores.
+    fn load(&self) -> Result<HighScoreTables, HighScoresLoadError>;
+    fn save(&self, high_score_tables: &HighScoreTables) -> Result<(), String>;
 }
@@ -122,6 +122,12 @@ impl HighScoresScreenBackground {
     }
 }
 
+impl Default for HighScoresScreenBackground {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
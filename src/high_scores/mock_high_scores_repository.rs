@@ -1,39 +1,55 @@
 use std::cell::RefCell;
 
-use crate::high_scores::{HighScores, HighScoresRepository};
+use crate::game_logic::GameMode;
+use crate::high_scores::{
+    HighScoreTableKey, HighScoreTables, HighScores, HighScoresLoadError, HighScoresRepository,
+};
 
 pub struct MockHighScoresRepository {
-    high_scores: RefCell<HighScores>,
+    high_score_tables: RefCell<HighScoreTables>,
+    pub load_error: Option<HighScoresLoadError>,
     pub fail_on_save: bool,
 }
 
 impl MockHighScoresRepository {
+    /// Creates a repository with the given marathon high scores.
     pub fn new(high_scores: HighScores) -> Self {
+        let mut high_score_tables = HighScoreTables::new();
+        high_score_tables.set(
+            HighScoreTableKey::with_default_rules(GameMode::Marathon),
+            high_scores,
+        );
+        Self::with_tables(high_score_tables)
+    }
+
+    pub fn with_tables(high_score_tables: HighScoreTables) -> Self {
         Self {
-            high_scores: RefCell::new(high_scores),
+            high_score_tables: RefCell::new(high_score_tables),
+            load_error: None,
             fail_on_save: false,
         }
     }
 
     pub fn empty() -> Self {
-        Self {
-            high_scores: RefCell::new(HighScores::new()),
-            fail_on_save: false,
-        }
+        Self::with_tables(HighScoreTables::new())
     }
 }
 
 impl HighScoresRepository for MockHighScoresRepository {
-    fn load(&self) -> Result<HighScores, String> {
-        Ok(self.high_scores.borrow().clone())
+    fn load(&self) -> Result<HighScoreTables, HighScoresLoadError> {
+        if let Some(load_error) = &self.load_error {
+            return Err(load_error.clone());
+        }
+
+        Ok(self.high_score_tables.borrow().clone())
     }
 
-    fn save(&self, high_scores: &HighScores) -> Result<(), String> {
+    fn save(&self, high_score_tables: &HighScoreTables) -> Result<(), String> {
         if self.fail_on_save {
             return Err("Mock save failure".to_string());
         }
 
-        *self.high_scores.borrow_mut() = high_scores.clone();
+        *self.high_score_tables.borrow_mut() = high_score_tables.clone();
         Ok(())
     }
 }
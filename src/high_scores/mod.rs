@@ -1,17 +1,27 @@
 mod file_high_scores_repository;
 mod high_score;
 mod high_score_manager;
+mod high_score_table_key;
+mod high_score_tables;
 mod high_scores;
+mod high_scores_load_error;
 mod high_scores_repository;
 mod high_scores_screen_background;
 #[cfg(test)]
 mod mock_high_scores_repository;
+mod null_high_scores_repository;
+mod ranking;
 
 pub use file_high_scores_repository::FileHighScoresRepository;
 pub use high_score::HighScore;
 pub use high_score_manager::HighScoreManager;
+pub use high_score_table_key::HighScoreTableKey;
+pub use high_score_tables::HighScoreTables;
 pub use high_scores::HighScores;
+pub use high_scores_load_error::HighScoresLoadError;
 pub use high_scores_repository::HighScoresRepository;
 pub use high_scores_screen_background::HighScoresScreenBackground;
 #[cfg(test)]
 pub use mock_high_scores_repository::MockHighScoresRepository;
+pub use null_high_scores_repository::NullHighScoresRepository;
+pub use ranking::Ranking;
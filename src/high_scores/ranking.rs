@@ -0,0 +1,107 @@
+use crate::game_logic::{GameMode, GameResult};
+use std::cmp::{Ordering, Reverse};
+
+/// How the games in a high score table are ranked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ranking {
+    HigherScore,
+    /// For modes that are played until a goal is reached, the higher score breaks ties.
+    LowerTime,
+}
+
+impl Ranking {
+    pub fn for_game_mode(game_mode: GameMode) -> Self {
+        match game_mode {
+            GameMode::Marathon | GameMode::Coop | GameMode::Puzzle => Ranking::HigherScore,
+        }
+    }
+
+    /// Orders the better result first.
+    pub fn compare(&self, a: &GameResult, b: &GameResult) -> Ordering {
+        match self {
+            Ranking::HigherScore => Reverse(a.score).cmp(&Reverse(b.score)),
+            Ranking::LowerTime => (a.time, Reverse(a.score)).cmp(&(b.time, Reverse(b.score))),
+        }
+    }
+
+    /// Returns the title of the column the tables are ranked on.
+    pub fn column_title(&self) -> &'static str {
+        match self {
+            Ranking::HigherScore => "SCORE",
+            Ranking::LowerTime => "TIME",
+        }
+    }
+
+    /// Formats the value the tables are ranked on in six characters.
+    pub fn format_game_result(&self, game_result: &GameResult) -> String {
+        match self {
+            Ranking::HigherScore => format!("{:06}", game_result.score),
+            Ranking::LowerTime => {
+                let tenths = game_result.time.as_millis() / 100;
+                format!(
+                    "{:>6}",
+                    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[rstest]
+    #[case(Ranking::HigherScore, 2000, 90, 1000, 60, Ordering::Less)]
+    #[case(Ranking::HigherScore, 1000, 60, 1000, 90, Ordering::Equal)]
+    #[case(Ranking::LowerTime, 2000, 90, 1000, 60, Ordering::Greater)]
+    #[case(Ranking::LowerTime, 2000, 60, 1000, 60, Ordering::Less)]
+    fn compare_orders_better_result_first(
+        #[case] sut: Ranking,
+        #[case] score: u32,
+        #[case] seconds: u64,
+        #[case] other_score: u32,
+        #[case] other_seconds: u64,
+        #[case] expected: Ordering,
+    ) {
+        // Arrange
+        let game_result = create_test_game_result(score, seconds);
+        let other_game_result = create_test_game_result(other_score, other_seconds);
+
+        // Act
+        let result = sut.compare(&game_result, &other_game_result);
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(Ranking::HigherScore, "012000")]
+    #[case(Ranking::LowerTime, "1:05.2")]
+    fn format_game_result_formats_ranked_value(#[case] sut: Ranking, #[case] expected: &str) {
+        // Arrange
+        let game_result = GameResult {
+            level: 3,
+            score: 12000,
+            time: Duration::from_millis(65_250),
+            ..Default::default()
+        };
+
+        // Act
+        let result = sut.format_game_result(&game_result);
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    fn create_test_game_result(score: u32, seconds: u64) -> GameResult {
+        GameResult {
+            level: 0,
+            score,
+            time: Duration::from_secs(seconds),
+            ..Default::default()
+        }
+    }
+}
@@ -2,11 +2,12 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputEvent {
     KeyPressed(Key),
+    KeyReleased(Key),
     Quit,
 }
 
 /// Platform-independent key representation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Key {
     Up,
     Down,
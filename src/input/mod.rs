@@ -1,5 +1,7 @@
 mod input_event;
+#[cfg(feature = "sdl")]
 mod sdl_translator;
 
 pub use input_event::*;
+#[cfg(feature = "sdl")]
 pub use sdl_translator::translate_sdl_event;
@@ -0,0 +1,22 @@
+pub mod ai;
+pub mod animation;
+pub mod common;
+pub mod constants;
+pub mod events;
+pub mod fumen;
+pub mod game_logic;
+pub mod graphics;
+pub mod gui;
+pub mod high_scores;
+pub mod input;
+pub mod menu;
+pub mod network;
+pub mod puzzle;
+pub mod replay;
+pub mod save_game;
+pub mod screens;
+pub mod simulation;
+pub mod statistics;
+#[cfg(test)]
+mod test_helpers;
+pub mod tetromino;
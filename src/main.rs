@@ -1,35 +1,38 @@
-use crate::constants::*;
-use crate::game_logic::GameTimer;
-use crate::graphics::SdlDisplay;
-use crate::high_scores::FileHighScoresRepository;
-use crate::high_scores::HighScoreManager;
-use crate::input::translate_sdl_event;
-use crate::input::InputEvent;
-use crate::screens::EnterHighScoreScreen;
-use crate::screens::GameScreen;
-use crate::screens::HighScoresScreen;
-use crate::screens::MenuScreen;
-use crate::screens::Screen;
-use crate::screens::ScreenResult;
+use samtrisrust::constants::*;
+use samtrisrust::fumen::Fumen;
+use samtrisrust::game_logic::{GameMode, GameTimer};
+use samtrisrust::graphics::SdlDisplay;
+use samtrisrust::high_scores::FileHighScoresRepository;
+use samtrisrust::high_scores::HighScoreManager;
+use samtrisrust::high_scores::HighScoreTableKey;
+use samtrisrust::input::translate_sdl_event;
+use samtrisrust::input::InputEvent;
+use samtrisrust::puzzle::FilePuzzleRepository;
+use samtrisrust::replay::FileReplayRepository;
+use samtrisrust::save_game::{FileSavedGameRepository, SavedGameRepository};
+use samtrisrust::screens::BoardEditorScreen;
+use samtrisrust::screens::EnterHighScoreScreen;
+use samtrisrust::screens::GameScreen;
+use samtrisrust::screens::HighScoresScreen;
+use samtrisrust::screens::HistoryScreen;
+use samtrisrust::screens::MenuScreen;
+use samtrisrust::screens::NetworkVersusScreen;
+use samtrisrust::screens::PuzzleScreen;
+use samtrisrust::screens::PuzzleSelectScreen;
+use samtrisrust::screens::ReplayListScreen;
+use samtrisrust::screens::ReplayScreen;
+use samtrisrust::screens::Screen;
+use samtrisrust::screens::ScreenResult;
+use samtrisrust::screens::StatisticsScreen;
+use samtrisrust::screens::VersusScreen;
+use samtrisrust::statistics::{
+    FileGameHistoryRepository, FileLifetimeStatisticsRepository, GameHistoryRepository,
+    LifetimeStatisticsRepository,
+};
 use sdl2::image::{self, InitFlag, LoadTexture};
 use sdl2::EventPump;
 use std::time::Duration;
 
-mod animation;
-mod common;
-mod constants;
-mod events;
-mod game_logic;
-mod graphics;
-mod gui;
-mod high_scores;
-mod input;
-mod menu;
-mod screens;
-#[cfg(test)]
-mod test_helpers;
-mod tetromino;
-
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sdl_context = sdl2::init()?;
     let _image_context = image::init(InitFlag::PNG)?;
@@ -58,7 +61,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut display = SdlDisplay::new(canvas, BLOCK_SIZE, tetrominos_texture, font);
 
-    let mut current_screen: Box<dyn Screen> = Box::new(MenuScreen::new());
+    let mut current_screen = create_start_screen();
     let mut game_timer = GameTimer::new();
 
     'running: loop {
@@ -69,18 +72,78 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ScreenResult::Continue => {}
             ScreenResult::Quit => break 'running,
             ScreenResult::Play => {
-                current_screen = Box::new(GameScreen::new(create_high_score_manager()));
+                current_screen = Box::new(GameScreen::new(
+                    create_high_score_manager(),
+                    Box::new(FileReplayRepository::new(REPLAYS_DIR)),
+                    Box::new(FileSavedGameRepository::new(SAVED_GAME_FILE)),
+                    create_lifetime_statistics_repository(),
+                    create_game_history_repository(),
+                ));
+            }
+            ScreenResult::PlayPractice => {
+                current_screen = Box::new(GameScreen::new_practice(create_high_score_manager()));
+            }
+            ScreenResult::ContinueGame => {
+                current_screen = create_continue_screen();
+            }
+            ScreenResult::PlayVersus => {
+                current_screen = Box::new(VersusScreen::new(
+                    rand::random(),
+                    [create_high_score_manager(), create_high_score_manager()],
+                ));
+            }
+            ScreenResult::PlayCoop => {
+                current_screen = Box::new(GameScreen::new_coop(
+                    create_high_score_manager_with_table_key(
+                        HighScoreTableKey::with_default_rules(GameMode::Coop),
+                    ),
+                    create_lifetime_statistics_repository(),
+                    create_game_history_repository(),
+                ));
             }
             ScreenResult::ReturnToMainMenu => {
-                current_screen = Box::new(MenuScreen::new());
+                current_screen = create_menu_screen();
             }
             ScreenResult::ShowHighScores => {
                 current_screen = Box::new(HighScoresScreen::new(create_high_score_manager()));
             }
-            ScreenResult::EnterHighScore(game_result) => {
+            ScreenResult::ShowHistory => {
+                current_screen = Box::new(HistoryScreen::new(create_game_history_repository()));
+            }
+            ScreenResult::ShowStatistics => {
+                current_screen = Box::new(StatisticsScreen::new(
+                    create_lifetime_statistics_repository(),
+                ));
+            }
+            ScreenResult::ShowReplays => {
+                current_screen = Box::new(ReplayListScreen::new(Box::new(
+                    FileReplayRepository::new(REPLAYS_DIR),
+                )));
+            }
+            ScreenResult::WatchReplay(replay) => {
+                current_screen = Box::new(ReplayScreen::new(replay));
+            }
+            ScreenResult::ShowPuzzles => {
+                current_screen = Box::new(PuzzleSelectScreen::new(&create_puzzle_repository()));
+            }
+            ScreenResult::PlayPuzzle(name, puzzle) => {
+                current_screen = Box::new(PuzzleScreen::new(
+                    Box::new(create_puzzle_repository()),
+                    create_lifetime_statistics_repository(),
+                    create_game_history_repository(),
+                    name,
+                    puzzle,
+                ));
+            }
+            ScreenResult::EditBoard => {
+                current_screen =
+                    Box::new(BoardEditorScreen::new(Box::new(create_puzzle_repository())));
+            }
+            ScreenResult::EnterHighScore(table_key, game_result, replay_name) => {
                 current_screen = Box::new(EnterHighScoreScreen::new(
-                    create_high_score_manager(),
+                    create_high_score_manager_with_table_key(table_key),
                     game_result,
+                    replay_name,
                 ));
             }
         }
@@ -109,7 +172,106 @@ fn poll_events(event_pump: &mut EventPump) -> Vec<InputEvent> {
     events
 }
 
+/// Starts a network game with `--host [port]` or `--join <address[:port]>`, lets the AI play
+/// with `--demo`, starts a game against the AI with `--versus-cpu`, starts a practice game from
+/// a board with `--fumen <fumen>`, otherwise shows the main menu.
+fn create_start_screen() -> Box<dyn Screen> {
+    let args: Vec<String> = std::env::args().collect();
+    match (args.get(1).map(String::as_str), args.get(2)) {
+        (Some("--host"), port) => {
+            let port = port
+                .and_then(|port| port.parse().ok())
+                .unwrap_or(NETWORK_DEFAULT_PORT);
+            Box::new(NetworkVersusScreen::host(port, create_high_score_manager()))
+        }
+        (Some("--join"), Some(address)) => {
+            let address = if address.contains(':') {
+                address.clone()
+            } else {
+                format!("{address}:{NETWORK_DEFAULT_PORT}")
+            };
+            Box::new(NetworkVersusScreen::join(
+                &address,
+                create_high_score_manager(),
+            ))
+        }
+        (Some("--demo"), _) => Box::new(GameScreen::new_demo(create_high_score_manager())),
+        (Some("--fumen"), Some(fumen)) => match Fumen::decode(fumen) {
+            Ok(fumen) => Box::new(GameScreen::new_practice_from_fumen(
+                create_high_score_manager(),
+                &fumen,
+            )),
+            Err(e) => {
+                eprintln!("Failed to read fumen: {e}");
+                create_menu_screen()
+            }
+        },
+        (Some("--versus-cpu"), _) => Box::new(VersusScreen::new_against_cpu(
+            rand::random(),
+            [create_high_score_manager(), create_high_score_manager()],
+        )),
+        _ => create_menu_screen(),
+    }
+}
+
+/// Shows the main menu, with CONTINUE when there is a saved game.
+fn create_menu_screen() -> Box<dyn Screen> {
+    match FileSavedGameRepository::new(SAVED_GAME_FILE).load() {
+        Ok(Some(_)) => Box::new(MenuScreen::with_continue()),
+        _ => Box::new(MenuScreen::new()),
+    }
+}
+
+fn create_continue_screen() -> Box<dyn Screen> {
+    let saved_game_repository = FileSavedGameRepository::new(SAVED_GAME_FILE);
+    match saved_game_repository.load() {
+        Ok(Some(saved_game)) => Box::new(GameScreen::resume(
+            create_high_score_manager(),
+            Box::new(FileReplayRepository::new(REPLAYS_DIR)),
+            Box::new(saved_game_repository),
+            create_lifetime_statistics_repository(),
+            create_game_history_repository(),
+            saved_game,
+        )),
+        Ok(None) => Box::new(MenuScreen::new()),
+        Err(e) => {
+            eprintln!("Failed to load saved game: {e}");
+            Box::new(MenuScreen::new())
+        }
+    }
+}
+
+fn create_puzzle_repository() -> FilePuzzleRepository {
+    FilePuzzleRepository::new(PUZZLES_DIR, SOLVED_PUZZLES_FILE)
+}
+
+fn create_lifetime_statistics_repository() -> Box<dyn LifetimeStatisticsRepository> {
+    Box::new(FileLifetimeStatisticsRepository::new(STATISTICS_FILE))
+}
+
+fn create_game_history_repository() -> Box<dyn GameHistoryRepository> {
+    Box::new(FileGameHistoryRepository::new(
+        GAME_HISTORY_FILE,
+        GAME_HISTORY_CSV_FILE,
+    ))
+}
+
 fn create_high_score_manager() -> HighScoreManager {
-    let high_scores_repository = FileHighScoresRepository::new(HIGH_SCORES_FILE.to_string());
-    HighScoreManager::new(Box::new(high_scores_repository))
+    create_high_score_manager_with_table_key(HighScoreTableKey::with_default_rules(
+        GameMode::Marathon,
+    ))
+}
+
+fn create_high_score_manager_with_table_key(table_key: HighScoreTableKey) -> HighScoreManager {
+    let high_scores_repository = FileHighScoresRepository::new(
+        HIGH_SCORES_FILE.to_string(),
+        HIGH_SCORES_SECRET_FILE.to_string(),
+    );
+    let mut high_score_manager =
+        HighScoreManager::with_table_key(Box::new(high_scores_repository), table_key);
+    // Only marathon games are recorded, the high scores of other modes can't be verified
+    if table_key.game_mode == GameMode::Marathon {
+        high_score_manager.set_replay_repository(Box::new(FileReplayRepository::new(REPLAYS_DIR)));
+    }
+    high_score_manager
 }
@@ -21,14 +21,25 @@ impl GraphicsMenuRenderer {
         display: &mut D,
     ) -> Result<(), String> {
         let menu_start_y = WINDOW_HEIGHT_IN_BLOCKS * BLOCK_SIZE * 3 / 5; // Lower to make room for tetromino title
-        let line_height = CHAR_HEIGHT * 2;
+        let line_height = CHAR_HEIGHT * 3 / 2;
+
+        // Only the items that fit below the title are shown, scrolled to keep the selected one
+        // visible
+        let first_visible_index =
+            (menu.get_selected_index() + 1).saturating_sub(MENU_VISIBLE_ITEMS);
+        let visible_items = menu
+            .get_items()
+            .iter()
+            .enumerate()
+            .skip(first_visible_index)
+            .take(MENU_VISIBLE_ITEMS);
 
-        for (index, item) in menu.get_items().iter().enumerate() {
+        for (row, (index, item)) in visible_items.enumerate() {
             let is_selected = index == menu.get_selected_index();
             let color = Color::WHITE;
 
             let text_x = WINDOW_WIDTH_IN_BLOCKS * BLOCK_SIZE / 3;
-            let text_y = menu_start_y + (index as u32 * line_height);
+            let text_y = menu_start_y + (row as u32 * line_height);
 
             if is_selected {
                 let block_pos = Position::new((text_x - BLOCK_SIZE * 2) as i32, text_y as i32);
@@ -51,6 +62,12 @@ impl GraphicsMenuRenderer {
     }
 }
 
+impl Default for GraphicsMenuRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MenuRenderer for GraphicsMenuRenderer {
     fn draw<D: Display + ?Sized>(&self, menu: &Menu, display: &mut D) -> Result<(), String> {
         self.title.draw(display)?;
@@ -84,7 +101,7 @@ mod tests {
     }
 
     #[test]
-    fn graphics_menu_renderer_draws_all_menu_items() {
+    fn graphics_menu_renderer_draws_menu_items_that_fit() {
         // Arrange
         let sut = GraphicsMenuRenderer::new();
         let menu = Menu::new();
@@ -112,7 +129,32 @@ mod tests {
             .drawn_text
             .iter()
             .any(|(text, _, _, _)| text.contains("QUIT"));
-        assert!(quit_drawn);
+        assert!(
+            !quit_drawn,
+            "QUIT is below the visible items until scrolled to"
+        );
+    }
+
+    #[test]
+    fn graphics_menu_renderer_scrolls_to_selected_item() {
+        // Arrange
+        let sut = GraphicsMenuRenderer::new();
+        let mut menu = Menu::with_continue();
+        menu.select_previous_item(); // Wraps to QUIT, the last item
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&menu, &mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let drawn_items: Vec<&str> = display
+            .drawn_text
+            .iter()
+            .map(|(text, _, _, _)| text.as_str())
+            .collect();
+        assert!(drawn_items.contains(&"QUIT"));
+        assert!(!drawn_items.contains(&"CONTINUE"));
     }
 
     #[test]
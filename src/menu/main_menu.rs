@@ -8,11 +8,30 @@ pub struct Menu {
 impl Menu {
     pub fn new() -> Self {
         Self {
-            items: vec![MenuItem::Play, MenuItem::HighScores, MenuItem::Quit],
+            items: vec![
+                MenuItem::Play,
+                MenuItem::Practice,
+                MenuItem::Puzzles,
+                MenuItem::Versus,
+                MenuItem::Coop,
+                MenuItem::HighScores,
+                MenuItem::Statistics,
+                MenuItem::History,
+                MenuItem::Replays,
+                MenuItem::BoardEditor,
+                MenuItem::Quit,
+            ],
             selected_index: 0,
         }
     }
 
+    /// Creates the main menu with CONTINUE as first and selected item.
+    pub fn with_continue() -> Self {
+        let mut menu = Self::new();
+        menu.items.insert(0, MenuItem::Continue);
+        menu
+    }
+
     pub fn get_items(&self) -> &[MenuItem] {
         &self.items
     }
@@ -42,6 +61,12 @@ impl Menu {
     }
 }
 
+impl Default for Menu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,6 +82,16 @@ mod tests {
         assert_eq!(sut.get_selected_item(), &MenuItem::Play);
     }
 
+    #[test]
+    fn with_continue_starts_with_continue_selected() {
+        // Act
+        let sut = Menu::with_continue();
+
+        // Assert
+        assert_eq!(sut.get_selected_item(), &MenuItem::Continue);
+        assert_eq!(sut.get_items().len(), Menu::new().get_items().len() + 1);
+    }
+
     #[test]
     fn select_next_item_advances_selection() {
         // Arrange
@@ -67,14 +102,14 @@ mod tests {
 
         // Assert
         assert_eq!(sut.get_selected_index(), 1);
-        assert_eq!(sut.get_selected_item(), &MenuItem::HighScores);
+        assert_eq!(sut.get_selected_item(), &MenuItem::Practice);
     }
 
     #[test]
     fn select_next_item_at_end_wraps_to_beginning() {
         // Arrange
         let mut sut = Menu::new();
-        sut.selected_index = 2; // Last item
+        sut.selected_index = 10; // Last item
 
         // Act
         sut.select_next_item();
@@ -107,14 +142,22 @@ mod tests {
         sut.select_previous_item();
 
         // Assert
-        assert_eq!(sut.get_selected_index(), 2);
+        assert_eq!(sut.get_selected_index(), 10);
         assert_eq!(sut.get_selected_item(), &MenuItem::Quit);
     }
 
     #[rstest]
     #[case(0, MenuItem::Play)]
-    #[case(1, MenuItem::HighScores)]
-    #[case(2, MenuItem::Quit)]
+    #[case(1, MenuItem::Practice)]
+    #[case(2, MenuItem::Puzzles)]
+    #[case(3, MenuItem::Versus)]
+    #[case(4, MenuItem::Coop)]
+    #[case(5, MenuItem::HighScores)]
+    #[case(6, MenuItem::Statistics)]
+    #[case(7, MenuItem::History)]
+    #[case(8, MenuItem::Replays)]
+    #[case(9, MenuItem::BoardEditor)]
+    #[case(10, MenuItem::Quit)]
     fn get_selected_item_returns_correct_item_for_index(
         #[case] index: usize,
         #[case] expected_item: MenuItem,
@@ -1,15 +1,33 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MenuItem {
+    Continue,
     Play,
+    Practice,
+    Puzzles,
+    Versus,
+    Coop,
     HighScores,
+    Statistics,
+    History,
+    Replays,
+    BoardEditor,
     Quit,
 }
 
 impl MenuItem {
     pub fn display_text(&self) -> &'static str {
         match self {
+            MenuItem::Continue => "CONTINUE",
             MenuItem::Play => "PLAY",
+            MenuItem::Practice => "PRACTICE",
+            MenuItem::Puzzles => "PUZZLES",
+            MenuItem::Versus => "VERSUS",
+            MenuItem::Coop => "CO-OP",
             MenuItem::HighScores => "HIGH SCORES",
+            MenuItem::Statistics => "STATISTICS",
+            MenuItem::History => "HISTORY",
+            MenuItem::Replays => "REPLAYS",
+            MenuItem::BoardEditor => "BOARD EDITOR",
             MenuItem::Quit => "QUIT",
         }
     }
@@ -21,8 +39,17 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
+    #[case(MenuItem::Continue, "CONTINUE")]
     #[case(MenuItem::Play, "PLAY")]
+    #[case(MenuItem::Practice, "PRACTICE")]
+    #[case(MenuItem::Puzzles, "PUZZLES")]
+    #[case(MenuItem::Versus, "VERSUS")]
+    #[case(MenuItem::Coop, "CO-OP")]
     #[case(MenuItem::HighScores, "HIGH SCORES")]
+    #[case(MenuItem::Statistics, "STATISTICS")]
+    #[case(MenuItem::History, "HISTORY")]
+    #[case(MenuItem::Replays, "REPLAYS")]
+    #[case(MenuItem::BoardEditor, "BOARD EDITOR")]
     #[case(MenuItem::Quit, "QUIT")]
     fn display_text_returns_correct_string(#[case] menu_item: MenuItem, #[case] expected: &str) {
         // Act
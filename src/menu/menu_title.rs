@@ -52,6 +52,12 @@ impl MenuTitle {
     }
 }
 
+impl Default for MenuTitle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
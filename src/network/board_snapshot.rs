@@ -0,0 +1,275 @@
+use crate::common::{Dimensions, Position};
+use crate::game_logic::{GameStatistics, PlayfieldGrid};
+use crate::graphics::{HudView, PlayfieldView};
+use crate::tetromino::{TetrominoDefinitions, TetrominoInstance, TetrominoType};
+
+/// Everything needed to draw the board of a remote player.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardSnapshot {
+    pub grid: PlayfieldGrid,
+    pub current_tetromino: Option<TetrominoInstance>,
+    pub hud_view: HudView,
+}
+
+impl BoardSnapshot {
+    /// Only the finesse faults of the statistics are sent to the peer, so the others are left out
+    /// of the snapshot too.
+    pub fn from_views(playfield_view: &PlayfieldView, hud_view: &HudView) -> Self {
+        Self {
+            grid: playfield_view.grid.clone(),
+            current_tetromino: playfield_view
+                .current_tetrominoes
+                .first()
+                .map(|t| (*t).clone()),
+            hud_view: HudView {
+                statistics: GameStatistics {
+                    nr_finesse_faults: hud_view.statistics.nr_finesse_faults,
+                    ..GameStatistics::new()
+                },
+                ..hud_view.clone()
+            },
+        }
+    }
+
+    pub fn get_playfield_view(&self) -> PlayfieldView<'_> {
+        PlayfieldView {
+            dimensions: self.grid.get_dimensions(),
+            grid: &self.grid,
+            current_tetrominoes: self.current_tetromino.iter().collect(),
+            next_tetromino_type: self.hud_view.next_tetromino_type,
+            full_lines: vec![],
+            show_blinking_lines: false,
+        }
+    }
+
+    /// Encodes the snapshot as space separated fields:
+    /// width height cells current_tetromino next_tetromino hold_tetromino level lines score
+    /// finesse_faults game_over
+    pub fn encode(&self) -> String {
+        let dimensions = self.grid.get_dimensions();
+        let mut cells = String::new();
+        for y in 0..dimensions.height {
+            for x in 0..dimensions.width {
+                let position = Position::new(x as i32, y as i32);
+                cells.push(self.grid.get(position).map_or('.', |t| t.to_char()));
+            }
+        }
+
+        format!(
+            "{} {} {} {} {} {} {} {} {} {} {}",
+            dimensions.width,
+            dimensions.height,
+            cells,
+            encode_tetromino(self.current_tetromino.as_ref()),
+            self.hud_view.next_tetromino_type.to_char(),
+            self.hud_view
+                .hold_tetromino_type
+                .map_or('-', |tetromino_type| tetromino_type.to_char()),
+            self.hud_view.current_level,
+            self.hud_view.total_lines_cleared,
+            self.hud_view.score,
+            self.hud_view.statistics.nr_finesse_faults,
+            u8::from(self.hud_view.show_game_over)
+        )
+    }
+
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = text.split(' ').collect();
+        if fields.len() != 11 {
+            return Err(format!("Invalid board snapshot: {text}"));
+        }
+
+        let width = parse_number(fields[0])?;
+        let height = parse_number(fields[1])?;
+        let grid = decode_grid(Dimensions::new(width, height), fields[2])?;
+        let current_tetromino = decode_tetromino(fields[3])?;
+        let hud_view = HudView {
+            next_tetromino_type: decode_tetromino_type(fields[4])?,
+            hold_tetromino_type: decode_optional_tetromino_type(fields[5])?,
+            current_level: parse_number(fields[6])?,
+            total_lines_cleared: parse_number(fields[7])?,
+            score: parse_number(fields[8])?,
+            statistics: GameStatistics {
+                nr_finesse_faults: parse_number(fields[9])?,
+                ..GameStatistics::new()
+            },
+            show_game_over: fields[10] == "1",
+        };
+
+        Ok(Self {
+            grid,
+            current_tetromino,
+            hud_view,
+        })
+    }
+}
+
+/// A tetromino is encoded as type,x,y,rotation or as - when there is none.
+fn encode_tetromino(tetromino: Option<&TetrominoInstance>) -> String {
+    match tetromino {
+        Some(tetromino) => {
+            let position = tetromino.get_position();
+            format!(
+                "{},{},{},{}",
+                tetromino.get_type().to_char(),
+                position.x,
+                position.y,
+                usize::from(tetromino.get_rotation_index())
+            )
+        }
+        None => "-".to_string(),
+    }
+}
+
+fn decode_tetromino(text: &str) -> Result<Option<TetrominoInstance>, String> {
+    if text == "-" {
+        return Ok(None);
+    }
+
+    let parts: Vec<&str> = text.split(',').collect();
+    if parts.len() != 4 {
+        return Err(format!("Invalid tetromino: {text}"));
+    }
+
+    let tetromino_type = decode_tetromino_type(parts[0])?;
+    let x = parts[1]
+        .parse::<i32>()
+        .map_err(|e| format!("Invalid tetromino x '{}': {e}", parts[1]))?;
+    let y = parts[2]
+        .parse::<i32>()
+        .map_err(|e| format!("Invalid tetromino y '{}': {e}", parts[2]))?;
+    let rotation: u32 = parse_number(parts[3])?;
+
+    let mut tetromino = TetrominoInstance::new(
+        tetromino_type,
+        Position::new(x, y),
+        &TetrominoDefinitions::new(),
+    );
+    for _ in 0..rotation {
+        tetromino.rotate_clockwise();
+    }
+    Ok(Some(tetromino))
+}
+
+fn decode_grid(dimensions: Dimensions, cells: &str) -> Result<PlayfieldGrid, String> {
+    let cells: Vec<char> = cells.chars().collect();
+    if cells.len() != (dimensions.width * dimensions.height) as usize {
+        return Err(format!(
+            "Expected {} cells, got {}",
+            dimensions.width * dimensions.height,
+            cells.len()
+        ));
+    }
+
+    let mut grid = PlayfieldGrid::new(dimensions);
+    for (index, ch) in cells.iter().enumerate() {
+        if *ch == '.' {
+            continue;
+        }
+        let tetromino_type = TetrominoType::from_char(*ch).ok_or(format!("Invalid cell '{ch}'"))?;
+        let x = (index as u32 % dimensions.width) as i32;
+        let y = (index as u32 / dimensions.width) as i32;
+        grid.set(Position::new(x, y), Some(tetromino_type));
+    }
+    Ok(grid)
+}
+
+fn decode_tetromino_type(text: &str) -> Result<TetrominoType, String> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => {
+            TetrominoType::from_char(ch).ok_or(format!("Invalid tetromino type '{text}'"))
+        }
+        _ => Err(format!("Invalid tetromino type '{text}'")),
+    }
+}
+
+fn decode_optional_tetromino_type(text: &str) -> Result<Option<TetrominoType>, String> {
+    if text == "-" {
+        return Ok(None);
+    }
+
+    decode_tetromino_type(text).map(Some)
+}
+
+fn parse_number(text: &str) -> Result<u32, String> {
+    text.parse::<u32>()
+        .map_err(|e| format!("Invalid number '{text}': {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_returns_encoded_snapshot() {
+        // Arrange
+        let sut = create_test_snapshot();
+
+        // Act
+        let result = BoardSnapshot::decode(&sut.encode());
+
+        // Assert
+        assert_eq!(result, Ok(sut));
+    }
+
+    #[test]
+    fn encode_writes_cells_row_by_row() {
+        // Arrange
+        let mut sut = create_test_snapshot();
+        sut.current_tetromino = None;
+
+        // Act
+        let result = sut.encode();
+
+        // Assert
+        assert_eq!(result, "3 2 ...I.T - O L 2 5 300 3 0");
+    }
+
+    #[test]
+    fn decode_with_wrong_number_of_cells_returns_error() {
+        // Act
+        let result = BoardSnapshot::decode("3 2 ...I. - O L 2 5 300 0 0");
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_with_missing_fields_returns_error() {
+        // Act
+        let result = BoardSnapshot::decode("3 2 ...I.T");
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    fn create_test_snapshot() -> BoardSnapshot {
+        let mut grid = PlayfieldGrid::new(Dimensions::new(3, 2));
+        grid.set(Position::new(0, 1), Some(TetrominoType::I));
+        grid.set(Position::new(2, 1), Some(TetrominoType::T));
+        let mut current_tetromino = TetrominoInstance::new(
+            TetrominoType::J,
+            Position::new(1, -1),
+            &TetrominoDefinitions::new(),
+        );
+        current_tetromino.rotate_counterclockwise();
+
+        BoardSnapshot {
+            grid,
+            current_tetromino: Some(current_tetromino),
+            hud_view: HudView {
+                next_tetromino_type: TetrominoType::O,
+                hold_tetromino_type: Some(TetrominoType::L),
+                current_level: 2,
+                total_lines_cleared: 5,
+                score: 300,
+                statistics: GameStatistics {
+                    nr_finesse_faults: 3,
+                    ..GameStatistics::new()
+                },
+                show_game_over: false,
+            },
+        }
+    }
+}
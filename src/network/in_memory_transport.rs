@@ -0,0 +1,42 @@
+use crate::network::{NetworkMessage, Transport};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+
+/// Transport between two peers in the same process, for testing network games without sockets.
+pub struct InMemoryTransport {
+    outgoing: Sender<NetworkMessage>,
+    incoming: Receiver<NetworkMessage>,
+}
+
+impl InMemoryTransport {
+    /// Creates two transports that are connected to each other.
+    pub fn pair() -> (Self, Self) {
+        let (first_sender, first_receiver) = mpsc::channel();
+        let (second_sender, second_receiver) = mpsc::channel();
+        (
+            Self {
+                outgoing: first_sender,
+                incoming: second_receiver,
+            },
+            Self {
+                outgoing: second_sender,
+                incoming: first_receiver,
+            },
+        )
+    }
+}
+
+impl Transport for InMemoryTransport {
+    fn send(&mut self, message: &NetworkMessage) -> Result<(), String> {
+        self.outgoing
+            .send(message.clone())
+            .map_err(|_| "Connection closed".to_string())
+    }
+
+    fn receive(&mut self) -> Result<Option<NetworkMessage>, String> {
+        match self.incoming.try_recv() {
+            Ok(message) => Ok(Some(message)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err("Connection closed".to_string()),
+        }
+    }
+}
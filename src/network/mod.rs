@@ -0,0 +1,15 @@
+mod board_snapshot;
+#[cfg(test)]
+mod in_memory_transport;
+mod network_message;
+mod network_versus;
+mod tcp_transport;
+mod transport;
+
+pub use board_snapshot::BoardSnapshot;
+#[cfg(test)]
+pub use in_memory_transport::InMemoryTransport;
+pub use network_message::NetworkMessage;
+pub use network_versus::*;
+pub use tcp_transport::TcpTransport;
+pub use transport::Transport;
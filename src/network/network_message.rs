@@ -0,0 +1,112 @@
+use crate::network::BoardSnapshot;
+
+/// Messages exchanged between the peers of a network game. Every message is sent as a single
+/// line of text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetworkMessage {
+    Seed(u64),                 // Seed for the tetromino generators, sent by the host
+    Garbage(u32, u32),         // Number of garbage lines, column of the hole
+    Board(Box<BoardSnapshot>), // Board of the sender, for drawing it at the peer
+    ToppedOut,                 // The sender has lost
+}
+
+impl NetworkMessage {
+    pub fn encode(&self) -> String {
+        match self {
+            NetworkMessage::Seed(seed) => format!("SEED {seed}"),
+            NetworkMessage::Garbage(nr_lines, hole_column) => {
+                format!("GARBAGE {nr_lines} {hole_column}")
+            }
+            NetworkMessage::Board(board_snapshot) => format!("BOARD {}", board_snapshot.encode()),
+            NetworkMessage::ToppedOut => "TOPPED_OUT".to_string(),
+        }
+    }
+
+    pub fn decode(line: &str) -> Result<Self, String> {
+        let (command, arguments) = line.split_once(' ').unwrap_or((line, ""));
+        match command {
+            "SEED" => arguments
+                .parse::<u64>()
+                .map(NetworkMessage::Seed)
+                .map_err(|e| format!("Invalid seed '{arguments}': {e}")),
+            "GARBAGE" => decode_garbage(arguments),
+            "BOARD" => BoardSnapshot::decode(arguments)
+                .map(|board_snapshot| NetworkMessage::Board(Box::new(board_snapshot))),
+            "TOPPED_OUT" => Ok(NetworkMessage::ToppedOut),
+            _ => Err(format!("Unknown message: {line}")),
+        }
+    }
+}
+
+fn decode_garbage(arguments: &str) -> Result<NetworkMessage, String> {
+    let numbers: Vec<u32> = arguments
+        .split(' ')
+        .map(|number| number.parse::<u32>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Invalid garbage '{arguments}': {e}"))?;
+
+    match numbers[..] {
+        [nr_lines, hole_column] => Ok(NetworkMessage::Garbage(nr_lines, hole_column)),
+        _ => Err(format!("Invalid garbage '{arguments}'")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Dimensions;
+    use crate::game_logic::{GameStatistics, PlayfieldGrid};
+    use crate::graphics::HudView;
+    use crate::tetromino::TetrominoType;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(NetworkMessage::Seed(1234567890123), "SEED 1234567890123")]
+    #[case(NetworkMessage::Garbage(4, 7), "GARBAGE 4 7")]
+    #[case(NetworkMessage::ToppedOut, "TOPPED_OUT")]
+    fn encode_returns_expected_line(#[case] message: NetworkMessage, #[case] expected: &str) {
+        // Act
+        let result = message.encode();
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(NetworkMessage::Seed(42))]
+    #[case(NetworkMessage::Garbage(2, 0))]
+    #[case(NetworkMessage::ToppedOut)]
+    #[case(NetworkMessage::Board(Box::new(BoardSnapshot {
+        grid: PlayfieldGrid::new(Dimensions::new(2, 2)),
+        current_tetromino: None,
+        hud_view: HudView {
+            next_tetromino_type: TetrominoType::S,
+            hold_tetromino_type: None,
+            current_level: 0,
+            total_lines_cleared: 0,
+            score: 0,
+            statistics: GameStatistics::new(),
+            show_game_over: true,
+        },
+    })))]
+    fn decode_returns_encoded_message(#[case] message: NetworkMessage) {
+        // Act
+        let result = NetworkMessage::decode(&message.encode());
+
+        // Assert
+        assert_eq!(result, Ok(message));
+    }
+
+    #[rstest]
+    #[case("HELLO")]
+    #[case("SEED abc")]
+    #[case("GARBAGE 4")]
+    #[case("GARBAGE 4 x")]
+    fn decode_with_invalid_line_returns_error(#[case] line: &str) {
+        // Act
+        let result = NetworkMessage::decode(line);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,432 @@
+use crate::common::SeededRng;
+use crate::constants::PLAYFIELD_WIDTH;
+use crate::game_logic::{get_garbage_lines_for_events, Game, GameState};
+use crate::graphics::{Display, HudRenderer, PlayfieldRenderer};
+use crate::gui::GameInput;
+use crate::network::{BoardSnapshot, NetworkMessage, Transport};
+use crate::tetromino::TetrominoGenerator;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkVersusState {
+    Playing,
+    Won,
+    Lost,
+    Draw,
+    ConnectionLost(String),
+}
+
+/// Returns the seed the host sends right after the connection is made, or None when it has not
+/// arrived yet. Messages that arrive after the seed stay queued in the transport.
+pub fn try_receive_seed<X: Transport>(transport: &mut X) -> Result<Option<u64>, String> {
+    while let Some(message) = transport.receive()? {
+        if let NetworkMessage::Seed(seed) = message {
+            return Ok(Some(seed));
+        }
+    }
+    Ok(None)
+}
+
+/// A versus game against a peer on another machine. The local game is simulated here, the board
+/// of the peer is only drawn from the snapshots it sends. Lines cleared locally first cancel the
+/// garbage pending from the peer, the rest is sent to the peer. When both players top out before
+/// hearing from the other, both receive the ToppedOut of the other after losing, which is a draw.
+pub struct NetworkVersus<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator, X: Transport>
+{
+    game: Game<R, H, T>,
+    transport: X,
+    remote_board: Option<BoardSnapshot>,
+    last_sent_board: Option<BoardSnapshot>,
+    remote_playfield_renderer: R,
+    remote_hud_renderer: H,
+    garbage_hole_rng: SeededRng,
+    state: NetworkVersusState,
+}
+
+impl<R: PlayfieldRenderer, H: HudRenderer, T: TetrominoGenerator, X: Transport>
+    NetworkVersus<R, H, T, X>
+{
+    pub fn new(
+        game: Game<R, H, T>,
+        transport: X,
+        remote_playfield_renderer: R,
+        remote_hud_renderer: H,
+        seed: u64,
+    ) -> Self {
+        Self {
+            game,
+            transport,
+            remote_board: None,
+            last_sent_board: None,
+            remote_playfield_renderer,
+            remote_hud_renderer,
+            garbage_hole_rng: SeededRng::new(seed),
+            state: NetworkVersusState::Playing,
+        }
+    }
+
+    pub fn get_state(&self) -> &NetworkVersusState {
+        &self.state
+    }
+
+    #[cfg(test)]
+    pub fn get_game(&self) -> &Game<R, H, T> {
+        &self.game
+    }
+
+    #[cfg(test)]
+    pub fn get_game_mut(&mut self) -> &mut Game<R, H, T> {
+        &mut self.game
+    }
+
+    #[cfg(test)]
+    pub fn get_remote_board(&self) -> Option<&BoardSnapshot> {
+        self.remote_board.as_ref()
+    }
+
+    pub fn handle_input(&mut self, input: GameInput) {
+        if self.state == NetworkVersusState::Playing {
+            self.game.handle_input(input);
+        }
+    }
+
+    pub fn set_held_inputs(&mut self, held_inputs: &[GameInput]) {
+        self.game.set_held_inputs(held_inputs);
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        let result = match self.state {
+            NetworkVersusState::Playing => self.exchange_messages(delta_time),
+            // The peer may have topped out too before our ToppedOut arrived.
+            NetworkVersusState::Lost => self.receive_messages(),
+            _ => return,
+        };
+
+        if let Err(error) = result {
+            if self.state == NetworkVersusState::Playing {
+                self.state = NetworkVersusState::ConnectionLost(error);
+            }
+        }
+    }
+
+    fn exchange_messages(&mut self, delta_time: Duration) -> Result<(), String> {
+        self.receive_messages()?;
+        if self.state != NetworkVersusState::Playing {
+            return Ok(());
+        }
+
+        let events = self.game.update(delta_time);
+
+        let nr_lines = self
+            .game
+            .cancel_garbage(get_garbage_lines_for_events(&events));
+        if nr_lines > 0 {
+            let hole_column = self.garbage_hole_rng.next_index(PLAYFIELD_WIDTH);
+            self.transport
+                .send(&NetworkMessage::Garbage(nr_lines, hole_column))?;
+        }
+
+        let board_snapshot =
+            BoardSnapshot::from_views(&self.game.get_playfield_view(), &self.game.get_hud_view());
+        if self.last_sent_board.as_ref() != Some(&board_snapshot) {
+            self.transport
+                .send(&NetworkMessage::Board(Box::new(board_snapshot.clone())))?;
+            self.last_sent_board = Some(board_snapshot);
+        }
+
+        if *self.game.get_game_state() == GameState::GameOver {
+            self.transport.send(&NetworkMessage::ToppedOut)?;
+            self.state = NetworkVersusState::Lost;
+        }
+
+        Ok(())
+    }
+
+    fn receive_messages(&mut self) -> Result<(), String> {
+        while let Some(message) = self.transport.receive()? {
+            match message {
+                NetworkMessage::Garbage(nr_lines, hole_column) => {
+                    self.game.receive_garbage(nr_lines, hole_column);
+                }
+                NetworkMessage::Board(board_snapshot) => {
+                    self.remote_board = Some(*board_snapshot);
+                }
+                NetworkMessage::ToppedOut => {
+                    self.state = if self.state == NetworkVersusState::Lost {
+                        NetworkVersusState::Draw
+                    } else {
+                        NetworkVersusState::Won
+                    };
+                }
+                NetworkMessage::Seed(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws the local board and the last received board of the peer without clearing or
+    /// presenting the display.
+    pub fn draw_boards(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        self.game.draw_board(display)?;
+
+        if let Some(remote_board) = &self.remote_board {
+            self.remote_playfield_renderer
+                .draw(&remote_board.get_playfield_view(), display)?;
+            self.remote_hud_renderer
+                .draw(&remote_board.hud_view, display)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event, EventQueue};
+    use crate::game_logic::PlayfieldState;
+    use crate::graphics::{MockDisplay, MockHudRenderer, MockPlayfieldRenderer};
+    use crate::network::InMemoryTransport;
+    use crate::test_helpers::*;
+    use crate::tetromino::FixedTetrominoGenerator;
+    use std::sync::Arc;
+
+    type TestNetworkVersus = NetworkVersus<
+        MockPlayfieldRenderer,
+        MockHudRenderer,
+        FixedTetrominoGenerator,
+        InMemoryTransport,
+    >;
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    #[test]
+    fn try_receive_seed_returns_seed_and_keeps_later_messages() {
+        // Arrange
+        let (mut host, mut sut) = InMemoryTransport::pair();
+        host.send(&NetworkMessage::Seed(1234)).unwrap();
+        host.send(&NetworkMessage::ToppedOut).unwrap();
+
+        // Act
+        let result = try_receive_seed(&mut sut);
+
+        // Assert
+        assert_eq!(result, Ok(Some(1234)));
+        assert_eq!(sut.receive(), Ok(Some(NetworkMessage::ToppedOut)));
+    }
+
+    #[test]
+    fn try_receive_seed_before_seed_arrived_returns_none() {
+        // Arrange
+        let (_host, mut sut) = InMemoryTransport::pair();
+
+        // Act
+        let result = try_receive_seed(&mut sut);
+
+        // Assert
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn update_sends_garbage_for_cleared_lines_to_peer() {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let (transport, peer_transport) = InMemoryTransport::pair();
+        let mut sut = create_test_network_versus(transport, event_queue.clone());
+        let mut peer = create_test_network_versus(peer_transport, Arc::new(EventQueue::new()));
+        event_queue.push_back(Event::LinesCleared(4));
+
+        // Act
+        sut.update(FRAME);
+        peer.update(FRAME);
+
+        // Assert
+        let pending_garbage = peer.get_game().get_playfield().get_pending_garbage();
+        assert_eq!(pending_garbage.len(), 1);
+        assert_eq!(pending_garbage[0].0, 4);
+    }
+
+    #[test]
+    fn update_cancels_pending_garbage_before_sending_garbage_to_peer() {
+        // Arrange
+        let event_queue = Arc::new(EventQueue::new());
+        let (transport, peer_transport) = InMemoryTransport::pair();
+        let mut sut = create_test_network_versus(transport, event_queue.clone());
+        let mut peer = create_test_network_versus(peer_transport, Arc::new(EventQueue::new()));
+        sut.get_game_mut().receive_garbage(3, 0);
+        event_queue.push_back(Event::LinesCleared(4));
+
+        // Act
+        sut.update(FRAME);
+        peer.update(FRAME);
+
+        // Assert
+        assert!(sut
+            .get_game()
+            .get_playfield()
+            .get_pending_garbage()
+            .is_empty());
+        let pending_garbage = peer.get_game().get_playfield().get_pending_garbage();
+        assert_eq!(pending_garbage.len(), 1);
+        assert_eq!(pending_garbage[0].0, 1);
+    }
+
+    #[test]
+    fn update_sends_board_to_peer() {
+        // Arrange
+        let (mut sut, mut peer) = create_connected_pair();
+        sut.handle_input(GameInput::MoveLeft);
+
+        // Act
+        sut.update(FRAME);
+        peer.update(FRAME);
+
+        // Assert
+        let remote_tetromino = peer
+            .get_remote_board()
+            .unwrap()
+            .current_tetromino
+            .as_ref()
+            .unwrap()
+            .get_position();
+        let local_tetromino = sut
+            .get_game()
+            .get_playfield()
+            .get_current_tetromino()
+            .unwrap()
+            .get_position();
+        assert_eq!(remote_tetromino, local_tetromino);
+    }
+
+    #[test]
+    fn update_when_topped_out_loses_and_peer_wins() {
+        // Arrange
+        let (mut sut, mut peer) = create_connected_pair();
+        sut.get_game_mut()
+            .get_playfield_mut()
+            .set_state(PlayfieldState::GameOver);
+
+        // Act
+        sut.update(FRAME);
+        peer.update(FRAME);
+
+        // Assert
+        assert_eq!(sut.get_state(), &NetworkVersusState::Lost);
+        assert_eq!(peer.get_state(), &NetworkVersusState::Won);
+    }
+
+    #[test]
+    fn update_without_changes_sends_board_only_once() {
+        // Arrange
+        let (transport, mut peer_transport) = InMemoryTransport::pair();
+        let mut sut = create_test_network_versus(transport, Arc::new(EventQueue::new()));
+
+        // Act
+        sut.update(FRAME);
+        sut.update(FRAME);
+
+        // Assert
+        assert!(matches!(
+            peer_transport.receive(),
+            Ok(Some(NetworkMessage::Board(_)))
+        ));
+        assert_eq!(peer_transport.receive(), Ok(None));
+    }
+
+    #[test]
+    fn update_when_peer_topped_out_after_losing_is_draw() {
+        // Arrange
+        let (transport, mut peer_transport) = InMemoryTransport::pair();
+        let mut sut = create_test_network_versus(transport, Arc::new(EventQueue::new()));
+        sut.get_game_mut()
+            .get_playfield_mut()
+            .set_state(PlayfieldState::GameOver);
+        sut.update(FRAME);
+        peer_transport.send(&NetworkMessage::ToppedOut).unwrap();
+
+        // Act
+        sut.update(FRAME);
+
+        // Assert
+        assert_eq!(sut.get_state(), &NetworkVersusState::Draw);
+    }
+
+    #[test]
+    fn update_after_losing_when_peer_disconnected_stays_lost() {
+        // Arrange
+        let (mut sut, peer) = create_connected_pair();
+        sut.get_game_mut()
+            .get_playfield_mut()
+            .set_state(PlayfieldState::GameOver);
+        sut.update(FRAME);
+        drop(peer);
+
+        // Act
+        sut.update(FRAME);
+
+        // Assert
+        assert_eq!(sut.get_state(), &NetworkVersusState::Lost);
+    }
+
+    #[test]
+    fn update_when_peer_disconnected_sets_connection_lost() {
+        // Arrange
+        let (mut sut, peer) = create_connected_pair();
+        drop(peer);
+
+        // Act
+        sut.update(FRAME);
+
+        // Assert
+        assert!(matches!(
+            sut.get_state(),
+            NetworkVersusState::ConnectionLost(_)
+        ));
+    }
+
+    #[test]
+    fn draw_boards_draws_remote_board_once_received() {
+        // Arrange
+        let (mut sut, mut peer) = create_connected_pair();
+        peer.update(FRAME);
+        sut.update(FRAME);
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw_boards(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(sut.remote_playfield_renderer.get_draw_calls().len(), 1);
+        assert_eq!(sut.remote_hud_renderer.get_draw_calls().len(), 1);
+    }
+
+    fn create_connected_pair() -> (TestNetworkVersus, TestNetworkVersus) {
+        let (first_transport, second_transport) = InMemoryTransport::pair();
+        (
+            create_test_network_versus(first_transport, Arc::new(EventQueue::new())),
+            create_test_network_versus(second_transport, Arc::new(EventQueue::new())),
+        )
+    }
+
+    fn create_test_network_versus(
+        transport: InMemoryTransport,
+        event_queue: Arc<EventQueue>,
+    ) -> TestNetworkVersus {
+        let playfield = create_test_playfield_with_event_queue(event_queue.clone());
+        let mut game = Game::new(
+            playfield,
+            MockPlayfieldRenderer::new(),
+            MockHudRenderer::new(),
+            event_queue,
+            create_test_high_score_manager(),
+        );
+        game.start_level(0);
+        NetworkVersus::new(
+            game,
+            transport,
+            MockPlayfieldRenderer::new(),
+            MockHudRenderer::new(),
+            42,
+        )
+    }
+}
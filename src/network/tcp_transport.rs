@@ -0,0 +1,197 @@
+use crate::network::{NetworkMessage, Transport};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Transport over a TCP connection. Incoming lines are read and outgoing lines are written on
+/// separate threads, so neither send nor receive blocks the game loop.
+pub struct TcpTransport {
+    stream: TcpStream,
+    outgoing: Sender<String>,
+    incoming: Receiver<Result<NetworkMessage, String>>,
+}
+
+impl TcpTransport {
+    pub fn connect(address: &str) -> Result<Self, String> {
+        let socket_address = address
+            .to_socket_addrs()
+            .map_err(|e| format!("Invalid address '{address}': {e}"))?
+            .next()
+            .ok_or(format!("Invalid address '{address}'"))?;
+        let stream = TcpStream::connect_timeout(&socket_address, CONNECT_TIMEOUT)
+            .map_err(|e| format!("Failed to connect to {address}: {e}"))?;
+        Self::from_stream(stream)
+    }
+
+    /// Returns a transport for the first peer that connected to the listener, or None when no
+    /// peer has connected yet. The listener has to be non-blocking.
+    pub fn try_accept(listener: &TcpListener) -> Result<Option<Self>, String> {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                stream
+                    .set_nonblocking(false)
+                    .map_err(|e| format!("Failed to configure connection: {e}"))?;
+                Self::from_stream(stream).map(Some)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
+            Err(e) => Err(format!("Failed to accept connection: {e}")),
+        }
+    }
+
+    fn from_stream(stream: TcpStream) -> Result<Self, String> {
+        stream
+            .set_nodelay(true)
+            .map_err(|e| format!("Failed to configure connection: {e}"))?;
+        let reader_stream = stream
+            .try_clone()
+            .map_err(|e| format!("Failed to configure connection: {e}"))?;
+        let writer_stream = stream
+            .try_clone()
+            .map_err(|e| format!("Failed to configure connection: {e}"))?;
+
+        let (sender, incoming) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(reader_stream).lines() {
+                let message = line
+                    .map_err(|e| format!("Connection lost: {e}"))
+                    .and_then(|line| NetworkMessage::decode(&line));
+                if sender.send(message).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let (outgoing, lines_to_write) = mpsc::channel::<String>();
+        thread::spawn(move || {
+            let mut writer = BufWriter::new(writer_stream);
+            while let Ok(line) = lines_to_write.recv() {
+                // Lines queued in the meantime are written before flushing, so a slow connection
+                // costs a single write per batch instead of one per message.
+                let mut result = writeln!(writer, "{line}");
+                for line in lines_to_write.try_iter() {
+                    result = result.and_then(|_| writeln!(writer, "{line}"));
+                }
+                if result.and_then(|_| writer.flush()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            stream,
+            outgoing,
+            incoming,
+        })
+    }
+}
+
+impl Transport for TcpTransport {
+    fn send(&mut self, message: &NetworkMessage) -> Result<(), String> {
+        self.outgoing
+            .send(message.encode())
+            .map_err(|_| "Connection lost".to_string())
+    }
+
+    fn receive(&mut self) -> Result<Option<NetworkMessage>, String> {
+        match self.incoming.try_recv() {
+            Ok(message) => message.map(Some),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err("Connection closed".to_string()),
+        }
+    }
+}
+
+impl Drop for TcpTransport {
+    /// Stops the reading thread. The writing thread still writes the queued lines, and the
+    /// connection closes once it has finished.
+    fn drop(&mut self) {
+        let _ = self.stream.shutdown(std::net::Shutdown::Read);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn loopback_transports_exchange_messages() {
+        // Arrange
+        let (mut host, mut client) = create_loopback_pair();
+
+        // Act
+        client.send(&NetworkMessage::Garbage(4, 2)).unwrap();
+        host.send(&NetworkMessage::Seed(42)).unwrap();
+
+        // Assert
+        assert_eq!(receive_one(&mut host), NetworkMessage::Garbage(4, 2));
+        assert_eq!(receive_one(&mut client), NetworkMessage::Seed(42));
+    }
+
+    #[test]
+    fn receive_after_peer_disconnects_returns_error() {
+        // Arrange
+        let (mut host, client) = create_loopback_pair();
+        drop(client);
+
+        // Act
+        let result = wait_for(|| host.receive().err());
+
+        // Assert
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn messages_sent_before_drop_still_arrive() {
+        // Arrange
+        let (mut host, mut client) = create_loopback_pair();
+
+        // Act
+        client.send(&NetworkMessage::ToppedOut).unwrap();
+        drop(client);
+
+        // Assert
+        assert_eq!(receive_one(&mut host), NetworkMessage::ToppedOut);
+    }
+
+    #[test]
+    fn try_accept_without_peer_returns_none() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+
+        // Act
+        let result = TcpTransport::try_accept(&listener);
+
+        // Assert
+        assert!(matches!(result, Ok(None)));
+    }
+
+    fn create_loopback_pair() -> (TcpTransport, TcpTransport) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let client = TcpTransport::connect(&address).unwrap();
+        let host = wait_for(|| TcpTransport::try_accept(&listener).unwrap());
+        (host, client)
+    }
+
+    fn receive_one(transport: &mut TcpTransport) -> NetworkMessage {
+        wait_for(|| transport.receive().unwrap())
+    }
+
+    fn wait_for<R>(mut poll: impl FnMut() -> Option<R>) -> R {
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(5) {
+            if let Some(result) = poll() {
+                return result;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        panic!("Timed out");
+    }
+}
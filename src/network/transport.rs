@@ -0,0 +1,10 @@
+use crate::network::NetworkMessage;
+
+/// Connection to the peer of a network game.
+pub trait Transport {
+    fn send(&mut self, message: &NetworkMessage) -> Result<(), String>;
+
+    /// Returns the next received message without blocking, or None when no message is waiting.
+    /// Returns an error when the connection was lost.
+    fn receive(&mut self) -> Result<Option<NetworkMessage>, String>;
+}
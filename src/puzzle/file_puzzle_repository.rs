@@ -0,0 +1,186 @@
+use crate::puzzle::{Puzzle, PuzzleRepository};
+use std::fs;
+use std::path::PathBuf;
+
+const PUZZLE_EXTENSION: &str = "puzzle";
+
+/// Stores every puzzle in its own file in a directory, named after the file. The names of the
+/// solved puzzles are kept in a separate file, one per line.
+pub struct FilePuzzleRepository {
+    directory: PathBuf,
+    solved_file_path: PathBuf,
+}
+
+impl FilePuzzleRepository {
+    pub fn new(directory: impl Into<PathBuf>, solved_file_path: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            solved_file_path: solved_file_path.into(),
+        }
+    }
+
+    fn get_file_path(&self, name: &str) -> PathBuf {
+        self.directory.join(format!("{name}.{PUZZLE_EXTENSION}"))
+    }
+}
+
+impl PuzzleRepository for FilePuzzleRepository {
+    fn list(&self) -> Result<Vec<String>, String> {
+        if !self.directory.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&self.directory)
+            .map_err(|e| format!("Failed to read puzzles directory: {e}"))?;
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == PUZZLE_EXTENSION))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        names.sort();
+
+        Ok(names)
+    }
+
+    fn load(&self, name: &str) -> Result<Puzzle, String> {
+        let text = fs::read_to_string(self.get_file_path(name))
+            .map_err(|e| format!("Failed to read puzzle: {e}"))?;
+        Puzzle::decode(&text).map_err(|e| format!("Invalid puzzle '{name}': {e}"))
+    }
+
+    fn save(&self, name: &str, puzzle: &Puzzle) -> Result<(), String> {
+        fs::create_dir_all(&self.directory)
+            .map_err(|e| format!("Failed to create puzzles directory: {e}"))?;
+        fs::write(self.get_file_path(name), puzzle.encode())
+            .map_err(|e| format!("Failed to write puzzle: {e}"))
+    }
+
+    fn get_solved(&self) -> Result<Vec<String>, String> {
+        if !self.solved_file_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let text = fs::read_to_string(&self.solved_file_path)
+            .map_err(|e| format!("Failed to read solved puzzles: {e}"))?;
+        Ok(text.lines().map(str::to_string).collect())
+    }
+
+    fn mark_solved(&self, name: &str) -> Result<(), String> {
+        let mut solved = self.get_solved()?;
+        if solved.iter().any(|solved_name| solved_name == name) {
+            return Ok(());
+        }
+
+        solved.push(name.to_string());
+        fs::write(&self.solved_file_path, solved.join("\n") + "\n")
+            .map_err(|e| format!("Failed to write solved puzzles: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::PuzzleGoal;
+
+    #[test]
+    fn list_returns_sorted_puzzle_names_that_can_be_loaded() {
+        // Arrange
+        let directory = std::env::temp_dir().join("samtris_test_list_puzzles");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        let text = "title O\ngoal perfect_clear\npieces O\ngrid\nIIIIIIII..\n";
+        fs::write(directory.join("b.puzzle"), text).unwrap();
+        fs::write(directory.join("a.puzzle"), text).unwrap();
+        fs::write(directory.join("notes.txt"), "not a puzzle").unwrap();
+        let sut = FilePuzzleRepository::new(&directory, directory.join("solved.dat"));
+
+        // Act
+        let result = sut.list();
+
+        // Assert
+        assert_eq!(result, Ok(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(sut.load("b").unwrap().goal, PuzzleGoal::PerfectClear);
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn save_writes_puzzle_that_can_be_loaded() {
+        // Arrange
+        let directory = std::env::temp_dir().join("samtris_test_save_puzzle");
+        let _ = fs::remove_dir_all(&directory);
+        let sut = FilePuzzleRepository::new(&directory, directory.join("solved.dat"));
+        let text = "title O\ngoal perfect_clear\npieces O\ngrid\nIIIIIIII..\n";
+        let puzzle = Puzzle::decode(text).unwrap();
+
+        // Act
+        let result = sut.save("edited", &puzzle);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(sut.list(), Ok(vec!["edited".to_string()]));
+        assert_eq!(sut.load("edited"), Ok(puzzle));
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn mark_solved_adds_name_once() {
+        // Arrange
+        let directory = std::env::temp_dir().join("samtris_test_solved_puzzles");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        let sut = FilePuzzleRepository::new(&directory, directory.join("solved.dat"));
+
+        // Act
+        sut.mark_solved("first").unwrap();
+        sut.mark_solved("second").unwrap();
+        sut.mark_solved("first").unwrap();
+
+        // Assert
+        assert_eq!(
+            sut.get_solved(),
+            Ok(vec!["first".to_string(), "second".to_string()])
+        );
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn puzzles_shipped_with_the_game_can_be_loaded() {
+        // Arrange
+        let directory =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(crate::constants::PUZZLES_DIR);
+        let sut = FilePuzzleRepository::new(&directory, directory.join("solved.dat"));
+
+        // Act
+        let names = sut.list().unwrap();
+
+        // Assert
+        assert!(!names.is_empty());
+        for name in names {
+            assert!(sut.load(&name).is_ok(), "Puzzle '{name}' can't be loaded");
+        }
+    }
+
+    #[test]
+    fn load_returns_error_for_missing_puzzle() {
+        // Arrange
+        let sut = FilePuzzleRepository::new(
+            "samtris_test_nonexistent_puzzles",
+            "samtris_test_nonexistent_solved.dat",
+        );
+
+        // Act
+        let result = sut.load("missing");
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(sut.list(), Ok(Vec::new()));
+        assert_eq!(sut.get_solved(), Ok(Vec::new()));
+    }
+}
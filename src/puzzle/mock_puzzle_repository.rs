@@ -0,0 +1,60 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::puzzle::{Puzzle, PuzzleRepository};
+
+/// Keeps named puzzles in memory, clone `puzzles` or `solved` before boxing to inspect which
+/// puzzles were saved or marked as solved.
+#[derive(Default)]
+pub struct MockPuzzleRepository {
+    pub puzzles: Rc<RefCell<Vec<(String, Puzzle)>>>,
+    pub solved: Rc<RefCell<Vec<String>>>,
+}
+
+impl MockPuzzleRepository {
+    pub fn new(puzzles: Vec<(String, Puzzle)>) -> Self {
+        Self {
+            puzzles: Rc::new(RefCell::new(puzzles)),
+            solved: Rc::default(),
+        }
+    }
+}
+
+impl PuzzleRepository for MockPuzzleRepository {
+    fn list(&self) -> Result<Vec<String>, String> {
+        Ok(self
+            .puzzles
+            .borrow()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect())
+    }
+
+    fn load(&self, name: &str) -> Result<Puzzle, String> {
+        self.puzzles
+            .borrow()
+            .iter()
+            .find(|(puzzle_name, _)| puzzle_name == name)
+            .map(|(_, puzzle)| puzzle.clone())
+            .ok_or(format!("Puzzle '{name}' not found"))
+    }
+
+    fn save(&self, name: &str, puzzle: &Puzzle) -> Result<(), String> {
+        let mut puzzles = self.puzzles.borrow_mut();
+        puzzles.retain(|(puzzle_name, _)| puzzle_name != name);
+        puzzles.push((name.to_string(), puzzle.clone()));
+        Ok(())
+    }
+
+    fn get_solved(&self) -> Result<Vec<String>, String> {
+        Ok(self.solved.borrow().clone())
+    }
+
+    fn mark_solved(&self, name: &str) -> Result<(), String> {
+        let mut solved = self.solved.borrow_mut();
+        if !solved.iter().any(|solved_name| solved_name == name) {
+            solved.push(name.to_string());
+        }
+        Ok(())
+    }
+}
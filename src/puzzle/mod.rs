@@ -0,0 +1,13 @@
+mod file_puzzle_repository;
+#[cfg(test)]
+mod mock_puzzle_repository;
+mod puzzle_data;
+mod puzzle_goal;
+mod puzzle_repository;
+
+pub use file_puzzle_repository::FilePuzzleRepository;
+#[cfg(test)]
+pub use mock_puzzle_repository::MockPuzzleRepository;
+pub use puzzle_data::Puzzle;
+pub use puzzle_goal::PuzzleGoal;
+pub use puzzle_repository::PuzzleRepository;
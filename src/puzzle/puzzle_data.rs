@@ -0,0 +1,203 @@
+use crate::constants::*;
+use crate::game_logic::{PlayfieldGrid, EMPTY_CELL_CHAR};
+use crate::puzzle::PuzzleGoal;
+use crate::tetromino::TetrominoType;
+
+const COMMENT_PREFIX: char = '#';
+const GRID_SECTION: &str = "grid";
+
+/// A starting playfield, the pieces that can be used in order and the goal to reach with them.
+///
+/// Puzzles are stored as text that is easy to edit by hand:
+///
+/// ```text
+/// # Lines starting with '#' are comments
+/// title SPLIT TETRIS
+/// goal clear_lines 4
+/// pieces I I
+/// grid
+/// JJJJJJJJ..
+/// LLLLLLLL..
+/// ```
+///
+/// The grid rows are the bottom of the playfield, '.' is an empty cell and the letter of a
+/// tetromino type a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub title: String,
+    pub goal: PuzzleGoal,
+    pub pieces: Vec<TetrominoType>,
+    pub grid: PlayfieldGrid,
+}
+
+impl Puzzle {
+    pub fn encode(&self) -> String {
+        let pieces: Vec<String> = self
+            .pieces
+            .iter()
+            .map(|piece| piece.to_char().to_string())
+            .collect();
+        let mut lines = vec![
+            format!("title {}", self.title),
+            format!("goal {}", self.goal.encode()),
+            format!("pieces {}", pieces.join(" ")),
+            GRID_SECTION.to_string(),
+        ];
+        let empty_row = EMPTY_CELL_CHAR.to_string().repeat(PLAYFIELD_WIDTH as usize);
+        lines.extend(
+            self.grid
+                .to_rows()
+                .into_iter()
+                .skip_while(|row| *row == empty_row),
+        );
+
+        lines.join("\n") + "\n"
+    }
+
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let mut lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with(COMMENT_PREFIX));
+
+        let title = read_value(&mut lines, "title")?.to_string();
+        let goal = PuzzleGoal::decode(
+            &read_value(&mut lines, "goal")?
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+        )?;
+        let pieces = decode_pieces(read_value(&mut lines, "pieces")?)?;
+        if lines.next() != Some(GRID_SECTION) {
+            return Err(format!("Puzzle is missing '{GRID_SECTION}'"));
+        }
+        let grid = decode_grid(&lines.collect::<Vec<_>>())?;
+
+        Ok(Self {
+            title,
+            goal,
+            pieces,
+            grid,
+        })
+    }
+}
+
+/// Reads the next line, which must start with the given key, and returns the rest of it.
+fn read_value<'a>(lines: &mut impl Iterator<Item = &'a str>, key: &str) -> Result<&'a str, String> {
+    lines
+        .next()
+        .and_then(|line| line.strip_prefix(key))
+        .filter(|value| value.is_empty() || value.starts_with(' '))
+        .map(str::trim)
+        .ok_or(format!("Puzzle is missing '{key}'"))
+}
+
+fn decode_pieces(value: &str) -> Result<Vec<TetrominoType>, String> {
+    let pieces = value
+        .split_whitespace()
+        .map(|piece| {
+            piece
+                .chars()
+                .next()
+                .filter(|_| piece.len() == 1)
+                .and_then(TetrominoType::from_char)
+                .ok_or(format!("Invalid piece in puzzle: '{piece}'"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if pieces.is_empty() {
+        return Err("Puzzle has no pieces".to_string());
+    }
+    Ok(pieces)
+}
+
+/// Parses the bottom rows of the playfield and fills the rows above them with empty cells.
+fn decode_grid(rows: &[&str]) -> Result<PlayfieldGrid, String> {
+    if rows.len() > PLAYFIELD_HEIGHT as usize {
+        return Err(format!("Puzzle grid has more than {PLAYFIELD_HEIGHT} rows"));
+    }
+    if let Some(row) = rows
+        .iter()
+        .find(|row| row.chars().count() != PLAYFIELD_WIDTH as usize)
+    {
+        return Err(format!(
+            "Puzzle grid row doesn't have {PLAYFIELD_WIDTH} cells: '{row}'"
+        ));
+    }
+
+    let empty_row = EMPTY_CELL_CHAR.to_string().repeat(PLAYFIELD_WIDTH as usize);
+    let nr_empty_rows = PLAYFIELD_HEIGHT as usize - rows.len();
+    let mut all_rows = vec![empty_row.as_str(); nr_empty_rows];
+    all_rows.extend_from_slice(rows);
+    PlayfieldGrid::from_rows(&all_rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const TEST_PUZZLE: &str = "\
+# Two I pieces for a tetris
+title SPLIT TETRIS
+
+goal clear_lines 4
+pieces I I
+grid
+JJJJJJJJ..
+LLLLLLLL..
+SSSSSSSS..
+ZZZZZZZZ..
+";
+
+    #[test]
+    fn decode_reads_puzzle_and_puts_grid_at_bottom() {
+        // Act
+        let result = Puzzle::decode(TEST_PUZZLE);
+
+        // Assert
+        let puzzle = result.unwrap();
+        assert_eq!(puzzle.title, "SPLIT TETRIS");
+        assert_eq!(puzzle.goal, PuzzleGoal::ClearLines(4));
+        assert_eq!(puzzle.pieces, vec![TetrominoType::I, TetrominoType::I]);
+        let rows = puzzle.grid.to_rows();
+        assert_eq!(rows.len(), PLAYFIELD_HEIGHT as usize);
+        assert_eq!(rows[0], "..........");
+        assert_eq!(rows[PLAYFIELD_HEIGHT as usize - 1], "ZZZZZZZZ..");
+    }
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        // Arrange
+        let puzzle = Puzzle::decode(TEST_PUZZLE).unwrap();
+
+        // Act
+        let result = Puzzle::decode(&puzzle.encode());
+
+        // Assert
+        assert_eq!(result, Ok(puzzle));
+    }
+
+    #[rstest]
+    #[case("", "Puzzle is missing 'title'")]
+    #[case("title A\ngoal win", "Unknown puzzle goal: 'win'")]
+    #[case("title A\ngoal perfect_clear\npieces", "Puzzle has no pieces")]
+    #[case(
+        "title A\ngoal perfect_clear\npieces Q",
+        "Invalid piece in puzzle: 'Q'"
+    )]
+    #[case("title A\ngoal perfect_clear\npieces O", "Puzzle is missing 'grid'")]
+    #[case(
+        "title A\ngoal perfect_clear\npieces O\ngrid\n...",
+        "Puzzle grid row doesn't have 10 cells: '...'"
+    )]
+    #[case(
+        "title A\ngoal perfect_clear\npieces O\ngrid\n.........X",
+        "Invalid grid cell 'X' in row 19"
+    )]
+    fn decode_rejects_invalid_puzzles(#[case] text: &str, #[case] expected_error: &str) {
+        // Act
+        let result = Puzzle::decode(text);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
+}
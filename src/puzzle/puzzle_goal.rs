@@ -0,0 +1,129 @@
+use crate::events::Event;
+
+/// What has to be achieved with the pieces of a puzzle to solve it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PuzzleGoal {
+    ClearLines(u32),
+    PerfectClear,
+    TSpinDouble,
+}
+
+impl PuzzleGoal {
+    pub fn display_text(&self) -> String {
+        match self {
+            PuzzleGoal::ClearLines(1) => "CLEAR 1 LINE".to_string(),
+            PuzzleGoal::ClearLines(nr_lines) => format!("CLEAR {nr_lines} LINES"),
+            PuzzleGoal::PerfectClear => "PERFECT CLEAR".to_string(),
+            PuzzleGoal::TSpinDouble => "T-SPIN DOUBLE".to_string(),
+        }
+    }
+
+    /// Returns whether the goal is reached with the lines cleared so far or with one of the
+    /// events of the last update of the game.
+    pub fn is_reached(&self, total_lines_cleared: u32, events: &[Event]) -> bool {
+        match self {
+            PuzzleGoal::ClearLines(nr_lines) => total_lines_cleared >= *nr_lines,
+            PuzzleGoal::PerfectClear => events.contains(&Event::PerfectClear),
+            PuzzleGoal::TSpinDouble => events.contains(&Event::TSpin(2)),
+        }
+    }
+
+    /// Returns the goal as the values of a goal line in a puzzle file, e.g. "clear_lines 4".
+    pub fn encode(&self) -> String {
+        match self {
+            PuzzleGoal::ClearLines(nr_lines) => format!("clear_lines {nr_lines}"),
+            PuzzleGoal::PerfectClear => "perfect_clear".to_string(),
+            PuzzleGoal::TSpinDouble => "t_spin_double".to_string(),
+        }
+    }
+
+    pub fn decode(values: &[&str]) -> Result<Self, String> {
+        match values {
+            ["clear_lines", nr_lines] => nr_lines
+                .parse()
+                .map(PuzzleGoal::ClearLines)
+                .map_err(|_| format!("Invalid number of lines: '{nr_lines}'")),
+            ["perfect_clear"] => Ok(PuzzleGoal::PerfectClear),
+            ["t_spin_double"] => Ok(PuzzleGoal::TSpinDouble),
+            _ => Err(format!("Unknown puzzle goal: '{}'", values.join(" "))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(PuzzleGoal::ClearLines(1), "CLEAR 1 LINE")]
+    #[case(PuzzleGoal::ClearLines(4), "CLEAR 4 LINES")]
+    #[case(PuzzleGoal::PerfectClear, "PERFECT CLEAR")]
+    #[case(PuzzleGoal::TSpinDouble, "T-SPIN DOUBLE")]
+    fn display_text_describes_goal(#[case] sut: PuzzleGoal, #[case] expected: &str) {
+        // Act
+        let result = sut.display_text();
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(PuzzleGoal::ClearLines(4))]
+    #[case(PuzzleGoal::PerfectClear)]
+    #[case(PuzzleGoal::TSpinDouble)]
+    fn encode_and_decode_round_trip(#[case] sut: PuzzleGoal) {
+        // Act
+        let encoded = sut.encode();
+        let result = PuzzleGoal::decode(&encoded.split_whitespace().collect::<Vec<_>>());
+
+        // Assert
+        assert_eq!(result, Ok(sut));
+    }
+
+    #[rstest]
+    #[case(&["clear_lines", "four"], "Invalid number of lines: 'four'")]
+    #[case(&["clear_lines"], "Unknown puzzle goal: 'clear_lines'")]
+    #[case(&["win"], "Unknown puzzle goal: 'win'")]
+    fn decode_rejects_invalid_goals(#[case] values: &[&str], #[case] expected_error: &str) {
+        // Act
+        let result = PuzzleGoal::decode(values);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
+
+    #[rstest]
+    #[case(PuzzleGoal::ClearLines(4), 4, &[])]
+    #[case(PuzzleGoal::PerfectClear, 0, &[Event::PerfectClear])]
+    #[case(PuzzleGoal::TSpinDouble, 0, &[Event::LinesCleared(2), Event::TSpin(2)])]
+    fn is_reached_after_matching_lines_or_event(
+        #[case] sut: PuzzleGoal,
+        #[case] total_lines_cleared: u32,
+        #[case] events: &[Event],
+    ) {
+        // Act
+        let result = sut.is_reached(total_lines_cleared, events);
+
+        // Assert
+        assert!(result);
+    }
+
+    #[test]
+    fn t_spin_single_does_not_reach_t_spin_double() {
+        // Act
+        let result = PuzzleGoal::TSpinDouble.is_reached(1, &[Event::TSpin(1)]);
+
+        // Assert
+        assert!(!result);
+    }
+
+    #[test]
+    fn is_not_reached_without_lines_and_events() {
+        // Act
+        let result = PuzzleGoal::ClearLines(1).is_reached(0, &[]);
+
+        // Assert
+        assert!(!result);
+    }
+}
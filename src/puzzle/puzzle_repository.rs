@@ -0,0 +1,12 @@
+use crate::puzzle::Puzzle;
+
+pub trait PuzzleRepository {
+    /// Returns the names of all puzzles, sorted by name.
+    fn list(&self) -> Result<Vec<String>, String>;
+    fn load(&self, name: &str) -> Result<Puzzle, String>;
+    /// Saves the puzzle under the given name, replacing a puzzle with the same name.
+    fn save(&self, name: &str, puzzle: &Puzzle) -> Result<(), String>;
+    /// Returns the names of the puzzles that have been solved.
+    fn get_solved(&self) -> Result<Vec<String>, String>;
+    fn mark_solved(&self, name: &str) -> Result<(), String>;
+}
@@ -0,0 +1,151 @@
+use crate::common::DateTime;
+use crate::replay::{Replay, ReplayRepository};
+use std::fs;
+use std::path::PathBuf;
+
+const REPLAY_EXTENSION: &str = "replay";
+
+/// Stores every replay in its own file in a directory, named after the time it was saved.
+pub struct FileReplayRepository {
+    directory: PathBuf,
+}
+
+impl FileReplayRepository {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn get_file_path(&self, name: &str) -> PathBuf {
+        self.directory.join(format!("{name}.{REPLAY_EXTENSION}"))
+    }
+
+    fn create_unique_name(&self) -> String {
+        let timestamp = DateTime::now().to_file_name();
+        let mut name = timestamp.clone();
+        let mut suffix = 1;
+        while self.get_file_path(&name).exists() {
+            name = format!("{timestamp}-{suffix}");
+            suffix += 1;
+        }
+        name
+    }
+}
+
+impl ReplayRepository for FileReplayRepository {
+    fn save(&self, replay: &Replay) -> Result<String, String> {
+        fs::create_dir_all(&self.directory)
+            .map_err(|e| format!("Failed to create replays directory: {e}"))?;
+        let name = self.create_unique_name();
+        fs::write(self.get_file_path(&name), replay.encode())
+            .map_err(|e| format!("Failed to write replay: {e}"))?;
+        Ok(name)
+    }
+
+    fn list(&self) -> Result<Vec<String>, String> {
+        if !self.directory.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&self.directory)
+            .map_err(|e| format!("Failed to read replays directory: {e}"))?;
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == REPLAY_EXTENSION))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        names.sort_by(|a, b| b.cmp(a));
+
+        Ok(names)
+    }
+
+    fn load(&self, name: &str) -> Result<Replay, String> {
+        if !is_valid_name(name) {
+            return Err(format!("Invalid replay name '{name}'"));
+        }
+        let text = fs::read_to_string(self.get_file_path(name))
+            .map_err(|e| format!("Failed to read replay: {e}"))?;
+        Replay::decode(&text)
+    }
+}
+
+/// Names are read from files like the high scores, so they must not lead outside the directory.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(['/', '\\']) && !name.contains("..")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::GameRules;
+    use crate::gui::GameInput;
+    use rstest::rstest;
+
+    #[test]
+    fn save_writes_replay_file_that_can_be_loaded() {
+        // Arrange
+        let directory = std::env::temp_dir().join("samtris_test_save_replay");
+        let _ = fs::remove_dir_all(&directory);
+        let sut = FileReplayRepository::new(&directory);
+        let mut replay = Replay::new(42, GameRules::default(), 3);
+        replay.record_input(5, GameInput::Drop);
+
+        // Act
+        let first_result = sut.save(&replay);
+        let second_result = sut.save(&replay);
+
+        // Assert
+        let first_name = first_result.unwrap();
+        let second_name = second_result.unwrap();
+        assert_ne!(first_name, second_name);
+        assert_eq!(sut.list(), Ok(vec![second_name.clone(), first_name]));
+        assert_eq!(sut.load(&second_name), Ok(replay));
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("../secret")]
+    #[case("..")]
+    #[case("sub/replay")]
+    #[case("sub\\replay")]
+    #[case("/etc/passwd")]
+    fn load_rejects_names_outside_the_directory(#[case] name: &str) {
+        // Arrange
+        let sut = FileReplayRepository::new("samtris_test_nonexistent_replays");
+
+        // Act
+        let result = sut.load(name);
+
+        // Assert
+        assert_eq!(result, Err(format!("Invalid replay name '{name}'")));
+    }
+
+    #[test]
+    fn list_returns_empty_list_when_directory_does_not_exist() {
+        // Arrange
+        let sut = FileReplayRepository::new("samtris_test_nonexistent_replays");
+
+        // Act
+        let result = sut.list();
+
+        // Assert
+        assert_eq!(result, Ok(Vec::new()));
+    }
+
+    #[test]
+    fn load_returns_error_for_missing_replay() {
+        // Arrange
+        let sut = FileReplayRepository::new("samtris_test_nonexistent_replays");
+
+        // Act
+        let result = sut.load("missing");
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
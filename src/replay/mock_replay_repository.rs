@@ -0,0 +1,46 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::replay::{Replay, ReplayRepository};
+
+/// Keeps saved replays in memory, clone `saved_replays` before boxing to inspect them. Replays
+/// are named after their index.
+#[derive(Default)]
+pub struct MockReplayRepository {
+    pub saved_replays: Rc<RefCell<Vec<Replay>>>,
+}
+
+impl MockReplayRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_replays(replays: Vec<Replay>) -> Self {
+        Self {
+            saved_replays: Rc::new(RefCell::new(replays)),
+        }
+    }
+}
+
+impl ReplayRepository for MockReplayRepository {
+    fn save(&self, replay: &Replay) -> Result<String, String> {
+        let mut saved_replays = self.saved_replays.borrow_mut();
+        saved_replays.push(replay.clone());
+        Ok((saved_replays.len() - 1).to_string())
+    }
+
+    fn list(&self) -> Result<Vec<String>, String> {
+        let nr_replays = self.saved_replays.borrow().len();
+        Ok((0..nr_replays)
+            .rev()
+            .map(|index| index.to_string())
+            .collect())
+    }
+
+    fn load(&self, name: &str) -> Result<Replay, String> {
+        name.parse::<usize>()
+            .ok()
+            .and_then(|index| self.saved_replays.borrow().get(index).cloned())
+            .ok_or(format!("Replay '{name}' not found"))
+    }
+}
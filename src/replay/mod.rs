@@ -0,0 +1,15 @@
+mod file_replay_repository;
+#[cfg(test)]
+mod mock_replay_repository;
+mod replay_data;
+mod replay_player;
+mod replay_repository;
+mod replay_verifier;
+
+pub use file_replay_repository::FileReplayRepository;
+#[cfg(test)]
+pub use mock_replay_repository::MockReplayRepository;
+pub use replay_data::*;
+pub use replay_player::ReplayPlayer;
+pub use replay_repository::ReplayRepository;
+pub use replay_verifier::*;
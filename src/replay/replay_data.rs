@@ -0,0 +1,335 @@
+use crate::common::RngAlgorithm;
+use crate::game_logic::GameRules;
+use crate::gui::GameInput;
+use std::str::FromStr;
+use std::time::Duration;
+
+const REPLAY_HEADER: &str = "SAMTRIS-REPLAY";
+pub const REPLAY_FORMAT_VERSION: u32 = 2;
+/// Replays of this version have the same fields, but their tetrominoes were drawn with
+/// RngAlgorithm::Legacy.
+const LEGACY_RNG_REPLAY_FORMAT_VERSION: u32 = 1;
+
+/// Something the player did during a single game tick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayAction {
+    /// An input that was handled by the game, like a key press.
+    Input(GameInput),
+    /// The set of inputs held down changed, this drives auto-repeat and initial rotation.
+    HeldInputs(Vec<GameInput>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayEvent {
+    /// Number of game updates that happened before the action.
+    pub tick: u64,
+    pub action: ReplayAction,
+}
+
+/// Everything needed to play back a game: the seed and algorithm of the tetromino generator, the
+/// rules and start level, and every player action stamped with the tick it happened in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    pub seed: u64,
+    pub rng_algorithm: RngAlgorithm,
+    pub rules: GameRules,
+    pub start_level: u32,
+    pub events: Vec<ReplayEvent>,
+}
+
+impl Replay {
+    pub fn new(seed: u64, rules: GameRules, start_level: u32) -> Self {
+        Self {
+            seed,
+            rng_algorithm: RngAlgorithm::default(),
+            rules,
+            start_level,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn record_input(&mut self, tick: u64, input: GameInput) {
+        self.events.push(ReplayEvent {
+            tick,
+            action: ReplayAction::Input(input),
+        });
+    }
+
+    /// Records the held inputs, unless they're the same as the last recorded held inputs.
+    pub fn record_held_inputs(&mut self, tick: u64, held_inputs: &[GameInput]) {
+        let last_held_inputs = self
+            .events
+            .iter()
+            .rev()
+            .find_map(|event| match &event.action {
+                ReplayAction::HeldInputs(inputs) => Some(inputs.as_slice()),
+                ReplayAction::Input(_) => None,
+            })
+            .unwrap_or(&[]);
+        if last_held_inputs != held_inputs {
+            self.events.push(ReplayEvent {
+                tick,
+                action: ReplayAction::HeldInputs(held_inputs.to_vec()),
+            });
+        }
+    }
+
+    /// Returns the actions that happened in the given tick, in the order they happened. The
+    /// events are sorted by tick, so they are found with a binary search.
+    pub fn get_actions_at(&self, tick: u64) -> impl Iterator<Item = &ReplayAction> {
+        let start = self.events.partition_point(|event| event.tick < tick);
+        self.events[start..]
+            .iter()
+            .take_while(move |event| event.tick == tick)
+            .map(|event| &event.action)
+    }
+
+    /// Encodes the replay with the format version of its RNG algorithm, so a decoded old replay
+    /// is encoded the same way again.
+    pub fn encode(&self) -> String {
+        let version = match self.rng_algorithm {
+            RngAlgorithm::Legacy => LEGACY_RNG_REPLAY_FORMAT_VERSION,
+            RngAlgorithm::ChaCha8 => REPLAY_FORMAT_VERSION,
+        };
+        let mut lines = vec![
+            format!("{REPLAY_HEADER} {version}"),
+            format!("seed {}", self.seed),
+            format!("entry_delay_ms {}", self.rules.entry_delay.as_millis()),
+            format!(
+                "line_clear_delay_ms {}",
+                self.rules.line_clear_delay.as_millis()
+            ),
+            format!("start_level {}", self.start_level),
+        ];
+        lines.extend(self.events.iter().map(encode_event));
+
+        lines.join("\n") + "\n"
+    }
+
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+
+        let header = lines.next().ok_or("Replay is empty")?;
+        let version = header
+            .strip_prefix(REPLAY_HEADER)
+            .ok_or(format!("Not a replay: '{header}'"))?;
+        let rng_algorithm = match parse_number(version)? {
+            LEGACY_RNG_REPLAY_FORMAT_VERSION => RngAlgorithm::Legacy,
+            REPLAY_FORMAT_VERSION => RngAlgorithm::ChaCha8,
+            version => return Err(format!("Unsupported replay version {version}")),
+        };
+
+        let seed = parse_setting(lines.next(), "seed")?;
+        let entry_delay = Duration::from_millis(parse_setting(lines.next(), "entry_delay_ms")?);
+        let line_clear_delay =
+            Duration::from_millis(parse_setting(lines.next(), "line_clear_delay_ms")?);
+        let start_level = parse_setting(lines.next(), "start_level")?;
+
+        let events = lines.map(decode_event).collect::<Result<Vec<_>, _>>()?;
+        if events.windows(2).any(|pair| pair[1].tick < pair[0].tick) {
+            return Err("Replay events are not in tick order".to_string());
+        }
+
+        Ok(Self {
+            seed,
+            rng_algorithm,
+            rules: GameRules::new(entry_delay, line_clear_delay),
+            start_level,
+            events,
+        })
+    }
+}
+
+fn encode_event(event: &ReplayEvent) -> String {
+    match &event.action {
+        ReplayAction::Input(input) => format!("{} press {input}", event.tick),
+        ReplayAction::HeldInputs(inputs) => {
+            let inputs: Vec<String> = inputs.iter().map(GameInput::to_string).collect();
+            format!("{} hold {}", event.tick, inputs.join(","))
+                .trim_end()
+                .to_string()
+        }
+    }
+}
+
+fn decode_event(line: &str) -> Result<ReplayEvent, String> {
+    let mut parts = line.split_whitespace();
+    let tick = parse_number(parts.next().unwrap_or_default())?;
+    let action = match (parts.next(), parts.next()) {
+        (Some("press"), Some(input)) => ReplayAction::Input(parse_input(input)?),
+        (Some("hold"), inputs) => ReplayAction::HeldInputs(
+            inputs
+                .unwrap_or_default()
+                .split(',')
+                .filter(|input| !input.is_empty())
+                .map(parse_input)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        _ => return Err(format!("Invalid replay event: '{line}'")),
+    };
+
+    Ok(ReplayEvent { tick, action })
+}
+
+fn parse_setting<N: FromStr>(line: Option<&str>, name: &str) -> Result<N, String> {
+    let value = line
+        .and_then(|line| line.strip_prefix(name))
+        .ok_or(format!("Replay is missing '{name}'"))?;
+    parse_number(value)
+}
+
+fn parse_number<N: FromStr>(text: &str) -> Result<N, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("Invalid number in replay: '{}'", text.trim()))
+}
+
+fn parse_input(text: &str) -> Result<GameInput, String> {
+    GameInput::from_str(text).map_err(|_| format!("Unknown input in replay: '{text}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn create_test_replay() -> Replay {
+        let mut replay = Replay::new(
+            1234,
+            GameRules::new(Duration::from_millis(100), Duration::from_millis(200)),
+            5,
+        );
+        replay.record_held_inputs(3, &[GameInput::MoveLeft]);
+        replay.record_input(3, GameInput::MoveLeft);
+        replay.record_held_inputs(10, &[GameInput::MoveLeft, GameInput::RotateClockwise]);
+        replay.record_input(10, GameInput::RotateClockwise);
+        replay.record_held_inputs(12, &[]);
+        replay.record_input(20, GameInput::Drop);
+        replay
+    }
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        // Arrange
+        let replay = create_test_replay();
+
+        // Act
+        let result = Replay::decode(&replay.encode());
+
+        // Assert
+        assert_eq!(result, Ok(replay));
+    }
+
+    #[test]
+    fn decode_version_1_replay_draws_tetrominoes_with_legacy_algorithm() {
+        // Arrange
+        let text = "SAMTRIS-REPLAY 1\nseed 7\nentry_delay_ms 0\nline_clear_delay_ms 0\nstart_level 3\n4 press Drop\n";
+
+        // Act
+        let result = Replay::decode(text).unwrap();
+
+        // Assert
+        assert_eq!(result.rng_algorithm, RngAlgorithm::Legacy);
+        assert_eq!(result.seed, 7);
+        assert_eq!(result.start_level, 3);
+        assert_eq!(result.rules, GameRules::without_delays());
+        assert_eq!(result.encode(), text);
+    }
+
+    #[test]
+    fn encode_writes_version_header_and_events() {
+        // Arrange
+        let replay = create_test_replay();
+
+        // Act
+        let result = replay.encode();
+
+        // Assert
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "SAMTRIS-REPLAY 2");
+        assert_eq!(lines[1], "seed 1234");
+        assert_eq!(lines[5], "3 hold MoveLeft");
+        assert_eq!(lines[6], "3 press MoveLeft");
+        assert_eq!(lines[9], "12 hold");
+    }
+
+    #[rstest]
+    #[case("", "Replay is empty")]
+    #[case("SAMTRIS-REPLAY 3\nseed 1", "Unsupported replay version 3")]
+    #[case("name|100|1", "Not a replay: 'name|100|1'")]
+    #[case("SAMTRIS-REPLAY 2\nseed 1", "Replay is missing 'entry_delay_ms'")]
+    #[case(
+        "SAMTRIS-REPLAY 2\nseed 1\nentry_delay_ms 0\nline_clear_delay_ms 0\nstart_level 0\n5 press Drop\n3 press Drop",
+        "Replay events are not in tick order"
+    )]
+    fn decode_rejects_invalid_replays(#[case] text: &str, #[case] expected_error: &str) {
+        // Act
+        let result = Replay::decode(text);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
+
+    #[test]
+    fn decode_rejects_unknown_input() {
+        // Arrange
+        let text = Replay::new(1, GameRules::default(), 0).encode() + "5 press Teleport\n";
+
+        // Act
+        let result = Replay::decode(&text);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err("Unknown input in replay: 'Teleport'".to_string())
+        );
+    }
+
+    #[test]
+    fn record_held_inputs_skips_unchanged_inputs() {
+        // Arrange
+        let mut sut = Replay::new(1, GameRules::default(), 0);
+
+        // Act
+        sut.record_held_inputs(1, &[]);
+        sut.record_held_inputs(2, &[GameInput::MoveDown]);
+        sut.record_held_inputs(3, &[GameInput::MoveDown]);
+
+        // Assert
+        assert_eq!(sut.events.len(), 1);
+        assert_eq!(sut.events[0].tick, 2);
+    }
+
+    #[test]
+    fn get_actions_at_returns_actions_of_tick_in_order() {
+        // Arrange
+        let sut = create_test_replay();
+
+        // Act
+        let result: Vec<&ReplayAction> = sut.get_actions_at(10).collect();
+
+        // Assert
+        assert_eq!(
+            result,
+            vec![
+                &ReplayAction::HeldInputs(vec![GameInput::MoveLeft, GameInput::RotateClockwise]),
+                &ReplayAction::Input(GameInput::RotateClockwise)
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(11)]
+    #[case(21)]
+    fn get_actions_at_tick_without_actions_returns_nothing(#[case] tick: u64) {
+        // Arrange
+        let sut = create_test_replay();
+
+        // Act
+        let result = sut.get_actions_at(tick).count();
+
+        // Assert
+        assert_eq!(result, 0);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::common::Dimensions;
+use crate::constants::*;
+use crate::events::EventQueue;
+use crate::game_logic::{Game, GameState, Playfield};
+use crate::graphics::{BoardLayout, Display, GraphicsHudRenderer, GraphicsPlayfieldRenderer};
+use crate::high_scores::{HighScoreManager, NullHighScoresRepository};
+use crate::replay::{Replay, ReplayAction};
+use crate::tetromino::RandomTetrominoGenerator;
+use std::sync::Arc;
+use std::time::Duration;
+
+type ReplayGame = Game<GraphicsPlayfieldRenderer, GraphicsHudRenderer, RandomTetrominoGenerator>;
+
+/// Plays back a replay one game tick at a time by feeding its actions into a fresh game with the
+/// same seed, rules and start level.
+pub struct ReplayPlayer {
+    replay: Replay,
+    game: ReplayGame,
+    tick: u64,
+}
+
+impl ReplayPlayer {
+    pub fn new(replay: Replay) -> Self {
+        let game = create_game(&replay);
+        Self {
+            replay,
+            game,
+            tick: 0,
+        }
+    }
+
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn get_game(&self) -> &ReplayGame {
+        &self.game
+    }
+
+    pub fn is_finished(&self) -> bool {
+        *self.game.get_game_state() != GameState::Playing
+    }
+
+    /// Applies the actions of the current tick and advances the game by one tick.
+    pub fn step(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+
+        for action in self.replay.get_actions_at(self.tick) {
+            match action {
+                ReplayAction::Input(input) => {
+                    self.game.handle_input(*input);
+                }
+                ReplayAction::HeldInputs(held_inputs) => self.game.set_held_inputs(held_inputs),
+            }
+        }
+        self.game.update(Duration::from_millis(GAME_TICK_MS));
+        self.tick += 1;
+    }
+
+    /// Moves to the given tick, or to the end of the game if it ends earlier. Games can't be run
+    /// backwards, so seeking back re-simulates from the start.
+    pub fn seek(&mut self, tick: u64) {
+        if tick < self.tick {
+            self.game = create_game(&self.replay);
+            self.tick = 0;
+        }
+        while self.tick < tick && !self.is_finished() {
+            self.step();
+        }
+    }
+
+    /// Plays the rest of the replay without drawing.
+    pub fn run_to_end(&mut self) {
+        while !self.is_finished() {
+            self.step();
+        }
+    }
+
+    pub fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        self.game.draw_board(display)
+    }
+}
+
+fn create_game(replay: &Replay) -> ReplayGame {
+    let event_queue = Arc::new(EventQueue::new());
+    let playfield = Playfield::new(
+        Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+        RandomTetrominoGenerator::with_rng_algorithm(replay.seed, replay.rng_algorithm),
+        event_queue.clone(),
+    );
+    let layout = BoardLayout {
+        show_statistics: false, // The status lines are drawn where the panel would be
+        ..BoardLayout::single_player()
+    };
+    let mut game = Game::new(
+        playfield,
+        GraphicsPlayfieldRenderer::with_layout(layout),
+        GraphicsHudRenderer::with_layout(layout),
+        event_queue,
+        HighScoreManager::new(Box::new(NullHighScoresRepository)),
+    );
+    game.set_rules(replay.rules);
+    game.start_level(replay.start_level);
+    game
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::RngAlgorithm;
+    use crate::game_logic::GameRules;
+    use crate::gui::GameInput;
+
+    #[test]
+    fn playing_recorded_game_gives_same_result() {
+        // Arrange
+        let (replay, expected_hud_view) = record_game_with_drops_every(30, RngAlgorithm::ChaCha8);
+        let mut sut = ReplayPlayer::new(replay);
+
+        // Act
+        sut.run_to_end();
+
+        // Assert
+        assert_eq!(sut.get_game().get_hud_view(), expected_hud_view);
+    }
+
+    #[test]
+    fn playing_decoded_version_1_replay_gives_same_result() {
+        // Arrange
+        let (replay, expected_hud_view) = record_game_with_drops_every(30, RngAlgorithm::Legacy);
+        let replay = Replay::decode(&replay.encode()).unwrap();
+        let mut sut = ReplayPlayer::new(replay);
+
+        // Act
+        sut.run_to_end();
+
+        // Assert
+        assert_eq!(sut.get_game().get_hud_view(), expected_hud_view);
+    }
+
+    #[test]
+    fn seek_back_gives_same_board_as_playing_to_tick() {
+        // Arrange
+        let (replay, _) = record_game_with_drops_every(20, RngAlgorithm::ChaCha8);
+        let mut expected = ReplayPlayer::new(replay.clone());
+        expected.seek(100);
+        let mut sut = ReplayPlayer::new(replay);
+        sut.seek(300);
+
+        // Act
+        sut.seek(100);
+
+        // Assert
+        assert_eq!(sut.get_tick(), 100);
+        assert_eq!(
+            sut.get_game().get_playfield_view().grid,
+            expected.get_game().get_playfield_view().grid
+        );
+    }
+
+    #[test]
+    fn seek_past_end_stops_at_game_over() {
+        // Arrange
+        let (replay, _) = record_game_with_drops_every(10, RngAlgorithm::ChaCha8);
+        let mut sut = ReplayPlayer::new(replay);
+
+        // Act
+        sut.seek(u64::MAX);
+
+        // Assert
+        assert!(sut.is_finished());
+        assert!(sut.get_tick() < u64::MAX);
+    }
+
+    /// Records a game in which the player moves left and drops a tetromino every given number of
+    /// ticks until it tops out, with tetrominoes drawn with the given algorithm.
+    fn record_game_with_drops_every(
+        interval: u64,
+        rng_algorithm: RngAlgorithm,
+    ) -> (Replay, crate::graphics::HudView) {
+        let mut replay = Replay::new(99, GameRules::default(), 2);
+        replay.rng_algorithm = rng_algorithm;
+        let mut game = create_game(&replay);
+        let mut tick = 0;
+        while *game.get_game_state() == GameState::Playing {
+            if tick % interval == 0 {
+                replay.record_held_inputs(tick, &[GameInput::MoveLeft]);
+                game.set_held_inputs(&[GameInput::MoveLeft]);
+                replay.record_input(tick, GameInput::MoveLeft);
+                game.handle_input(GameInput::MoveLeft);
+                replay.record_held_inputs(tick, &[]);
+                game.set_held_inputs(&[]);
+                replay.record_input(tick, GameInput::Drop);
+                game.handle_input(GameInput::Drop);
+            }
+            game.update(Duration::from_millis(GAME_TICK_MS));
+            tick += 1;
+        }
+
+        (replay, game.get_hud_view())
+    }
+}
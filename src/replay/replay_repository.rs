@@ -0,0 +1,9 @@
+use crate::replay::Replay;
+
+pub trait ReplayRepository {
+    /// Saves the replay and returns the name it can be loaded with.
+    fn save(&self, replay: &Replay) -> Result<String, String>;
+    /// Returns the names of all saved replays, newest first.
+    fn list(&self) -> Result<Vec<String>, String>;
+    fn load(&self, name: &str) -> Result<Replay, String>;
+}
@@ -0,0 +1,121 @@
+use crate::game_logic::GameResult;
+use crate::high_scores::HighScore;
+use crate::replay::{Replay, ReplayPlayer, ReplayRepository};
+
+/// Re-simulates the replay without drawing and checks it ends with the given score and level.
+pub fn verify_replay(replay: &Replay, game_result: &GameResult) -> Result<(), String> {
+    let mut player = ReplayPlayer::new(replay.clone());
+    player.run_to_end();
+
+    let hud_view = player.get_game().get_hud_view();
+    if hud_view.score != game_result.score || hud_view.current_level != game_result.level {
+        return Err(format!(
+            "Replay ends with score {} at level {}, not score {} at level {}",
+            hud_view.score, hud_view.current_level, game_result.score, game_result.level
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the high score has a replay that reproduces its score and level.
+pub fn verify_high_score(
+    high_score: &HighScore,
+    replay_repository: &dyn ReplayRepository,
+) -> Result<(), String> {
+    let replay_name = high_score
+        .replay_name
+        .as_ref()
+        .ok_or("High score has no replay")?;
+    let replay = replay_repository.load(replay_name)?;
+    verify_replay(&replay, &high_score.game_result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::GameRules;
+    use crate::gui::GameInput;
+    use crate::replay::MockReplayRepository;
+
+    #[test]
+    fn verify_replay_accepts_matching_result() {
+        // Arrange
+        let replay = create_test_replay();
+        let game_result = play_to_end(&replay);
+
+        // Act
+        let result = verify_replay(&replay, &game_result);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn verify_replay_rejects_impossible_score() {
+        // Arrange
+        let replay = create_test_replay();
+        let mut game_result = play_to_end(&replay);
+        game_result.score += 1000;
+
+        // Act
+        let result = verify_replay(&replay, &game_result);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn verify_high_score_loads_linked_replay() {
+        // Arrange
+        let replay = create_test_replay();
+        let game_result = play_to_end(&replay);
+        let replay_repository = MockReplayRepository::with_replays(vec![replay]);
+        let high_score = HighScore::with_replay("SAM".to_string(), game_result, "0".to_string());
+
+        // Act
+        let result = verify_high_score(&high_score, &replay_repository);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn verify_high_score_rejects_score_without_replay() {
+        // Arrange
+        let replay_repository = MockReplayRepository::new();
+        let high_score = HighScore::new(
+            "SAM".to_string(),
+            GameResult {
+                score: 1000,
+                level: 0,
+                ..Default::default()
+            },
+        );
+
+        // Act
+        let result = verify_high_score(&high_score, &replay_repository);
+
+        // Assert
+        assert_eq!(result, Err("High score has no replay".to_string()));
+    }
+
+    fn create_test_replay() -> Replay {
+        let mut replay = Replay::new(3, GameRules::default(), 0);
+        for tick in (0..2000).step_by(25) {
+            replay.record_input(tick, GameInput::Drop);
+        }
+        replay
+    }
+
+    fn play_to_end(replay: &Replay) -> GameResult {
+        let mut player = ReplayPlayer::new(replay.clone());
+        player.run_to_end();
+        let hud_view = player.get_game().get_hud_view();
+        GameResult {
+            score: hud_view.score,
+            level: hud_view.current_level,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::save_game::{SavedGame, SavedGameRepository};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct FileSavedGameRepository {
+    file_path: PathBuf,
+}
+
+impl FileSavedGameRepository {
+    pub fn new(file_path: impl Into<PathBuf>) -> Self {
+        Self {
+            file_path: file_path.into(),
+        }
+    }
+}
+
+impl SavedGameRepository for FileSavedGameRepository {
+    fn save(&self, saved_game: &SavedGame) -> Result<(), String> {
+        fs::write(&self.file_path, saved_game.encode())
+            .map_err(|e| format!("Failed to write saved game: {e}"))
+    }
+
+    fn load(&self) -> Result<Option<SavedGame>, String> {
+        if !Path::new(&self.file_path).exists() {
+            return Ok(None);
+        }
+
+        let text = fs::read_to_string(&self.file_path)
+            .map_err(|e| format!("Failed to read saved game: {e}"))?;
+        SavedGame::decode(&text).map(Some)
+    }
+
+    fn delete(&self) -> Result<(), String> {
+        if !Path::new(&self.file_path).exists() {
+            return Ok(());
+        }
+
+        fs::remove_file(&self.file_path).map_err(|e| format!("Failed to delete saved game: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Dimensions;
+    use crate::game_logic::{
+        GameRules, GameSnapshot, GameStatistics, PlayfieldGrid, PlayfieldSnapshot, PlayfieldState,
+    };
+    use crate::tetromino::RandomGeneratorState;
+    use std::time::Duration;
+
+    #[test]
+    fn save_load_and_delete_saved_game() {
+        // Arrange
+        let test_file = std::env::temp_dir().join("samtris_test_saved_game.dat");
+        let sut = FileSavedGameRepository::new(&test_file);
+        let saved_game = SavedGame {
+            generator_state: RandomGeneratorState {
+                seed: 1,
+                nr_draws: 2,
+            },
+            game: GameSnapshot {
+                playfield: PlayfieldSnapshot {
+                    grid: PlayfieldGrid::new(Dimensions::new(4, 3)),
+                    tetromino: None,
+                    state: PlayfieldState::GameOver,
+                    rules: GameRules::default(),
+                    gravity_level: 0,
+                    time_since_last_drop: Duration::ZERO,
+                    nr_locked_tetrominoes: 0,
+                    spawned_tetromino: None,
+                    nr_finesse_inputs: 0,
+                    hold_tetromino_type: None,
+                    can_hold: true,
+                },
+                level: 0,
+                total_lines_cleared: 0,
+                score: 0,
+                tick: 0,
+                statistics: GameStatistics::new(),
+                start_level: 0,
+            },
+            replay: None,
+        };
+
+        // Act
+        let save_result = sut.save(&saved_game);
+        let load_result = sut.load();
+        let delete_result = sut.delete();
+
+        // Assert
+        assert!(save_result.is_ok());
+        assert_eq!(load_result, Ok(Some(saved_game)));
+        assert!(delete_result.is_ok());
+        assert_eq!(sut.load(), Ok(None));
+    }
+}
@@ -0,0 +1,32 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::save_game::{SavedGame, SavedGameRepository};
+
+/// Keeps the saved game in memory, clone `saved_game` before boxing to inspect it.
+#[derive(Default)]
+pub struct MockSavedGameRepository {
+    pub saved_game: Rc<RefCell<Option<SavedGame>>>,
+}
+
+impl MockSavedGameRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SavedGameRepository for MockSavedGameRepository {
+    fn save(&self, saved_game: &SavedGame) -> Result<(), String> {
+        *self.saved_game.borrow_mut() = Some(saved_game.clone());
+        Ok(())
+    }
+
+    fn load(&self) -> Result<Option<SavedGame>, String> {
+        Ok(self.saved_game.borrow().clone())
+    }
+
+    fn delete(&self) -> Result<(), String> {
+        *self.saved_game.borrow_mut() = None;
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+mod file_saved_game_repository;
+#[cfg(test)]
+mod mock_saved_game_repository;
+mod saved_game;
+mod saved_game_repository;
+
+pub use file_saved_game_repository::FileSavedGameRepository;
+#[cfg(test)]
+pub use mock_saved_game_repository::MockSavedGameRepository;
+pub use saved_game::SavedGame;
+pub use saved_game_repository::SavedGameRepository;
@@ -0,0 +1,433 @@
+use crate::common::Position;
+use crate::game_logic::{
+    GameRules, GameSnapshot, GameStatistics, PlayfieldGrid, PlayfieldSnapshot, PlayfieldState,
+};
+use crate::replay::Replay;
+use crate::tetromino::{
+    RandomGeneratorState, TetrominoDefinitions, TetrominoInstance, TetrominoType,
+};
+use std::str::FromStr;
+use std::time::Duration;
+
+const SAVED_GAME_HEADER: &str = "SAMTRIS-SAVE";
+pub const SAVED_GAME_FORMAT_VERSION: u32 = 7;
+const REPLAY_SECTION: &str = "replay";
+
+/// A single player game that was quit, with everything needed to continue it exactly where it
+/// was.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedGame {
+    pub generator_state: RandomGeneratorState,
+    pub game: GameSnapshot,
+    /// The replay recorded so far, so a resumed game still ends with a complete replay.
+    pub replay: Option<Replay>,
+}
+
+impl SavedGame {
+    pub fn encode(&self) -> String {
+        let game = &self.game;
+        let playfield = &game.playfield;
+        let dimensions = playfield.grid.get_dimensions();
+        let mut lines = vec![
+            format!("{SAVED_GAME_HEADER} {SAVED_GAME_FORMAT_VERSION}"),
+            format!(
+                "generator {} {}",
+                self.generator_state.seed, self.generator_state.nr_draws
+            ),
+            format!("level {}", game.level),
+            format!("start_level {}", game.start_level),
+            format!("lines {}", game.total_lines_cleared),
+            format!("score {}", game.score),
+            format!("tick {}", game.tick),
+            format!("locked {}", playfield.nr_locked_tetrominoes),
+            encode_statistics(&game.statistics),
+            format!("entry_delay_ns {}", playfield.rules.entry_delay.as_nanos()),
+            format!(
+                "line_clear_delay_ns {}",
+                playfield.rules.line_clear_delay.as_nanos()
+            ),
+            format!(
+                "gravity {} {}",
+                playfield.gravity_level,
+                playfield.time_since_last_drop.as_nanos()
+            ),
+            encode_playfield_state(&playfield.state),
+            encode_tetromino("tetromino", playfield.tetromino.as_ref()),
+            encode_tetromino("spawned_tetromino", playfield.spawned_tetromino.as_ref()),
+            format!("finesse_inputs {}", playfield.nr_finesse_inputs),
+            format!(
+                "hold {} {}",
+                playfield
+                    .hold_tetromino_type
+                    .map_or("none".to_string(), |t| t.to_char().to_string()),
+                u8::from(playfield.can_hold)
+            ),
+            format!("grid {} {}", dimensions.width, dimensions.height),
+        ];
+        lines.extend(playfield.grid.to_rows());
+        if let Some(replay) = &self.replay {
+            lines.push(REPLAY_SECTION.to_string());
+            lines.push(replay.encode());
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+
+        let header = lines.next().ok_or("Saved game is empty")?;
+        let version = header
+            .strip_prefix(SAVED_GAME_HEADER)
+            .ok_or(format!("Not a saved game: '{header}'"))?;
+        let version: u32 = parse_number(version)?;
+        if version != SAVED_GAME_FORMAT_VERSION {
+            return Err(format!("Unsupported saved game version {version}"));
+        }
+
+        let generator = read_values(&mut lines, "generator")?;
+        let generator_state = RandomGeneratorState {
+            seed: parse_value(&generator, 0)?,
+            nr_draws: parse_value(&generator, 1)?,
+        };
+        let level = parse_value(&read_values(&mut lines, "level")?, 0)?;
+        let start_level = parse_value(&read_values(&mut lines, "start_level")?, 0)?;
+        let total_lines_cleared = parse_value(&read_values(&mut lines, "lines")?, 0)?;
+        let score = parse_value(&read_values(&mut lines, "score")?, 0)?;
+        let tick = parse_value(&read_values(&mut lines, "tick")?, 0)?;
+        let nr_locked_tetrominoes = parse_value(&read_values(&mut lines, "locked")?, 0)?;
+        let statistics = decode_statistics(&read_values(&mut lines, "statistics")?)?;
+        let entry_delay = parse_duration(&read_values(&mut lines, "entry_delay_ns")?, 0)?;
+        let line_clear_delay = parse_duration(&read_values(&mut lines, "line_clear_delay_ns")?, 0)?;
+        let gravity = read_values(&mut lines, "gravity")?;
+        let state = decode_playfield_state(&read_values(&mut lines, "state")?)?;
+        let tetromino = decode_tetromino(&read_values(&mut lines, "tetromino")?)?;
+        let spawned_tetromino = decode_tetromino(&read_values(&mut lines, "spawned_tetromino")?)?;
+        let nr_finesse_inputs = parse_value(&read_values(&mut lines, "finesse_inputs")?, 0)?;
+        let hold = read_values(&mut lines, "hold")?;
+        let hold_tetromino_type = decode_tetromino_type(&hold)?;
+        let can_hold = parse_value::<u8>(&hold, 1)? == 1;
+        let grid = decode_grid(&mut lines)?;
+
+        let replay = match lines.next() {
+            Some(REPLAY_SECTION) => Some(Replay::decode(&lines.collect::<Vec<_>>().join("\n"))?),
+            Some(line) => return Err(format!("Unexpected line in saved game: '{line}'")),
+            None => None,
+        };
+
+        Ok(Self {
+            generator_state,
+            game: GameSnapshot {
+                playfield: PlayfieldSnapshot {
+                    grid,
+                    tetromino,
+                    state,
+                    rules: GameRules::new(entry_delay, line_clear_delay),
+                    gravity_level: parse_value(&gravity, 0)?,
+                    time_since_last_drop: parse_duration(&gravity, 1)?,
+                    nr_locked_tetrominoes,
+                    spawned_tetromino,
+                    nr_finesse_inputs,
+                    hold_tetromino_type,
+                    can_hold,
+                },
+                level,
+                total_lines_cleared,
+                score,
+                tick,
+                statistics,
+                start_level,
+            },
+            replay,
+        })
+    }
+}
+
+fn encode_playfield_state(state: &PlayfieldState) -> String {
+    match state {
+        PlayfieldState::Playing => "state playing".to_string(),
+        PlayfieldState::EntryDelay { countdown } => {
+            format!("state entry_delay {}", countdown.as_nanos())
+        }
+        PlayfieldState::AnimatingLines {
+            countdown,
+            full_lines,
+        } => {
+            let full_lines: Vec<String> = full_lines.iter().map(u32::to_string).collect();
+            format!(
+                "state animating_lines {} {}",
+                countdown.as_nanos(),
+                full_lines.join(",")
+            )
+        }
+        PlayfieldState::GameOver => "state game_over".to_string(),
+    }
+}
+
+fn decode_playfield_state(values: &[&str]) -> Result<PlayfieldState, String> {
+    match values.first() {
+        Some(&"playing") => Ok(PlayfieldState::Playing),
+        Some(&"entry_delay") => Ok(PlayfieldState::EntryDelay {
+            countdown: parse_duration(values, 1)?,
+        }),
+        Some(&"animating_lines") => Ok(PlayfieldState::AnimatingLines {
+            countdown: parse_duration(values, 1)?,
+            full_lines: values
+                .get(2)
+                .ok_or("Saved game is missing the full lines")?
+                .split(',')
+                .map(parse_number)
+                .collect::<Result<Vec<_>, _>>()?,
+        }),
+        Some(&"game_over") => Ok(PlayfieldState::GameOver),
+        _ => Err(format!("Invalid playfield state: '{}'", values.join(" "))),
+    }
+}
+
+/// Encodes the play time, pieces and inputs, followed by the counts of the four kinds of line
+/// clears and of the spawned tetromino types, the combo and tetris streak counters and the finesse
+/// faults.
+fn encode_statistics(statistics: &GameStatistics) -> String {
+    let counts: Vec<String> = statistics
+        .nr_line_clears
+        .iter()
+        .chain(statistics.nr_spawned.iter())
+        .chain(get_streak_counters(statistics))
+        .chain([&statistics.nr_finesse_faults])
+        .map(u32::to_string)
+        .collect();
+    format!(
+        "statistics {} {} {} {}",
+        statistics.play_time.as_nanos(),
+        statistics.nr_pieces,
+        statistics.nr_inputs,
+        counts.join(" ")
+    )
+}
+
+fn decode_statistics(values: &[&str]) -> Result<GameStatistics, String> {
+    let mut statistics = GameStatistics {
+        play_time: parse_duration(values, 0)?,
+        nr_pieces: parse_value(values, 1)?,
+        nr_inputs: parse_value(values, 2)?,
+        ..GameStatistics::default()
+    };
+    let counts = statistics
+        .nr_line_clears
+        .iter_mut()
+        .chain(statistics.nr_spawned.iter_mut())
+        .chain([
+            &mut statistics.combo,
+            &mut statistics.max_combo,
+            &mut statistics.last_clearing_piece,
+            &mut statistics.tetris_streak,
+            &mut statistics.max_tetris_streak,
+            &mut statistics.nr_finesse_faults,
+        ]);
+    for (i, count) in counts.enumerate() {
+        *count = parse_value(values, i + 3)?;
+    }
+    Ok(statistics)
+}
+
+fn get_streak_counters(statistics: &GameStatistics) -> [&u32; 5] {
+    [
+        &statistics.combo,
+        &statistics.max_combo,
+        &statistics.last_clearing_piece,
+        &statistics.tetris_streak,
+        &statistics.max_tetris_streak,
+    ]
+}
+
+fn encode_tetromino(key: &str, tetromino: Option<&TetrominoInstance>) -> String {
+    match tetromino {
+        Some(tetromino) => {
+            let position = tetromino.get_position();
+            format!(
+                "{key} {} {} {} {}",
+                tetromino.get_type().to_char(),
+                position.x,
+                position.y,
+                usize::from(tetromino.get_rotation_index())
+            )
+        }
+        None => format!("{key} none"),
+    }
+}
+
+fn decode_tetromino(values: &[&str]) -> Result<Option<TetrominoInstance>, String> {
+    let Some(tetromino_type) = decode_tetromino_type(values)? else {
+        return Ok(None);
+    };
+    let position = Position::new(parse_value(values, 1)?, parse_value(values, 2)?);
+    let rotation: usize = parse_value(values, 3)?;
+
+    let mut tetromino =
+        TetrominoInstance::new(tetromino_type, position, &TetrominoDefinitions::new());
+    for _ in 0..rotation {
+        tetromino.rotate_clockwise();
+    }
+    Ok(Some(tetromino))
+}
+
+/// Decodes the tetromino type of the first value, or none.
+fn decode_tetromino_type(values: &[&str]) -> Result<Option<TetrominoType>, String> {
+    if values.first() == Some(&"none") {
+        return Ok(None);
+    }
+
+    values
+        .first()
+        .and_then(|value| value.chars().next())
+        .and_then(TetrominoType::from_char)
+        .map(Some)
+        .ok_or(format!("Invalid tetromino: '{}'", values.join(" ")))
+}
+
+fn decode_grid<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Result<PlayfieldGrid, String> {
+    let size = read_values(lines, "grid")?;
+    let height: usize = parse_value(&size, 1)?;
+    let rows: Vec<&str> = lines.take(height).collect();
+    if rows.len() != height {
+        return Err("Saved game grid is incomplete".to_string());
+    }
+    PlayfieldGrid::from_rows(&rows)
+}
+
+/// Reads the next line, which must start with the given key, and returns the values after it.
+fn read_values<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    key: &str,
+) -> Result<Vec<&'a str>, String> {
+    let line = lines.next().unwrap_or_default();
+    let mut parts = line.split_whitespace();
+    if parts.next() != Some(key) {
+        return Err(format!("Saved game is missing '{key}'"));
+    }
+    Ok(parts.collect())
+}
+
+fn parse_value<N: FromStr>(values: &[&str], index: usize) -> Result<N, String> {
+    parse_number(values.get(index).copied().unwrap_or_default())
+}
+
+fn parse_duration(values: &[&str], index: usize) -> Result<Duration, String> {
+    parse_value(values, index).map(Duration::from_nanos)
+}
+
+fn parse_number<N: FromStr>(text: &str) -> Result<N, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("Invalid number in saved game: '{}'", text.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::GameInput;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(PlayfieldState::Playing)]
+    #[case(PlayfieldState::EntryDelay { countdown: Duration::from_millis(150) })]
+    #[case(PlayfieldState::AnimatingLines { countdown: Duration::from_nanos(123_456_789), full_lines: vec![18, 21] })]
+    #[case(PlayfieldState::GameOver)]
+    fn encode_and_decode_round_trip(#[case] state: PlayfieldState) {
+        // Arrange
+        let mut saved_game = create_test_saved_game();
+        saved_game.game.playfield.state = state;
+
+        // Act
+        let result = SavedGame::decode(&saved_game.encode());
+
+        // Assert
+        assert_eq!(result, Ok(saved_game));
+    }
+
+    #[test]
+    fn encode_and_decode_round_trip_without_tetrominoes_and_replay() {
+        // Arrange
+        let mut saved_game = create_test_saved_game();
+        saved_game.game.playfield.tetromino = None;
+        saved_game.game.playfield.spawned_tetromino = None;
+        saved_game.replay = None;
+
+        // Act
+        let result = SavedGame::decode(&saved_game.encode());
+
+        // Assert
+        assert_eq!(result, Ok(saved_game));
+    }
+
+    #[rstest]
+    #[case("", "Saved game is empty")]
+    #[case("SAMTRIS-SAVE 6", "Unsupported saved game version 6")]
+    #[case("SAMTRIS-REPLAY 1", "Not a saved game: 'SAMTRIS-REPLAY 1'")]
+    #[case("SAMTRIS-SAVE 7\nlevel 3", "Saved game is missing 'generator'")]
+    fn decode_rejects_invalid_saved_games(#[case] text: &str, #[case] expected_error: &str) {
+        // Act
+        let result = SavedGame::decode(text);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
+
+    fn create_test_saved_game() -> SavedGame {
+        let grid = PlayfieldGrid::from_rows(&["....", "..T.", "IIIT"]).unwrap();
+        let mut tetromino = TetrominoInstance::new(
+            TetrominoType::J,
+            Position::new(1, -1),
+            &TetrominoDefinitions::new(),
+        );
+        tetromino.rotate_counterclockwise();
+        let spawned_tetromino = TetrominoInstance::new(
+            TetrominoType::J,
+            Position::new(1, -2),
+            &TetrominoDefinitions::new(),
+        );
+        let mut replay = Replay::new(77, GameRules::without_delays(), 4);
+        replay.record_input(12, GameInput::RotateClockwise);
+
+        SavedGame {
+            generator_state: RandomGeneratorState {
+                seed: u64::MAX,
+                nr_draws: 31,
+            },
+            game: GameSnapshot {
+                playfield: PlayfieldSnapshot {
+                    grid,
+                    tetromino: Some(tetromino),
+                    state: PlayfieldState::Playing,
+                    rules: GameRules::new(Duration::from_millis(100), Duration::from_millis(300)),
+                    gravity_level: 4,
+                    time_since_last_drop: Duration::from_millis(240),
+                    nr_locked_tetrominoes: 30,
+                    spawned_tetromino: Some(spawned_tetromino),
+                    nr_finesse_inputs: 3,
+                    hold_tetromino_type: Some(TetrominoType::Z),
+                    can_hold: false,
+                },
+                level: 4,
+                total_lines_cleared: 42,
+                score: 12345,
+                tick: 5000,
+                statistics: GameStatistics {
+                    play_time: Duration::from_millis(81_234),
+                    nr_pieces: 30,
+                    nr_inputs: 97,
+                    nr_line_clears: [4, 3, 2, 7],
+                    nr_spawned: [5, 4, 6, 3, 4, 5, 4],
+                    combo: 2,
+                    max_combo: 5,
+                    last_clearing_piece: 29,
+                    tetris_streak: 1,
+                    max_tetris_streak: 3,
+                    nr_finesse_faults: 6,
+                },
+                start_level: 4,
+            },
+            replay: Some(replay),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::save_game::SavedGame;
+
+/// Keeps the single game that was quit to be continued later.
+pub trait SavedGameRepository {
+    fn save(&self, saved_game: &SavedGame) -> Result<(), String>;
+    /// Returns the saved game, or None when there is none.
+    fn load(&self) -> Result<Option<SavedGame>, String>;
+    fn delete(&self) -> Result<(), String>;
+}
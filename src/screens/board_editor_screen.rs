@@ -0,0 +1,459 @@
+use crate::common::{Dimensions, Position};
+use crate::constants::*;
+use crate::game_logic::PlayfieldGrid;
+use crate::graphics::{
+    Color, Display, GraphicsPlayfieldRenderer, PlayfieldRenderer, PlayfieldView,
+};
+use crate::input::{InputEvent, Key};
+use crate::puzzle::{Puzzle, PuzzleGoal, PuzzleRepository};
+use crate::screens::{PuzzleScreen, Screen, ScreenResult};
+use crate::tetromino::TetrominoType;
+use std::time::Duration;
+
+const EDITED_PUZZLE_TITLE: &str = "EDITED BOARD";
+const QUEUE_PIECES_PER_LINE: usize = 8;
+const CURSOR_LINE_WIDTH: u32 = 2;
+
+const HELP_LINES: [&str; 12] = [
+    "ARROWS MOVE",
+    "SPACE PAINT",
+    "BACK ERASE",
+    "IOTSZJL COLOR",
+    "P ADD PIECE",
+    "X DEL PIECE",
+    "G GOAL",
+    "C CLEAR",
+    "W SAVE",
+    "R LOAD",
+    "ENTER PLAY",
+    "ESC MENU",
+];
+
+/// Edits a board and its piece queue by painting cells under a cursor. The board can be saved
+/// to and loaded from the puzzle repository, and played right away as a puzzle.
+pub struct BoardEditorScreen {
+    puzzle_repository: Box<dyn PuzzleRepository>,
+    renderer: GraphicsPlayfieldRenderer,
+    grid: PlayfieldGrid,
+    cursor: Position,
+    paint_type: TetrominoType,
+    pieces: Vec<TetrominoType>,
+    goal: PuzzleGoal,
+    title: String,
+    message: String,
+    /// The edited board while it is being played, editing continues when it ends.
+    puzzle_screen: Option<PuzzleScreen>,
+}
+
+impl BoardEditorScreen {
+    pub fn new(puzzle_repository: Box<dyn PuzzleRepository>) -> Self {
+        Self {
+            puzzle_repository,
+            renderer: GraphicsPlayfieldRenderer::new(),
+            grid: PlayfieldGrid::new(Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT)),
+            cursor: Position::new(0, PLAYFIELD_HEIGHT as i32 - 1),
+            paint_type: TetrominoType::I,
+            pieces: Vec::new(),
+            goal: PuzzleGoal::ClearLines(1),
+            title: EDITED_PUZZLE_TITLE.to_string(),
+            message: String::new(),
+            puzzle_screen: None,
+        }
+    }
+
+    fn get_puzzle(&self) -> Puzzle {
+        Puzzle {
+            title: self.title.clone(),
+            goal: self.goal,
+            pieces: self.pieces.clone(),
+            grid: self.grid.clone(),
+        }
+    }
+
+    fn move_cursor(&mut self, dx: i32, dy: i32) {
+        let x = (self.cursor.x + dx).clamp(0, PLAYFIELD_WIDTH as i32 - 1);
+        let y = (self.cursor.y + dy).clamp(0, PLAYFIELD_HEIGHT as i32 - 1);
+        self.cursor = Position::new(x, y);
+    }
+
+    fn save(&mut self) {
+        self.message = match self
+            .puzzle_repository
+            .save(EDITED_PUZZLE_NAME, &self.get_puzzle())
+        {
+            Ok(()) => "SAVED".to_string(),
+            Err(e) => {
+                eprintln!("Failed to save board: {e}");
+                "SAVE FAILED".to_string()
+            }
+        };
+    }
+
+    fn load(&mut self) {
+        match self.puzzle_repository.load(EDITED_PUZZLE_NAME) {
+            Ok(puzzle) => {
+                self.title = puzzle.title;
+                self.goal = puzzle.goal;
+                self.pieces = puzzle.pieces;
+                self.grid = puzzle.grid;
+                self.message = "LOADED".to_string();
+            }
+            Err(e) => {
+                eprintln!("Failed to load board: {e}");
+                self.message = "LOAD FAILED".to_string();
+            }
+        }
+    }
+
+    fn play(&mut self) {
+        if self.pieces.is_empty() {
+            self.message = "ADD PIECES FIRST".to_string();
+            return;
+        }
+        self.message.clear();
+        self.puzzle_screen = Some(PuzzleScreen::try_out(self.get_puzzle()));
+    }
+
+    fn handle_key_press(&mut self, key: Key) -> Option<ScreenResult> {
+        match key {
+            Key::Escape => return Some(ScreenResult::ReturnToMainMenu),
+            Key::Left => self.move_cursor(-1, 0),
+            Key::Right => self.move_cursor(1, 0),
+            Key::Up => self.move_cursor(0, -1),
+            Key::Down => self.move_cursor(0, 1),
+            Key::Space => self.grid.set(self.cursor, Some(self.paint_type)),
+            Key::Backspace => self.grid.set(self.cursor, None),
+            Key::Enter => self.play(),
+            Key::Alphanumeric('P') => self.pieces.push(self.paint_type),
+            Key::Alphanumeric('X') => {
+                self.pieces.pop();
+            }
+            Key::Alphanumeric('G') => self.goal = get_next_goal(self.goal),
+            Key::Alphanumeric('C') => self.grid.clear(),
+            Key::Alphanumeric('W') => self.save(),
+            Key::Alphanumeric('R') => self.load(),
+            Key::Alphanumeric(ch) => {
+                if let Some(tetromino_type) = TetrominoType::from_char(ch) {
+                    self.paint_type = tetromino_type;
+                }
+            }
+        }
+        None
+    }
+
+    fn get_status_lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("COLOR {}", self.paint_type.to_char()),
+            self.goal.display_text(),
+            format!("PIECES {}", self.pieces.len()),
+        ];
+        for chunk in self.pieces.chunks(QUEUE_PIECES_PER_LINE) {
+            lines.push(chunk.iter().map(|piece| piece.to_char()).collect());
+        }
+        if !self.message.is_empty() {
+            lines.push(self.message.clone());
+        }
+        lines
+    }
+
+    fn draw_cursor(&self, display: &mut dyn Display) -> Result<(), String> {
+        let x = PLAYFIELD_OFFSET_X + self.cursor.x as u32 * BLOCK_SIZE;
+        let y = PLAYFIELD_OFFSET_Y + self.cursor.y as u32 * BLOCK_SIZE;
+        let color = Color::WHITE;
+        display.draw_rectangle(x, y, BLOCK_SIZE, CURSOR_LINE_WIDTH, color)?;
+        display.draw_rectangle(
+            x,
+            y + BLOCK_SIZE - CURSOR_LINE_WIDTH,
+            BLOCK_SIZE,
+            CURSOR_LINE_WIDTH,
+            color,
+        )?;
+        display.draw_rectangle(x, y, CURSOR_LINE_WIDTH, BLOCK_SIZE, color)?;
+        display.draw_rectangle(
+            x + BLOCK_SIZE - CURSOR_LINE_WIDTH,
+            y,
+            CURSOR_LINE_WIDTH,
+            BLOCK_SIZE,
+            color,
+        )
+    }
+
+    fn draw_editor(&self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+
+        let view = PlayfieldView {
+            dimensions: self.grid.get_dimensions(),
+            grid: &self.grid,
+            current_tetrominoes: Vec::new(),
+            next_tetromino_type: self.paint_type,
+            full_lines: Vec::new(),
+            show_blinking_lines: true,
+        };
+        self.renderer.draw(&view, display)?;
+        self.draw_cursor(display)?;
+
+        for (i, line) in self.get_status_lines().iter().enumerate() {
+            let y = HUD_TEXT_OFFSET_Y + (i as u32) * HUD_TEXT_LINE_HEIGHT;
+            display.draw_text(line, HUD_TEXT_OFFSET_X, y, Color::WHITE)?;
+        }
+        for (i, line) in HELP_LINES.iter().enumerate() {
+            let y = HUD_TEXT_OFFSET_Y + (i as u32) * HUD_TEXT_LINE_HEIGHT;
+            display.draw_text(line, NEXT_TETROMINO_OFFSET_X, y, Color::GRAY)?;
+        }
+
+        display.present()
+    }
+}
+
+impl Screen for BoardEditorScreen {
+    fn update(&mut self, delta_time: Duration) {
+        if let Some(puzzle_screen) = &mut self.puzzle_screen {
+            puzzle_screen.update(delta_time);
+        }
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        match &mut self.puzzle_screen {
+            Some(puzzle_screen) => puzzle_screen.draw(display),
+            None => self.draw_editor(display),
+        }
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        if let Some(puzzle_screen) = &mut self.puzzle_screen {
+            return match puzzle_screen.handle_input(input_events) {
+                ScreenResult::ShowPuzzles => {
+                    self.puzzle_screen = None;
+                    ScreenResult::Continue
+                }
+                result => result,
+            };
+        }
+
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(key) => {
+                    if let Some(result) = self.handle_key_press(*key) {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(_) => {}
+            }
+        }
+        ScreenResult::Continue
+    }
+}
+
+/// Cycles through the goals that can be picked in the editor.
+fn get_next_goal(goal: PuzzleGoal) -> PuzzleGoal {
+    match goal {
+        PuzzleGoal::ClearLines(nr_lines) if nr_lines < 4 => PuzzleGoal::ClearLines(nr_lines + 1),
+        PuzzleGoal::ClearLines(_) => PuzzleGoal::PerfectClear,
+        PuzzleGoal::PerfectClear => PuzzleGoal::TSpinDouble,
+        PuzzleGoal::TSpinDouble => PuzzleGoal::ClearLines(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::MockDisplay;
+    use crate::puzzle::MockPuzzleRepository;
+    use rstest::rstest;
+
+    #[test]
+    fn space_paints_cell_under_cursor_with_selected_color() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+
+        // Act
+        press_keys(
+            &mut sut,
+            &[Key::Alphanumeric('T'), Key::Right, Key::Up, Key::Space],
+        );
+
+        // Assert
+        let position = Position::new(1, PLAYFIELD_HEIGHT as i32 - 2);
+        assert_eq!(sut.grid.get(position), Some(&TetrominoType::T));
+    }
+
+    #[test]
+    fn backspace_erases_cell_under_cursor() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+        press_keys(&mut sut, &[Key::Space]);
+
+        // Act
+        press_keys(&mut sut, &[Key::Backspace]);
+
+        // Assert
+        assert!(!sut.grid.is_position_occupied(sut.cursor));
+    }
+
+    #[test]
+    fn cursor_stays_on_playfield() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+
+        // Act
+        press_keys(&mut sut, &[Key::Left, Key::Down]);
+
+        // Assert
+        assert_eq!(sut.cursor, Position::new(0, PLAYFIELD_HEIGHT as i32 - 1));
+    }
+
+    #[test]
+    fn p_and_x_edit_piece_queue() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+
+        // Act
+        press_keys(
+            &mut sut,
+            &[
+                Key::Alphanumeric('O'),
+                Key::Alphanumeric('P'),
+                Key::Alphanumeric('L'),
+                Key::Alphanumeric('P'),
+                Key::Alphanumeric('P'),
+                Key::Alphanumeric('X'),
+            ],
+        );
+
+        // Assert
+        assert_eq!(sut.pieces, vec![TetrominoType::O, TetrominoType::L]);
+    }
+
+    #[rstest]
+    #[case(PuzzleGoal::ClearLines(1), PuzzleGoal::ClearLines(2))]
+    #[case(PuzzleGoal::ClearLines(4), PuzzleGoal::PerfectClear)]
+    #[case(PuzzleGoal::PerfectClear, PuzzleGoal::TSpinDouble)]
+    #[case(PuzzleGoal::TSpinDouble, PuzzleGoal::ClearLines(1))]
+    fn get_next_goal_cycles_through_goals(#[case] goal: PuzzleGoal, #[case] expected: PuzzleGoal) {
+        // Act
+        let result = get_next_goal(goal);
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn saved_board_can_be_loaded() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+        press_keys(
+            &mut sut,
+            &[
+                Key::Alphanumeric('S'),
+                Key::Space,
+                Key::Alphanumeric('P'),
+                Key::Alphanumeric('G'),
+            ],
+        );
+        let edited_puzzle = sut.get_puzzle();
+        press_keys(
+            &mut sut,
+            &[
+                Key::Alphanumeric('W'),
+                Key::Alphanumeric('C'),
+                Key::Alphanumeric('X'),
+            ],
+        );
+
+        // Act
+        press_keys(&mut sut, &[Key::Alphanumeric('R')]);
+
+        // Assert
+        assert_eq!(sut.get_puzzle(), edited_puzzle);
+        assert_eq!(sut.message, "LOADED");
+    }
+
+    #[test]
+    fn load_without_saved_board_shows_message() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+
+        // Act
+        press_keys(&mut sut, &[Key::Alphanumeric('R')]);
+
+        // Assert
+        assert_eq!(sut.message, "LOAD FAILED");
+    }
+
+    #[test]
+    fn enter_without_pieces_does_not_play() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+
+        // Act
+        press_keys(&mut sut, &[Key::Enter]);
+
+        // Assert
+        assert!(sut.puzzle_screen.is_none());
+        assert_eq!(sut.message, "ADD PIECES FIRST");
+    }
+
+    #[test]
+    fn enter_plays_board_and_escape_returns_to_editor() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+        press_keys(&mut sut, &[Key::Space, Key::Alphanumeric('P'), Key::Enter]);
+        assert!(sut.puzzle_screen.is_some());
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::Continue);
+        assert!(sut.puzzle_screen.is_none());
+        assert!(sut.grid.is_position_occupied(sut.cursor));
+    }
+
+    #[test]
+    fn escape_returns_to_main_menu() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn draw_shows_painted_cells_and_piece_queue() {
+        // Arrange
+        let mut sut = BoardEditorScreen::new(Box::new(MockPuzzleRepository::default()));
+        press_keys(
+            &mut sut,
+            &[
+                Key::Alphanumeric('J'),
+                Key::Space,
+                Key::Alphanumeric('P'),
+                Key::Alphanumeric('P'),
+            ],
+        );
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(display.drawn_blocks.len(), 1);
+        assert!(display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text == "JJ"));
+    }
+
+    fn press_keys(sut: &mut BoardEditorScreen, keys: &[Key]) {
+        let input_events: Vec<InputEvent> = keys
+            .iter()
+            .map(|&key| InputEvent::KeyPressed(key))
+            .collect();
+        sut.handle_input(&input_events);
+    }
+}
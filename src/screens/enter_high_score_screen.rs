@@ -1,3 +1,4 @@
+use crate::common::DateTime;
 use crate::constants::*;
 use crate::game_logic::GameResult;
 use crate::graphics::{Color, Display};
@@ -13,11 +14,20 @@ pub struct EnterHighScoreScreen {
     editing_index: usize,
     player_name: String,
     game_result: GameResult,
+    replay_name: Option<String>,
 }
 
 impl EnterHighScoreScreen {
-    pub fn new(high_score_manager: HighScoreManager, game_result: GameResult) -> Self {
-        let mut preview_scores = high_score_manager.get_high_scores().clone();
+    pub fn new(
+        high_score_manager: HighScoreManager,
+        game_result: GameResult,
+        replay_name: Option<String>,
+    ) -> Self {
+        let mut preview_scores = match high_score_manager.get_high_scores() {
+            Some(high_scores) => high_scores.clone(),
+            // The first high score of the table
+            None => HighScores::with_ranking(high_score_manager.get_table_key().get_ranking()),
+        };
         let placeholder_score = HighScore::new("........".to_string(), game_result);
         let editing_index = preview_scores.add(placeholder_score);
         Self {
@@ -27,10 +37,13 @@ impl EnterHighScoreScreen {
             editing_index,
             player_name: String::new(),
             game_result,
+            replay_name,
         }
     }
 
-    fn get_scores_to_display(&self) -> Vec<(usize, String, u32, u32)> {
+    /// Returns the rank, name, ranked value and level of each high score.
+    fn get_scores_to_display(&self) -> Vec<(usize, String, String, u32)> {
+        let ranking = self.preview_scores.get_ranking();
         self.preview_scores
             .get_scores()
             .iter()
@@ -44,7 +57,7 @@ impl EnterHighScoreScreen {
                 (
                     i + 1,
                     display_name,
-                    score.game_result.score,
+                    ranking.format_game_result(&score.game_result),
                     score.game_result.level,
                 )
             })
@@ -53,13 +66,9 @@ impl EnterHighScoreScreen {
 
     fn save_high_score(&mut self) -> Result<(), String> {
         let final_player_name = self.get_final_player_name();
-        let high_score = HighScore::new(
-            final_player_name,
-            GameResult {
-                score: self.game_result.score,
-                level: self.game_result.level,
-            },
-        );
+        let mut high_score = HighScore::new(final_player_name, self.game_result);
+        high_score.replay_name = self.replay_name.clone();
+        high_score.date_time = Some(DateTime::now());
         self.high_score_manager.add_high_score(high_score)?;
         Ok(())
     }
@@ -83,17 +92,18 @@ impl Screen for EnterHighScoreScreen {
 
         self.background.draw(display)?;
 
+        let ranking = self.preview_scores.get_ranking();
         display.draw_text(
-            "    SCORE   LEVEL  NAME",
+            &format!("    {:6}  LEVEL  NAME", ranking.column_title()),
             HIGH_SCORES_X,
             HIGH_SCORES_Y,
             Color::WHITE,
         )?;
 
         let scores = self.get_scores_to_display();
-        for (rank, name, score, level) in scores {
+        for (rank, name, value, level) in scores {
             let y = HIGH_SCORES_Y + (rank as u32 * HIGH_SCORES_LINE_HEIGHT);
-            let text = format!("{:2}  {:06}  {:5}  {}", rank, score, level + 1, name);
+            let text = format!("{:2}  {}  {:5}  {}", rank, value, level + 1, name);
             display.draw_text(&text, HIGH_SCORES_X, y, Color::WHITE)?;
         }
 
@@ -114,10 +124,8 @@ impl Screen for EnterHighScoreScreen {
                     }
                     return ScreenResult::ShowHighScores;
                 }
-                InputEvent::KeyPressed(Key::Alphanumeric(ch)) => {
-                    if self.player_name.len() < 8 {
-                        self.player_name.push(*ch);
-                    }
+                InputEvent::KeyPressed(Key::Alphanumeric(ch)) if self.player_name.len() < 8 => {
+                    self.player_name.push(*ch);
                 }
                 InputEvent::KeyPressed(Key::Backspace) => {
                     self.player_name.pop();
@@ -173,7 +181,7 @@ mod tests {
 
         // Assert
         assert_eq!(result, ScreenResult::ShowHighScores);
-        assert_eq!(sut.high_score_manager.get_high_scores().len(), 1);
+        assert_eq!(sut.high_score_manager.get_high_scores().unwrap().len(), 1);
     }
 
     #[test]
@@ -224,6 +232,7 @@ mod tests {
             GameResult {
                 score: 2000,
                 level: 1,
+                ..Default::default()
             },
         ));
         existing_scores.add(HighScore::new(
@@ -231,6 +240,7 @@ mod tests {
             GameResult {
                 score: 1000,
                 level: 1,
+                ..Default::default()
             },
         ));
 
@@ -241,7 +251,9 @@ mod tests {
             GameResult {
                 score: 1500,
                 level: 3,
+                ..Default::default()
             },
+            None,
         );
 
         // Act
@@ -311,7 +323,9 @@ mod tests {
             GameResult {
                 score: 1500,
                 level: 3,
+                ..Default::default()
             },
+            None,
         );
 
         let input_events = vec![InputEvent::KeyPressed(Key::Enter)];
@@ -336,6 +350,46 @@ mod tests {
         assert_eq!(result, ScreenResult::Continue);
     }
 
+    #[test]
+    fn handle_input_enter_links_replay_to_high_score() {
+        // Arrange
+        let manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let game_result = GameResult {
+            score: 1500,
+            level: 3,
+            ..Default::default()
+        };
+        let mut sut = EnterHighScoreScreen::new(manager, game_result, Some("replay".to_string()));
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        let high_scores = sut
+            .high_score_manager
+            .get_high_scores()
+            .unwrap()
+            .get_scores();
+        assert_eq!(high_scores[0].replay_name, Some("replay".to_string()));
+    }
+
+    #[test]
+    fn handle_input_enter_saves_date_and_time_of_high_score() {
+        // Arrange
+        let mut sut = create_test_screen();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        let high_scores = sut
+            .high_score_manager
+            .get_high_scores()
+            .unwrap()
+            .get_scores();
+        assert!(high_scores[0].date_time.is_some());
+    }
+
     fn create_test_screen() -> EnterHighScoreScreen {
         let repository = Box::new(MockHighScoresRepository::empty());
         let manager = HighScoreManager::new(repository);
@@ -344,7 +398,9 @@ mod tests {
             GameResult {
                 score: 1500,
                 level: 3,
+                ..Default::default()
             },
+            None,
         )
     }
 
@@ -1,72 +1,436 @@
+use crate::ai::{AiPlayer, HeuristicWeights};
+use crate::common::DateTime;
 use crate::common::Dimensions;
+use crate::common::Position;
 use crate::constants::*;
 use crate::events::EventQueue;
+use crate::fumen::{FileFumenRepository, Fumen, FumenRepository};
 use crate::game_logic::Game;
-use crate::game_logic::GameResult;
+use crate::game_logic::GameMode;
+use crate::game_logic::GameRules;
+use crate::game_logic::GameSnapshot;
 use crate::game_logic::GameState;
 use crate::game_logic::Playfield;
+use crate::game_logic::PracticeHistory;
+use crate::graphics::BoardLayout;
 use crate::graphics::Display;
 use crate::graphics::GraphicsHudRenderer;
 use crate::graphics::GraphicsPlayfieldRenderer;
 use crate::gui::GameInput;
 use crate::high_scores::HighScoreManager;
+use crate::high_scores::HighScoreTableKey;
 #[cfg(test)]
 use crate::high_scores::MockHighScoresRepository;
 use crate::input::InputEvent;
 use crate::input::Key;
+use crate::replay::Replay;
+use crate::replay::ReplayRepository;
+use crate::save_game::{SavedGame, SavedGameRepository};
+use crate::screens::versus_screen::translate_two_player_key;
 use crate::screens::Screen;
 use crate::screens::ScreenResult;
+use crate::statistics::GameHistoryRepository;
+use crate::statistics::GameRecord;
+use crate::statistics::LifetimeStatisticsRepository;
 use crate::tetromino::RandomTetrominoGenerator;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// A point in a practice game that undo and redo can return to.
+struct PracticeSnapshot {
+    game: GameSnapshot,
+    tetromino_generator: RandomTetrominoGenerator,
+}
+
 pub struct GameScreen {
     game: Game<GraphicsPlayfieldRenderer, GraphicsHudRenderer, RandomTetrominoGenerator>,
+    held_keys: HashSet<Key>,
+    nr_players: usize,
+    ai_player: Option<AiPlayer>,
+    /// Every input of the first player, stamped with the number of updates of the game so far.
+    replay: Option<Replay>,
+    replay_repository: Option<Box<dyn ReplayRepository>>,
+    /// Name of the replay saved when the game ended, linked to the high score.
+    replay_name: Option<String>,
+    /// Where the game is saved when it's quit with Escape, so it can be continued later.
+    saved_game_repository: Option<Box<dyn SavedGameRepository>>,
+    /// Where the game is added to the lifetime statistics when it ends, not set for practice and
+    /// demo games.
+    lifetime_statistics_repository: Option<Box<dyn LifetimeStatisticsRepository>>,
+    /// Where the game is added to the history when it ends, not set for practice and demo games
+    /// either.
+    game_history_repository: Option<Box<dyn GameHistoryRepository>>,
+    game_mode: GameMode,
+    /// Where the board is exported to as fumen with F, so it can be shared.
+    fumen_repository: Box<dyn FumenRepository>,
+    /// Only set in practice mode, which disables high scores.
+    practice_history: Option<PracticeHistory<PracticeSnapshot>>,
+    /// Time that has passed but hasn't been simulated yet, the game is updated in fixed ticks
+    /// so replays play back exactly the same.
+    unsimulated_time: Duration,
 }
 
 impl GameScreen {
-    pub fn new(high_score_manager: HighScoreManager) -> Self {
-        let playfield_dimensions = Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT);
+    /// Creates a single player game that is saved as a replay and added to the lifetime
+    /// statistics and the history when it ends, and saved to be continued when it's quit.
+    pub fn new(
+        high_score_manager: HighScoreManager,
+        replay_repository: Box<dyn ReplayRepository>,
+        saved_game_repository: Box<dyn SavedGameRepository>,
+        lifetime_statistics_repository: Box<dyn LifetimeStatisticsRepository>,
+        game_history_repository: Box<dyn GameHistoryRepository>,
+    ) -> Self {
+        let seed = rand::random();
+        let mut screen = Self::new_single_player(
+            high_score_manager,
+            RandomTetrominoGenerator::with_seed(seed),
+        );
+        screen.game.start_level(0);
+        screen.replay = Some(Replay::new(seed, GameRules::default(), 0));
+        screen.replay_repository = Some(replay_repository);
+        screen.saved_game_repository = Some(saved_game_repository);
+        screen.lifetime_statistics_repository = Some(lifetime_statistics_repository);
+        screen.game_history_repository = Some(game_history_repository);
+        screen
+    }
+
+    /// Continues a single player game that was quit. The saved game is removed from the
+    /// repository, so it can only be continued once.
+    pub fn resume(
+        high_score_manager: HighScoreManager,
+        replay_repository: Box<dyn ReplayRepository>,
+        saved_game_repository: Box<dyn SavedGameRepository>,
+        lifetime_statistics_repository: Box<dyn LifetimeStatisticsRepository>,
+        game_history_repository: Box<dyn GameHistoryRepository>,
+        saved_game: SavedGame,
+    ) -> Self {
+        let tetromino_generator = RandomTetrominoGenerator::from_state(saved_game.generator_state);
+        let mut screen = Self::new_single_player(high_score_manager, tetromino_generator);
+        screen.game.restore_snapshot(saved_game.game);
+        screen.replay = saved_game.replay;
+        if let Err(e) = saved_game_repository.delete() {
+            eprintln!("Failed to delete saved game: {e}");
+        }
+        screen.replay_repository = Some(replay_repository);
+        screen.saved_game_repository = Some(saved_game_repository);
+        screen.lifetime_statistics_repository = Some(lifetime_statistics_repository);
+        screen.game_history_repository = Some(game_history_repository);
+        screen
+    }
+
+    fn new_single_player(
+        high_score_manager: HighScoreManager,
+        tetromino_generator: RandomTetrominoGenerator,
+    ) -> Self {
+        let spawn_positions = [Position::new(TETRIS_SPAWN_X, TETRIS_SPAWN_Y)];
+        Self::with_players(
+            high_score_manager,
+            tetromino_generator,
+            Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+            &spawn_positions,
+            BoardLayout::single_player(),
+        )
+    }
+
+    /// Creates a co-op game in which two players share one wide playfield, each controlling
+    /// their own tetromino.
+    pub fn new_coop(
+        high_score_manager: HighScoreManager,
+        lifetime_statistics_repository: Box<dyn LifetimeStatisticsRepository>,
+        game_history_repository: Box<dyn GameHistoryRepository>,
+    ) -> Self {
+        let spawn_positions = COOP_SPAWN_X.map(|x| Position::new(x, TETRIS_SPAWN_Y));
+        let mut screen = Self::with_players(
+            high_score_manager,
+            RandomTetrominoGenerator::new(),
+            Dimensions::new(COOP_PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+            &spawn_positions,
+            BoardLayout::coop(),
+        );
+        screen.game.start_level(0);
+        screen.lifetime_statistics_repository = Some(lifetime_statistics_repository);
+        screen.game_history_repository = Some(game_history_repository);
+        screen.game_mode = GameMode::Coop;
+        screen
+    }
+
+    /// Creates a practice game in which every locked tetromino can be undone with U and redone
+    /// with R. It isn't saved and its scores don't enter the high scores.
+    pub fn new_practice(high_score_manager: HighScoreManager) -> Self {
+        let mut screen =
+            Self::new_single_player(high_score_manager, RandomTetrominoGenerator::new());
+        screen.game.start_level(0);
+        screen.start_practice();
+        screen
+    }
+
+    /// Creates a practice game that starts from a shared fumen board, dealing its falling
+    /// tetromino and queue first.
+    pub fn new_practice_from_fumen(high_score_manager: HighScoreManager, fumen: &Fumen) -> Self {
+        let tetromino_generator = RandomTetrominoGenerator::with_queue(fumen.get_tetromino_types());
+        let mut screen = Self::new_single_player(high_score_manager, tetromino_generator);
+        screen.game.set_grid(fumen.grid.clone());
+        screen.game.start_level(0);
+        screen.start_practice();
+        screen
+    }
+
+    /// Creates a demo game in which the AI plays and the keyboard is ignored until game over.
+    /// Scores of the AI don't enter the high scores.
+    pub fn new_demo(high_score_manager: HighScoreManager) -> Self {
+        let mut screen =
+            Self::new_single_player(high_score_manager, RandomTetrominoGenerator::new());
+        screen.game.start_level(0);
+        screen.ai_player = Some(AiPlayer::new(
+            HeuristicWeights::default(),
+            Duration::from_millis(AI_INPUT_INTERVAL_MS),
+        ));
+        screen
+    }
+
+    fn with_players(
+        high_score_manager: HighScoreManager,
+        tetromino_generator: RandomTetrominoGenerator,
+        playfield_dimensions: Dimensions,
+        spawn_positions: &[Position],
+        layout: BoardLayout,
+    ) -> Self {
         let event_queue = Arc::new(EventQueue::new());
-        let playfield = Playfield::new(
+        let playfield = Playfield::with_spawn_positions(
             playfield_dimensions,
-            RandomTetrominoGenerator::new(),
+            tetromino_generator,
             event_queue.clone(),
+            spawn_positions,
         );
-        let mut game = Game::new(
+        let game = Game::new(
             playfield,
-            GraphicsPlayfieldRenderer::new(),
-            GraphicsHudRenderer::new(),
+            GraphicsPlayfieldRenderer::with_layout(layout),
+            GraphicsHudRenderer::with_layout(layout),
             event_queue.clone(),
             high_score_manager,
         );
-        game.start_level(0);
 
-        Self { game }
+        Self {
+            game,
+            held_keys: HashSet::new(),
+            nr_players: spawn_positions.len(),
+            ai_player: None,
+            replay: None,
+            replay_repository: None,
+            replay_name: None,
+            saved_game_repository: None,
+            lifetime_statistics_repository: None,
+            game_history_repository: None,
+            game_mode: GameMode::Marathon,
+            fumen_repository: Box::new(FileFumenRepository::new(EXPORTED_FUMENS_FILE)),
+            practice_history: None,
+            unsimulated_time: Duration::ZERO,
+        }
+    }
+
+    /// Starts practice mode: from now on every locked tetromino can be undone and redone with
+    /// GameInput::Undo and GameInput::Redo, and the score doesn't enter the high scores.
+    fn start_practice(&mut self) {
+        self.practice_history = Some(PracticeHistory::new(self.get_practice_snapshot()));
+    }
+
+    fn get_practice_snapshot(&self) -> PracticeSnapshot {
+        PracticeSnapshot {
+            game: self.game.get_snapshot(),
+            tetromino_generator: self.game.get_tetromino_generator().clone(),
+        }
+    }
+
+    /// Restores the snapshot before the last locked tetromino on undo, or the one undone last on
+    /// redo. Does nothing outside practice mode.
+    fn step_practice_history(&mut self, input: GameInput) {
+        let Some(practice_history) = &mut self.practice_history else {
+            return;
+        };
+        let snapshot = match input {
+            GameInput::Undo => practice_history.undo(),
+            _ => practice_history.redo(),
+        };
+        let Some(snapshot) = snapshot else {
+            return;
+        };
+
+        let game = snapshot.game.clone();
+        let tetromino_generator = snapshot.tetromino_generator.clone();
+        self.game.restore_snapshot(game);
+        self.game.set_tetromino_generator(tetromino_generator);
     }
 
-    fn translate_key_to_game_input(&self, key: Key) -> Option<GameInput> {
+    /// Pushes a snapshot onto the practice history when a tetromino has locked since the last
+    /// one. The events of the lock are handled first, so the snapshot includes the new score.
+    fn update_practice_history(&mut self) {
+        let Some(practice_history) = &self.practice_history else {
+            return;
+        };
+        let nr_locked_tetrominoes = practice_history
+            .get_current()
+            .game
+            .playfield
+            .nr_locked_tetrominoes;
+        if self.game.get_nr_locked_tetrominoes() == nr_locked_tetrominoes {
+            return;
+        }
+
+        self.game.process_event_queue();
+        let snapshot = self.get_practice_snapshot();
+        if let Some(practice_history) = &mut self.practice_history {
+            practice_history.push(snapshot);
+        }
+    }
+
+    fn update_game_tick(&mut self) {
+        let tick_duration = Duration::from_millis(GAME_TICK_MS);
+        if let Some(ai_player) = &mut self.ai_player {
+            if let Some(input) = ai_player.update(tick_duration, &self.game.get_playfield_view()) {
+                self.game.handle_input(input);
+            }
+        }
+        self.game.update(tick_duration);
+        self.update_practice_history();
+        self.save_replay_if_game_over();
+        self.add_to_lifetime_statistics_if_game_over();
+        self.add_to_game_history_if_game_over();
+    }
+
+    /// Saves the game to continue it later, unless it's already over.
+    fn save_game(&self) {
+        let Some(saved_game_repository) = &self.saved_game_repository else {
+            return;
+        };
+        if *self.game.get_game_state() != GameState::Playing {
+            return;
+        }
+
+        let saved_game = SavedGame {
+            generator_state: self.game.get_tetromino_generator().get_state(),
+            game: self.game.get_snapshot(),
+            replay: self.replay.clone(),
+        };
+        if let Err(e) = saved_game_repository.save(&saved_game) {
+            eprintln!("Failed to save game: {e}");
+        }
+    }
+
+    /// Exports the board as fumen, so the moment can be shared.
+    fn export_fumen(&self) {
+        let fumen = Fumen::from_view(&self.game.get_playfield_view());
+        if let Err(e) = self.fumen_repository.append(&fumen) {
+            eprintln!("Failed to export fumen: {e}");
+        }
+    }
+
+    fn save_replay_if_game_over(&mut self) {
+        if *self.game.get_game_state() != GameState::GameOver {
+            return;
+        }
+
+        if let (Some(replay), Some(replay_repository)) =
+            (self.replay.take(), &self.replay_repository)
+        {
+            match replay_repository.save(&replay) {
+                Ok(replay_name) => self.replay_name = Some(replay_name),
+                Err(e) => eprintln!("Failed to save replay: {e}"),
+            }
+        }
+    }
+
+    /// Adds the game to the lifetime statistics once, when it's over.
+    fn add_to_lifetime_statistics_if_game_over(&mut self) {
+        if *self.game.get_game_state() != GameState::GameOver {
+            return;
+        }
+
+        if let Some(lifetime_statistics_repository) = self.lifetime_statistics_repository.take() {
+            if let Err(e) = lifetime_statistics_repository.add_game(
+                self.game_mode,
+                self.game.get_score(),
+                self.game.get_statistics(),
+            ) {
+                eprintln!("Failed to update statistics: {e}");
+            }
+        }
+    }
+
+    /// Adds the game to the history once, when it's over and its replay is saved.
+    fn add_to_game_history_if_game_over(&mut self) {
+        if *self.game.get_game_state() != GameState::GameOver {
+            return;
+        }
+
+        if let Some(game_history_repository) = self.game_history_repository.take() {
+            let game_record = GameRecord {
+                date_time: DateTime::now(),
+                game_mode: self.game_mode,
+                score: self.game.get_score(),
+                lines: self.game.get_total_lines_cleared(),
+                level: self.game.get_level(),
+                duration: self.game.get_statistics().play_time,
+                replay_name: self.replay_name.clone(),
+            };
+            if let Err(e) = game_history_repository.append(&game_record) {
+                eprintln!("Failed to add game to history: {e}");
+            }
+        }
+    }
+
+    /// Returns the player the key belongs to and the game input it maps to.
+    fn translate_key_to_player_input(&self, key: Key) -> Option<(usize, GameInput)> {
+        let is_practice = self.practice_history.is_some();
+        if let (true, Some(input)) = (is_practice, translate_practice_key(key)) {
+            return Some((0, input));
+        }
+
         match self.game.get_game_state() {
-            GameState::Playing => match key {
-                Key::Left => Some(GameInput::MoveLeft),
-                Key::Right => Some(GameInput::MoveRight),
-                Key::Down => Some(GameInput::MoveDown),
-                Key::Up | Key::Alphanumeric('X') => Some(GameInput::RotateClockwise),
-                Key::Alphanumeric('Z') => Some(GameInput::RotateCounterclockwise),
-                Key::Space => Some(GameInput::Drop),
-                _ => None,
-            },
+            GameState::Playing if self.ai_player.is_some() => None,
+            GameState::Playing if self.nr_players > 1 => translate_two_player_key(key),
+            GameState::Playing => translate_playing_key(key).map(|input| (0, input)),
             GameState::GameOver => match key {
-                Key::Space | Key::Enter => Some(GameInput::StartGame),
+                Key::Space | Key::Enter => Some((0, GameInput::StartGame)),
                 _ => None,
             },
             _ => None,
         }
     }
 
+    fn update_held_inputs(&mut self) {
+        for player in 0..self.nr_players {
+            let held_inputs: Vec<GameInput> = self
+                .held_keys
+                .iter()
+                .filter_map(|key| self.translate_key_to_player_input(*key))
+                .filter(|(key_player, _)| *key_player == player)
+                .map(|(_, game_input)| game_input)
+                .collect();
+            if let (0, GameState::Playing, Some(replay)) =
+                (player, self.game.get_game_state(), &mut self.replay)
+            {
+                replay.record_held_inputs(self.game.get_tick(), &held_inputs);
+            }
+            self.game.set_player_held_inputs(player, &held_inputs);
+        }
+    }
+
     fn handle_game_input(&mut self, key: Key) -> ScreenResult {
-        if let Some(game_input) = self.translate_key_to_game_input(key) {
-            match self.game.handle_input(game_input) {
+        if let Some((player, game_input)) = self.translate_key_to_player_input(key) {
+            if let GameInput::Undo | GameInput::Redo = game_input {
+                self.step_practice_history(game_input);
+                return ScreenResult::Continue;
+            }
+
+            if let (0, GameState::Playing, Some(replay)) =
+                (player, self.game.get_game_state(), &mut self.replay)
+            {
+                replay.record_input(self.game.get_tick(), game_input);
+            }
+            let game_state = self.game.handle_player_input(player, game_input);
+            self.update_practice_history();
+            match game_state {
                 GameState::Playing => {
                     // Continue playing, no special action needed
                 }
@@ -76,8 +440,18 @@ impl GameScreen {
                 GameState::ReturnToMainMenu => {
                     return ScreenResult::ReturnToMainMenu;
                 }
-                GameState::EnterHighScore(level, score) => {
-                    return ScreenResult::EnterHighScore(GameResult { level, score });
+                GameState::EnterHighScore(..)
+                    if self.ai_player.is_some() || self.practice_history.is_some() =>
+                {
+                    return ScreenResult::ReturnToMainMenu;
+                }
+                GameState::EnterHighScore(..) => {
+                    let table_key = HighScoreTableKey::new(self.game_mode, self.game.get_rules());
+                    return ScreenResult::EnterHighScore(
+                        table_key,
+                        self.game.get_game_result(),
+                        self.replay_name.clone(),
+                    );
                 }
             }
         }
@@ -93,9 +467,39 @@ impl GameScreen {
     }
 }
 
+/// Maps a key to the game input it triggers while a single player game is being played.
+pub fn translate_playing_key(key: Key) -> Option<GameInput> {
+    match key {
+        Key::Left => Some(GameInput::MoveLeft),
+        Key::Right => Some(GameInput::MoveRight),
+        Key::Down => Some(GameInput::MoveDown),
+        Key::Up | Key::Alphanumeric('X') => Some(GameInput::RotateClockwise),
+        Key::Alphanumeric('Z') => Some(GameInput::RotateCounterclockwise),
+        Key::Alphanumeric('A') => Some(GameInput::Rotate180),
+        Key::Alphanumeric('S') => Some(GameInput::SonicDrop),
+        Key::Alphanumeric('C') => Some(GameInput::Hold),
+        Key::Space => Some(GameInput::Drop),
+        _ => None,
+    }
+}
+
+/// Maps a key to undo or redo in a practice game, also after game over.
+fn translate_practice_key(key: Key) -> Option<GameInput> {
+    match key {
+        Key::Alphanumeric('U') => Some(GameInput::Undo),
+        Key::Alphanumeric('R') => Some(GameInput::Redo),
+        _ => None,
+    }
+}
+
 impl Screen for GameScreen {
     fn update(&mut self, delta_time: Duration) {
-        self.game.update(delta_time);
+        let tick_duration = Duration::from_millis(GAME_TICK_MS);
+        self.unsimulated_time += delta_time;
+        while self.unsimulated_time >= tick_duration {
+            self.unsimulated_time -= tick_duration;
+            self.update_game_tick();
+        }
     }
 
     fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
@@ -106,13 +510,25 @@ impl Screen for GameScreen {
         for event in input_events {
             match event {
                 InputEvent::Quit => return ScreenResult::Quit,
-                InputEvent::KeyPressed(Key::Escape) => return ScreenResult::ReturnToMainMenu,
+                InputEvent::KeyPressed(Key::Escape) => {
+                    self.save_game();
+                    return ScreenResult::ReturnToMainMenu;
+                }
+                InputEvent::KeyPressed(Key::Alphanumeric('F')) if self.nr_players == 1 => {
+                    self.export_fumen();
+                }
                 InputEvent::KeyPressed(key) => {
+                    self.held_keys.insert(*key);
+                    self.update_held_inputs();
                     let result = self.handle_game_input(*key);
                     if result != ScreenResult::Continue {
                         return result;
                     }
                 }
+                InputEvent::KeyReleased(key) => {
+                    self.held_keys.remove(key);
+                    self.update_held_inputs();
+                }
             }
         }
 
@@ -125,6 +541,13 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::fumen::MockFumenRepository;
+    use crate::game_logic::GameResult;
+    use crate::game_logic::PlayfieldGrid;
+    use crate::replay::{verify_replay, MockReplayRepository, ReplayAction, ReplayEvent};
+    use crate::save_game::MockSavedGameRepository;
+    use crate::statistics::{MockGameHistoryRepository, MockLifetimeStatisticsRepository};
+    use crate::tetromino::TetrominoType;
     use crate::{graphics::MockDisplay, test_helpers::get_tetromino_position_from_gamescreen};
 
     #[test]
@@ -204,10 +627,13 @@ mod tests {
     #[case(Key::Up, Some(GameInput::RotateClockwise))]
     #[case(Key::Alphanumeric('X'), Some(GameInput::RotateClockwise))]
     #[case(Key::Alphanumeric('Z'), Some(GameInput::RotateCounterclockwise))]
+    #[case(Key::Alphanumeric('A'), Some(GameInput::Rotate180))]
+    #[case(Key::Alphanumeric('S'), Some(GameInput::SonicDrop))]
+    #[case(Key::Alphanumeric('C'), Some(GameInput::Hold))]
     #[case(Key::Space, Some(GameInput::Drop))]
     #[case(Key::Enter, None)]
     #[case(Key::Escape, None)] // Handled separately in handle_input
-    fn translate_key_to_game_input_when_playing(
+    fn translate_key_to_player_input_when_playing(
         #[case] key: Key,
         #[case] expected: Option<GameInput>,
     ) {
@@ -215,10 +641,10 @@ mod tests {
         let sut = create_test_game_screen(); // Starts in Playing state
 
         // Act
-        let result = sut.translate_key_to_game_input(key);
+        let result = sut.translate_key_to_player_input(key);
 
         // Assert
-        assert_eq!(result, expected);
+        assert_eq!(result, expected.map(|input| (0, input)));
     }
 
     #[rstest]
@@ -231,7 +657,10 @@ mod tests {
     #[case(Key::Up, None)]
     #[case(Key::Alphanumeric('Z'), None)]
     #[case(Key::Alphanumeric('X'), None)]
-    fn translate_key_to_game_input_when_game_over(
+    #[case(Key::Alphanumeric('A'), None)]
+    #[case(Key::Alphanumeric('S'), None)]
+    #[case(Key::Alphanumeric('C'), None)]
+    fn translate_key_to_player_input_when_game_over(
         #[case] key: Key,
         #[case] expected: Option<GameInput>,
     ) {
@@ -240,10 +669,10 @@ mod tests {
         sut.game.set_game_state_game_over();
 
         // Act
-        let result = sut.translate_key_to_game_input(key);
+        let result = sut.translate_key_to_player_input(key);
 
         // Assert
-        assert_eq!(result, expected);
+        assert_eq!(result, expected.map(|input| (0, input)));
     }
 
     #[test]
@@ -259,8 +688,574 @@ mod tests {
         assert_eq!(result, ScreenResult::ReturnToMainMenu);
     }
 
+    #[test]
+    fn handle_input_key_pressed_adds_held_key() {
+        // Arrange
+        let mut sut = create_test_game_screen();
+        let input_events = vec![InputEvent::KeyPressed(Key::Up)];
+
+        // Act
+        let result = sut.handle_input(&input_events);
+
+        // Assert
+        assert_eq!(result, ScreenResult::Continue);
+        assert!(sut.held_keys.contains(&Key::Up));
+    }
+
+    #[test]
+    fn handle_input_key_released_removes_held_key() {
+        // Arrange
+        let mut sut = create_test_game_screen();
+        let input_events = vec![
+            InputEvent::KeyPressed(Key::Up),
+            InputEvent::KeyReleased(Key::Up),
+        ];
+
+        // Act
+        let result = sut.handle_input(&input_events);
+
+        // Assert
+        assert_eq!(result, ScreenResult::Continue);
+        assert!(sut.held_keys.is_empty());
+    }
+
+    #[test]
+    fn new_coop_spawns_tetromino_for_each_player_in_own_column() {
+        // Act
+        let sut = create_test_coop_game_screen();
+
+        // Assert
+        let playfield = sut.game.get_playfield();
+        for (player, spawn_x) in COOP_SPAWN_X.iter().enumerate() {
+            let position = playfield
+                .get_player_tetromino(player)
+                .unwrap()
+                .get_position();
+            assert_eq!(position.x, *spawn_x);
+        }
+    }
+
+    #[rstest]
+    #[case(Key::Alphanumeric('A'), 0)]
+    #[case(Key::Left, 1)]
+    fn handle_input_in_coop_moves_tetromino_of_player_owning_the_key(
+        #[case] key: Key,
+        #[case] player: usize,
+    ) {
+        // Arrange
+        let mut sut = create_test_coop_game_screen();
+        let other_player = 1 - player;
+        let start_x = get_player_tetromino_x(&sut, player);
+        let other_start_x = get_player_tetromino_x(&sut, other_player);
+        let input_events = vec![InputEvent::KeyPressed(key)];
+
+        // Act
+        sut.handle_input(&input_events);
+
+        // Assert
+        assert_eq!(get_player_tetromino_x(&sut, player), start_x - 1);
+        assert_eq!(get_player_tetromino_x(&sut, other_player), other_start_x);
+    }
+
+    #[test]
+    fn handle_input_in_demo_ignores_playing_keys() {
+        // Arrange
+        let mut sut = create_test_demo_game_screen();
+        let start_position = get_tetromino_position_from_gamescreen(&sut);
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Left)]);
+
+        // Assert
+        assert_eq!(get_tetromino_position_from_gamescreen(&sut), start_position);
+    }
+
+    #[test]
+    fn update_in_demo_lets_ai_play() {
+        // Arrange
+        let mut sut = create_test_demo_game_screen();
+        let start_position = get_tetromino_position_from_gamescreen(&sut);
+
+        // Act
+        sut.update(Duration::from_millis(AI_INPUT_INTERVAL_MS));
+
+        // Assert
+        let playfield_view = sut.game.get_playfield_view();
+        let has_moved = get_tetromino_position_from_gamescreen(&sut) != start_position;
+        let has_locked = playfield_view.grid != &PlayfieldGrid::new(playfield_view.dimensions);
+        assert!(has_moved || has_locked);
+    }
+
+    #[test]
+    fn handle_input_in_demo_after_game_over_returns_to_main_menu() {
+        // Arrange
+        let mut sut = create_test_demo_game_screen();
+        sut.game.set_game_state_game_over();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn update_saves_replay_when_game_ends() {
+        // Arrange
+        let replay_repository = MockReplayRepository::new();
+        let saved_replays = replay_repository.saved_replays.clone();
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new(
+            high_score_manager,
+            Box::new(replay_repository),
+            Box::new(MockSavedGameRepository::new()),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+        );
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Left)]);
+
+        // Act
+        while *sut.game.get_game_state() == GameState::Playing {
+            sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+            sut.update(Duration::from_millis(GAME_TICK_MS));
+        }
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Assert
+        let saved_replays = saved_replays.borrow();
+        assert_eq!(saved_replays.len(), 1);
+        assert_eq!(sut.replay_name, Some("0".to_string()));
+        assert_eq!(
+            saved_replays[0].events[0].action,
+            ReplayAction::HeldInputs(vec![GameInput::MoveLeft])
+        );
+        assert!(saved_replays[0]
+            .events
+            .iter()
+            .any(|event| event.action == ReplayAction::Input(GameInput::Drop)));
+    }
+
+    #[test]
+    fn update_saves_replay_that_reproduces_the_game_result() {
+        // Arrange
+        let replay_repository = MockReplayRepository::new();
+        let saved_replays = replay_repository.saved_replays.clone();
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new(
+            high_score_manager,
+            Box::new(replay_repository),
+            Box::new(MockSavedGameRepository::new()),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+        );
+        play_test_inputs(&mut sut);
+
+        // Act
+        while *sut.game.get_game_state() == GameState::Playing {
+            sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+            sut.update(Duration::from_millis(GAME_TICK_MS));
+        }
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Assert
+        let game_result = GameResult {
+            score: sut.game.get_score(),
+            level: sut.game.get_level(),
+            ..Default::default()
+        };
+        assert_eq!(
+            verify_replay(&saved_replays.borrow()[0], &game_result),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn update_adds_game_to_lifetime_statistics_once_when_game_ends() {
+        // Arrange
+        let lifetime_statistics_repository = MockLifetimeStatisticsRepository::new();
+        let lifetime_statistics = lifetime_statistics_repository.lifetime_statistics.clone();
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new(
+            high_score_manager,
+            Box::new(MockReplayRepository::new()),
+            Box::new(MockSavedGameRepository::new()),
+            Box::new(lifetime_statistics_repository),
+            Box::new(MockGameHistoryRepository::default()),
+        );
+
+        // Act
+        while *sut.game.get_game_state() == GameState::Playing {
+            sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+            sut.update(Duration::from_millis(GAME_TICK_MS));
+        }
+        sut.update(Duration::from_millis(GAME_TICK_MS * 3));
+
+        // Assert
+        let lifetime_statistics = lifetime_statistics.borrow();
+        assert_eq!(lifetime_statistics.nr_games, 1);
+        assert!(lifetime_statistics
+            .personal_bests
+            .contains_key(&GameMode::Marathon));
+    }
+
+    #[test]
+    fn update_adds_game_to_history_once_with_its_replay_when_game_ends() {
+        // Arrange
+        let game_history_repository = MockGameHistoryRepository::default();
+        let game_records = game_history_repository.game_records.clone();
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new(
+            high_score_manager,
+            Box::new(MockReplayRepository::new()),
+            Box::new(MockSavedGameRepository::new()),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(game_history_repository),
+        );
+
+        // Act
+        while *sut.game.get_game_state() == GameState::Playing {
+            sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+            sut.update(Duration::from_millis(GAME_TICK_MS));
+        }
+        sut.update(Duration::from_millis(GAME_TICK_MS * 3));
+
+        // Assert
+        let game_records = game_records.borrow();
+        assert_eq!(game_records.len(), 1);
+        assert_eq!(game_records[0].game_mode, GameMode::Marathon);
+        assert_eq!(game_records[0].score, sut.game.get_score());
+        assert_eq!(game_records[0].replay_name, Some("0".to_string()));
+        assert!(game_records[0].duration > Duration::ZERO);
+    }
+
+    #[test]
+    fn update_runs_game_in_fixed_ticks() {
+        // Arrange
+        let mut sut = create_test_game_screen();
+
+        // Act
+        sut.update(Duration::from_millis(GAME_TICK_MS * 3 + 5));
+
+        // Assert
+        assert_eq!(sut.unsimulated_time, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn escape_saves_game_that_resumes_in_exactly_the_same_state() {
+        // Arrange
+        let saved_game_repository = MockSavedGameRepository::new();
+        let saved_game = saved_game_repository.saved_game.clone();
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut original = GameScreen::new(
+            high_score_manager,
+            Box::new(MockReplayRepository::new()),
+            Box::new(saved_game_repository),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+        );
+        play_test_inputs(&mut original);
+        original.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+        let encoded_saved_game = saved_game.borrow().as_ref().unwrap().encode();
+
+        // Act
+        let mut sut = GameScreen::resume(
+            HighScoreManager::new(Box::new(MockHighScoresRepository::empty())),
+            Box::new(MockReplayRepository::new()),
+            Box::new(MockSavedGameRepository::new()),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+            SavedGame::decode(&encoded_saved_game).unwrap(),
+        );
+
+        // Assert
+        assert_eq!(sut.game.get_snapshot(), original.game.get_snapshot());
+        assert_eq!(sut.replay, original.replay);
+        assert_eq!(
+            sut.game.get_tetromino_generator().get_state(),
+            original.game.get_tetromino_generator().get_state()
+        );
+        play_test_inputs(&mut original);
+        play_test_inputs(&mut sut);
+        assert_eq!(sut.game.get_snapshot(), original.game.get_snapshot());
+        assert_eq!(sut.game.get_hud_view(), original.game.get_hud_view());
+    }
+
+    #[test]
+    fn escape_after_game_over_does_not_save_game() {
+        // Arrange
+        let saved_game_repository = MockSavedGameRepository::new();
+        let saved_game = saved_game_repository.saved_game.clone();
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new(
+            high_score_manager,
+            Box::new(MockReplayRepository::new()),
+            Box::new(saved_game_repository),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+        );
+        sut.game.set_game_state_game_over();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+        assert!(saved_game.borrow().is_none());
+    }
+
+    #[test]
+    fn resume_removes_saved_game() {
+        // Arrange
+        let mut original = create_test_game_screen();
+        let saved_game = SavedGame {
+            generator_state: original.game.get_tetromino_generator().get_state(),
+            game: original.game.get_snapshot(),
+            replay: None,
+        };
+        let saved_game_repository = MockSavedGameRepository::new();
+        *saved_game_repository.saved_game.borrow_mut() = Some(saved_game.clone());
+        let stored_saved_game = saved_game_repository.saved_game.clone();
+        original.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Act
+        GameScreen::resume(
+            HighScoreManager::new(Box::new(MockHighScoresRepository::empty())),
+            Box::new(MockReplayRepository::new()),
+            Box::new(saved_game_repository),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+            saved_game,
+        );
+
+        // Assert
+        assert!(stored_saved_game.borrow().is_none());
+    }
+
+    #[test]
+    fn handle_input_in_practice_undoes_and_redoes_locked_tetromino() {
+        // Arrange
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new_practice(high_score_manager);
+        let snapshot_before_drop = sut.game.get_snapshot();
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+        let snapshot_after_drop = sut.game.get_snapshot();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('U'))]);
+        let snapshot_after_undo = sut.game.get_snapshot();
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('R'))]);
+
+        // Assert
+        assert_eq!(snapshot_after_undo, snapshot_before_drop);
+        assert_eq!(sut.game.get_snapshot(), snapshot_after_drop);
+    }
+
+    #[test]
+    fn new_practice_from_fumen_starts_with_fumen_board_and_queue() {
+        // Arrange
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let fumen = Fumen::decode("v115@bhzhPeAgWVAFLDmClcJSAVDEHBEooRBUoAVBzAAAA").unwrap();
+
+        // Act
+        let mut sut = GameScreen::new_practice_from_fumen(high_score_manager, &fumen);
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Assert
+        let view = sut.game.get_playfield_view();
+        assert_eq!(view.grid, &fumen.grid);
+        assert_eq!(view.current_tetrominoes[0].get_type(), TetrominoType::T);
+        assert_eq!(view.next_tetromino_type, TetrominoType::S);
+        assert!(sut.practice_history.is_some());
+    }
+
+    #[test]
+    fn handle_input_records_inputs_stamped_with_number_of_updates() {
+        // Arrange
+        let mut sut = create_test_game_screen();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Left)]);
+        sut.update(Duration::from_millis(GAME_TICK_MS * 2));
+        sut.handle_input(&[
+            InputEvent::KeyReleased(Key::Left),
+            InputEvent::KeyPressed(Key::Space),
+        ]);
+
+        // Assert
+        let replay = sut.replay.unwrap();
+        assert_eq!(
+            replay.events,
+            vec![
+                ReplayEvent {
+                    tick: 0,
+                    action: ReplayAction::HeldInputs(vec![GameInput::MoveLeft])
+                },
+                ReplayEvent {
+                    tick: 0,
+                    action: ReplayAction::Input(GameInput::MoveLeft)
+                },
+                ReplayEvent {
+                    tick: 2,
+                    action: ReplayAction::HeldInputs(vec![])
+                },
+                ReplayEvent {
+                    tick: 2,
+                    action: ReplayAction::HeldInputs(vec![GameInput::Drop])
+                },
+                ReplayEvent {
+                    tick: 2,
+                    action: ReplayAction::Input(GameInput::Drop)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn handle_input_after_game_over_is_not_recorded() {
+        // Arrange
+        let mut sut = create_test_game_screen();
+        sut.game.set_game_state_game_over();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert!(sut.replay.unwrap().events.is_empty());
+    }
+
+    #[test]
+    fn handle_input_undo_after_game_over_in_practice_continues_playing() {
+        // Arrange
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new_practice(high_score_manager);
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+        sut.game.set_game_state_game_over();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('U'))]);
+
+        // Assert
+        assert_eq!(*sut.game.get_game_state(), GameState::Playing);
+        assert_eq!(sut.game.get_nr_locked_tetrominoes(), 0);
+    }
+
+    #[test]
+    fn handle_input_undo_outside_practice_does_nothing() {
+        // Arrange
+        let mut sut = create_test_game_screen();
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('U'))]);
+
+        // Assert
+        assert_eq!(sut.game.get_nr_locked_tetrominoes(), 1);
+    }
+
+    #[test]
+    fn handle_input_after_game_over_in_practice_returns_to_main_menu() {
+        // Arrange
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        let mut sut = GameScreen::new_practice(high_score_manager);
+        let blocks = sut
+            .game
+            .get_playfield()
+            .get_player_tetromino(0)
+            .unwrap()
+            .get_world_blocks();
+        let lowest_y = blocks.iter().map(|block| block.y).max().unwrap();
+        let tetromino_columns: HashSet<i32> = blocks
+            .iter()
+            .filter(|block| block.y == lowest_y)
+            .map(|block| block.x)
+            .collect();
+        let mut grid = PlayfieldGrid::new(Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT));
+        for x in 0..PLAYFIELD_WIDTH as i32 {
+            if !tetromino_columns.contains(&x) {
+                let bottom_row = PLAYFIELD_HEIGHT as i32 - 1;
+                grid.set(Position::new(x, bottom_row), Some(TetrominoType::I));
+            }
+        }
+        sut.game.set_grid(grid);
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+        sut.update(Duration::from_millis(GAME_TICK_MS * 60));
+        assert!(sut.game.get_score() > 0);
+        sut.game.set_game_state_game_over();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn handle_input_f_exports_board_as_fumen() {
+        // Arrange
+        let mut sut = create_test_game_screen();
+        let fumen_repository = MockFumenRepository::new();
+        let fumens = fumen_repository.fumens.clone();
+        sut.fumen_repository = Box::new(fumen_repository);
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('F'))]);
+
+        // Assert
+        assert_eq!(
+            *fumens.borrow(),
+            vec![Fumen::from_view(&sut.game.get_playfield_view())]
+        );
+    }
+
+    /// Moves, rotates and drops a few tetrominoes with some time passing in between.
+    fn play_test_inputs(sut: &mut GameScreen) {
+        let keys = [
+            Key::Left,
+            Key::Up,
+            Key::Space,
+            Key::Right,
+            Key::Down,
+            Key::Space,
+        ];
+        for key in keys {
+            sut.handle_input(&[InputEvent::KeyPressed(key)]);
+            sut.update(Duration::from_millis(GAME_TICK_MS * 7));
+            sut.handle_input(&[InputEvent::KeyReleased(key)]);
+            sut.update(Duration::from_millis(GAME_TICK_MS * 5));
+        }
+    }
+
+    fn create_test_demo_game_screen() -> GameScreen {
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        GameScreen::new_demo(high_score_manager)
+    }
+
+    fn get_player_tetromino_x(sut: &GameScreen, player: usize) -> i32 {
+        sut.game
+            .get_playfield()
+            .get_player_tetromino(player)
+            .unwrap()
+            .get_position()
+            .x
+    }
+
+    fn create_test_coop_game_screen() -> GameScreen {
+        let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
+        GameScreen::new_coop(
+            high_score_manager,
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+        )
+    }
+
     fn create_test_game_screen() -> GameScreen {
         let high_score_manager = HighScoreManager::new(Box::new(MockHighScoresRepository::empty()));
-        GameScreen::new(high_score_manager)
+        GameScreen::new(
+            high_score_manager,
+            Box::new(MockReplayRepository::new()),
+            Box::new(MockSavedGameRepository::new()),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+        )
     }
 }
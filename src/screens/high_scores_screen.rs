@@ -1,22 +1,53 @@
 use crate::constants::*;
 use crate::graphics::{Color, Display};
-use crate::high_scores::{HighScoreManager, HighScoresScreenBackground};
+use crate::high_scores::{
+    HighScoreManager, HighScoreTableKey, HighScoresLoadError, HighScoresScreenBackground,
+};
 use crate::input::{InputEvent, Key};
 use crate::screens::{Screen, ScreenResult};
 use std::time::Duration;
 
+/// Shows one high score table, Left and Right switch to the other tables.
 pub struct HighScoresScreen {
     high_score_manager: HighScoreManager,
     background: HighScoresScreenBackground,
+    table_key: HighScoreTableKey,
 }
 
 impl HighScoresScreen {
+    /// Creates the screen showing the table of the manager.
     pub fn new(high_score_manager: HighScoreManager) -> Self {
+        let table_key = high_score_manager.get_table_key();
         Self {
             high_score_manager,
             background: HighScoresScreenBackground::new(),
+            table_key,
         }
     }
+
+    /// Switches to the next table, or the previous one when going back.
+    fn switch_table(&mut self, forward: bool) {
+        let mut table_keys: Vec<HighScoreTableKey> = self
+            .high_score_manager
+            .get_high_score_tables()
+            .iter()
+            .map(|(table_key, _)| table_key)
+            .collect();
+        let index = match table_keys.binary_search(&self.table_key) {
+            Ok(index) => index,
+            Err(index) => {
+                // The table of the manager has no scores yet
+                table_keys.insert(index, self.table_key);
+                index
+            }
+        };
+        let next_index = if forward {
+            (index + 1) % table_keys.len()
+        } else {
+            (index + table_keys.len() - 1) % table_keys.len()
+        };
+        self.table_key = table_keys[next_index];
+    }
 }
 
 impl Screen for HighScoresScreen {
@@ -29,27 +60,42 @@ impl Screen for HighScoresScreen {
 
         self.background.draw(display)?;
 
+        let high_scores = self
+            .high_score_manager
+            .get_high_score_tables()
+            .get(self.table_key);
+        let ranking = self.table_key.get_ranking();
         display.draw_text(
-            "    SCORE   LEVEL  NAME",
+            &format!("    {:6}  LEVEL  NAME", ranking.column_title()),
             HIGH_SCORES_X,
             HIGH_SCORES_Y,
             Color::WHITE,
         )?;
 
-        let scores = self.high_score_manager.get_high_scores().get_scores();
+        let scores = high_scores.map_or(&[][..], |high_scores| high_scores.get_scores());
         for (i, score) in scores.iter().enumerate() {
             let y = HIGH_SCORES_Y + HIGH_SCORES_LINE_HEIGHT + (i as u32 * HIGH_SCORES_LINE_HEIGHT);
             let text = format!(
-                "{:2}  {:06}  {:5}  {}",
+                "{:2}  {}  {:5}  {}",
                 i + 1,
-                score.game_result.score,
+                ranking.format_game_result(&score.game_result),
                 score.game_result.level + 1,
                 score.name
             );
             display.draw_text(&text, HIGH_SCORES_X, y, Color::WHITE)?;
         }
 
-        display.draw_text("Press ESC to return", 150, 320, Color::WHITE)?;
+        let footer = format!("< {} >  ESC RETURN", self.table_key.get_title());
+        display.draw_text(&footer, HIGH_SCORES_X, 320, Color::WHITE)?;
+
+        if let Some(load_error) = self.high_score_manager.get_load_error() {
+            let notice = match load_error {
+                HighScoresLoadError::Io(_) => "SCORES COULD NOT BE READ",
+                HighScoresLoadError::Corrupted(_) => "CORRUPTED SCORES WERE RESET",
+                HighScoresLoadError::Tampered => "TAMPERED SCORES WERE RESET",
+            };
+            display.draw_text(notice, HIGH_SCORES_X, 345, Color::RED)?;
+        }
 
         display.present()?;
         Ok(())
@@ -60,6 +106,8 @@ impl Screen for HighScoresScreen {
             match event {
                 InputEvent::Quit => return ScreenResult::Quit,
                 InputEvent::KeyPressed(Key::Escape) => return ScreenResult::ReturnToMainMenu,
+                InputEvent::KeyPressed(Key::Left) => self.switch_table(false),
+                InputEvent::KeyPressed(Key::Right) => self.switch_table(true),
                 _ => {}
             }
         }
@@ -70,9 +118,10 @@ impl Screen for HighScoresScreen {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game_logic::GameResult;
+    use crate::game_logic::{GameMode, GameResult, GameRules};
     use crate::graphics::MockDisplay;
-    use crate::high_scores::{HighScore, HighScores, MockHighScoresRepository};
+    use crate::high_scores::{HighScore, HighScoreTables, HighScores, MockHighScoresRepository};
+    use rstest::rstest;
 
     #[test]
     fn new_creates_high_scores_screen() {
@@ -84,7 +133,25 @@ mod tests {
         let sut = HighScoresScreen::new(manager);
 
         // Assert
-        assert_eq!(sut.high_score_manager.get_high_scores().len(), 0);
+        assert_eq!(sut.high_score_manager.get_high_scores().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn draw_when_high_scores_were_tampered_with_tells_they_were_reset() {
+        // Arrange
+        let mut repository = MockHighScoresRepository::empty();
+        repository.load_error = Some(HighScoresLoadError::Tampered);
+        let mut sut = HighScoresScreen::new(HighScoreManager::new(Box::new(repository)));
+        let mut display = MockDisplay::new();
+
+        // Act
+        sut.draw(&mut display).unwrap();
+
+        // Assert
+        assert!(display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text == "TAMPERED SCORES WERE RESET"));
     }
 
     #[test]
@@ -111,6 +178,7 @@ mod tests {
             GameResult {
                 score: 1000,
                 level: 5,
+                ..Default::default()
             },
         ));
         let repository = Box::new(MockHighScoresRepository::new(scores));
@@ -143,18 +211,25 @@ mod tests {
             let name = format!("PLAYER{i}");
             let score = 1000 + i * 100;
             let level = i;
-            let high_score = HighScore::new(name, GameResult { score, level });
+            let high_score = HighScore::new(
+                name,
+                GameResult {
+                    score,
+                    level,
+                    ..Default::default()
+                },
+            );
             manager.add_high_score(high_score).unwrap();
         }
         let mut sut = HighScoresScreen::new(manager);
-        let initial_scores_len = sut.high_score_manager.get_high_scores().len();
+        let initial_scores_len = sut.high_score_manager.get_high_scores().unwrap().len();
 
         // Act
         sut.update(Duration::from_millis(100));
 
         // Assert
         assert_eq!(
-            sut.high_score_manager.get_high_scores().len(),
+            sut.high_score_manager.get_high_scores().unwrap().len(),
             initial_scores_len
         );
     }
@@ -188,4 +263,77 @@ mod tests {
         // Assert
         assert_eq!(result, ScreenResult::Continue);
     }
+
+    #[rstest]
+    #[case(Key::Right, "< COOP >  ESC RETURN", true)]
+    #[case(Key::Left, "< PUZZLE >  ESC RETURN", false)]
+    fn handle_input_left_and_right_switch_table(
+        #[case] key: Key,
+        #[case] expected_footer: &str,
+        #[case] expect_coop_score: bool,
+    ) {
+        // Arrange
+        let mut high_score_tables = HighScoreTables::new();
+        high_score_tables
+            .get_mut(HighScoreTableKey::with_default_rules(GameMode::Coop))
+            .add(HighScore::new(
+                "DUO".to_string(),
+                GameResult {
+                    score: 2000,
+                    level: 3,
+                    ..Default::default()
+                },
+            ));
+        let repository = Box::new(MockHighScoresRepository::with_tables(high_score_tables));
+        let mut sut = HighScoresScreen::new(HighScoreManager::new(repository));
+        let mut display = MockDisplay::new();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(key)]);
+        sut.draw(&mut display).unwrap();
+
+        // Assert
+        let texts: Vec<&str> = display
+            .drawn_text
+            .iter()
+            .map(|(text, _, _, _)| text.as_str())
+            .collect();
+        assert!(texts.contains(&expected_footer));
+        assert_eq!(texts.contains(&" 1  002000      4  DUO"), expect_coop_score);
+    }
+
+    #[test]
+    fn handle_input_left_switches_to_table_of_other_rules() {
+        // Arrange
+        let mut high_score_tables = HighScoreTables::new();
+        high_score_tables
+            .get_mut(HighScoreTableKey::new(
+                GameMode::Marathon,
+                GameRules::without_delays(),
+            ))
+            .add(HighScore::new(
+                "BOB".to_string(),
+                GameResult {
+                    score: 3000,
+                    level: 2,
+                    ..Default::default()
+                },
+            ));
+        let repository = Box::new(MockHighScoresRepository::with_tables(high_score_tables));
+        let mut sut = HighScoresScreen::new(HighScoreManager::new(repository));
+        let mut display = MockDisplay::new();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Left)]);
+        sut.draw(&mut display).unwrap();
+
+        // Assert
+        let texts: Vec<&str> = display
+            .drawn_text
+            .iter()
+            .map(|(text, _, _, _)| text.as_str())
+            .collect();
+        assert!(texts.contains(&"< MARATHON 0/0 >  ESC RETURN"));
+        assert!(texts.contains(&" 1  003000      3  BOB"));
+    }
 }
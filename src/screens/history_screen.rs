@@ -0,0 +1,359 @@
+use crate::constants::*;
+use crate::game_logic::GameMode;
+use crate::graphics::{Color, Display};
+use crate::input::{InputEvent, Key};
+use crate::screens::{Screen, ScreenResult};
+use crate::statistics::{GameHistory, GameHistoryRepository, GameRecord, HistorySort};
+use std::time::Duration;
+use strum::IntoEnumIterator;
+
+/// Lists every game played to the end. S changes the order, F shows the games of one mode only
+/// and E exports the listed games to CSV.
+pub struct HistoryScreen {
+    game_history_repository: Box<dyn GameHistoryRepository>,
+    game_history: GameHistory,
+    /// Only the games of this mode are listed, all games when it's not set.
+    game_mode: Option<GameMode>,
+    sort: HistorySort,
+    first_visible_index: usize,
+    /// Shown instead of the keys after exporting.
+    message: Option<String>,
+}
+
+impl HistoryScreen {
+    pub fn new(game_history_repository: Box<dyn GameHistoryRepository>) -> Self {
+        let game_history = game_history_repository.load().unwrap_or_else(|e| {
+            eprintln!("Failed to load history: {e}");
+            GameHistory::default()
+        });
+        Self {
+            game_history_repository,
+            game_history,
+            game_mode: None,
+            sort: HistorySort::Date,
+            first_visible_index: 0,
+            message: None,
+        }
+    }
+
+    fn get_game_records(&self) -> Vec<&GameRecord> {
+        self.game_history.select(self.game_mode, self.sort)
+    }
+
+    fn handle_key_press(&mut self, key: Key) -> Option<ScreenResult> {
+        self.message = None;
+        match key {
+            Key::Up => self.first_visible_index = self.first_visible_index.saturating_sub(1),
+            Key::Down => {
+                let nr_game_records = self.get_game_records().len();
+                if self.first_visible_index + GAME_HISTORY_VISIBLE_ITEMS < nr_game_records {
+                    self.first_visible_index += 1;
+                }
+            }
+            Key::Alphanumeric('S') => {
+                self.sort = self.sort.next();
+                self.first_visible_index = 0;
+            }
+            Key::Alphanumeric('F') => {
+                self.game_mode = get_next_game_mode(self.game_mode);
+                self.first_visible_index = 0;
+            }
+            Key::Alphanumeric('E') => self.export_csv(),
+            Key::Escape => return Some(ScreenResult::ReturnToMainMenu),
+            _ => {}
+        }
+        None
+    }
+
+    fn export_csv(&mut self) {
+        let message = match self
+            .game_history_repository
+            .export_csv(&self.get_game_records())
+        {
+            Ok(file_name) => format!("Exported to {file_name}"),
+            Err(e) => {
+                eprintln!("Failed to export history: {e}");
+                "Failed to export".to_string()
+            }
+        };
+        self.message = Some(message);
+    }
+
+    fn get_title(&self) -> String {
+        let game_mode = self.game_mode.map_or("ALL".to_string(), |game_mode| {
+            game_mode.to_string().to_uppercase()
+        });
+        format!("HISTORY  {game_mode}  BY {}", self.sort.display_text())
+    }
+}
+
+/// Returns the mode after the given one, no mode (all games) after the last one.
+fn get_next_game_mode(game_mode: Option<GameMode>) -> Option<GameMode> {
+    match game_mode {
+        None => GameMode::iter().next(),
+        Some(game_mode) => GameMode::iter()
+            .skip_while(|mode| *mode != game_mode)
+            .nth(1),
+    }
+}
+
+fn format_game_record(game_record: &GameRecord) -> String {
+    let date_time = &game_record.date_time;
+    let seconds = game_record.duration.as_secs();
+    format!(
+        "{:8} {:8} {:06} {:4} {:2} {:>5}",
+        format!(
+            "{:02}-{:02}-{:02}",
+            date_time.year % 100,
+            date_time.month,
+            date_time.day
+        ),
+        game_record.game_mode.to_string().to_uppercase(),
+        game_record.score,
+        game_record.lines,
+        game_record.level + 1,
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    )
+}
+
+impl Screen for HistoryScreen {
+    fn update(&mut self, _delta_time: Duration) {
+        // No updates needed
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+        display.draw_text(
+            &self.get_title(),
+            GAME_HISTORY_X,
+            HIGH_SCORES_Y,
+            Color::WHITE,
+        )?;
+
+        let header = format!(
+            "{:8} {:8} {:6} {:>4} {:2} {:>5}",
+            "DATE", "MODE", "SCORE", "LINE", "LV", "TIME"
+        );
+        display.draw_text(
+            &header,
+            GAME_HISTORY_X,
+            HIGH_SCORES_Y + HIGH_SCORES_LINE_HEIGHT,
+            Color::WHITE,
+        )?;
+
+        let game_records = self.get_game_records();
+        if game_records.is_empty() {
+            display.draw_text(
+                "No games yet",
+                GAME_HISTORY_X,
+                HIGH_SCORES_Y + 2 * HIGH_SCORES_LINE_HEIGHT,
+                Color::WHITE,
+            )?;
+        }
+
+        let visible_game_records = game_records
+            .iter()
+            .skip(self.first_visible_index)
+            .take(GAME_HISTORY_VISIBLE_ITEMS);
+        for (row, game_record) in visible_game_records.enumerate() {
+            let y = HIGH_SCORES_Y + (row as u32 + 2) * HIGH_SCORES_LINE_HEIGHT;
+            display.draw_text(
+                &format_game_record(game_record),
+                GAME_HISTORY_X,
+                y,
+                Color::WHITE,
+            )?;
+        }
+
+        let footer = self
+            .message
+            .as_deref()
+            .unwrap_or("S SORT  F FILTER  E CSV  ESC RETURN");
+        display.draw_text(footer, GAME_HISTORY_X, 370, Color::WHITE)?;
+
+        display.present()?;
+        Ok(())
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(key) => {
+                    if let Some(result) = self.handle_key_press(*key) {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(_) => {}
+            }
+        }
+        ScreenResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::DateTime;
+    use crate::graphics::MockDisplay;
+    use crate::statistics::MockGameHistoryRepository;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn draw_lists_games_newest_first() {
+        // Arrange
+        let (mut sut, _) = create_test_history_screen();
+        let mut display = MockDisplay::new();
+
+        // Act
+        sut.draw(&mut display).unwrap();
+
+        // Assert
+        let texts = get_drawn_texts(&display);
+        assert_eq!(texts[0], "HISTORY  ALL  BY DATE");
+        assert_eq!(texts[2], "26-10-18 PUZZLE   000040    2  1  0:08");
+        assert_eq!(texts[3], "26-10-18 COOP     003000   30  2  4:00");
+        assert_eq!(texts[4], "26-10-18 MARATHON 012000  120 13 12:34");
+    }
+
+    #[test]
+    fn s_sorts_games_by_score() {
+        // Arrange
+        let (mut sut, _) = create_test_history_screen();
+        let mut display = MockDisplay::new();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('S'))]);
+        sut.draw(&mut display).unwrap();
+
+        // Assert
+        let texts = get_drawn_texts(&display);
+        assert_eq!(texts[0], "HISTORY  ALL  BY SCORE");
+        assert!(texts[2].contains("MARATHON"));
+        assert!(texts[4].contains("PUZZLE"));
+    }
+
+    #[test]
+    fn f_shows_games_of_next_mode_only() {
+        // Arrange
+        let (mut sut, _) = create_test_history_screen();
+        let mut display = MockDisplay::new();
+
+        // Act
+        sut.handle_input(&[
+            InputEvent::KeyPressed(Key::Alphanumeric('F')),
+            InputEvent::KeyPressed(Key::Alphanumeric('F')),
+        ]);
+        sut.draw(&mut display).unwrap();
+
+        // Assert
+        let texts = get_drawn_texts(&display);
+        assert_eq!(texts[0], "HISTORY  COOP  BY DATE");
+        assert!(texts[2].contains("COOP"));
+        assert_eq!(texts[3], "S SORT  F FILTER  E CSV  ESC RETURN");
+    }
+
+    #[test]
+    fn get_next_game_mode_cycles_through_modes_and_all_games() {
+        // Arrange
+        let mut game_mode = None;
+
+        // Act
+        let mut result = Vec::new();
+        for _ in 0..4 {
+            game_mode = get_next_game_mode(game_mode);
+            result.push(game_mode);
+        }
+
+        // Assert
+        assert_eq!(
+            result,
+            vec![
+                Some(GameMode::Marathon),
+                Some(GameMode::Coop),
+                Some(GameMode::Puzzle),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn e_exports_listed_games_to_csv() {
+        // Arrange
+        let (mut sut, exported_csv) = create_test_history_screen();
+        let mut display = MockDisplay::new();
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('F'))]);
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('E'))]);
+        sut.draw(&mut display).unwrap();
+
+        // Assert
+        let exported_csv = exported_csv.borrow().clone().unwrap();
+        assert_eq!(exported_csv.lines().count(), 2);
+        assert!(exported_csv.contains(",marathon,12000,"));
+        let texts = get_drawn_texts(&display);
+        assert_eq!(texts.last().unwrap(), "Exported to history.csv");
+    }
+
+    #[test]
+    fn escape_returns_to_main_menu() {
+        // Arrange
+        let (mut sut, _) = create_test_history_screen();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    fn get_drawn_texts(display: &MockDisplay) -> Vec<String> {
+        display
+            .drawn_text
+            .iter()
+            .map(|(text, _, _, _)| text.clone())
+            .collect()
+    }
+
+    /// A history of a marathon, a co-op and a puzzle game, played in that order.
+    fn create_test_history_screen() -> (HistoryScreen, Rc<RefCell<Option<String>>>) {
+        let date_time = DateTime::from_unix_timestamp(1_792_327_385);
+        let game_records = vec![
+            GameRecord {
+                date_time,
+                game_mode: GameMode::Marathon,
+                score: 12000,
+                lines: 120,
+                level: 12,
+                duration: Duration::from_secs(754),
+                replay_name: Some("2026-10-18_12-43-05".to_string()),
+            },
+            GameRecord {
+                date_time,
+                game_mode: GameMode::Coop,
+                score: 3000,
+                lines: 30,
+                level: 1,
+                duration: Duration::from_secs(240),
+                replay_name: None,
+            },
+            GameRecord {
+                date_time,
+                game_mode: GameMode::Puzzle,
+                score: 40,
+                lines: 2,
+                level: 0,
+                duration: Duration::from_secs(8),
+                replay_name: None,
+            },
+        ];
+        let game_history_repository = MockGameHistoryRepository::new(game_records);
+        let exported_csv = game_history_repository.exported_csv.clone();
+        (
+            HistoryScreen::new(Box::new(game_history_repository)),
+            exported_csv,
+        )
+    }
+}
@@ -35,18 +35,41 @@ impl MenuScreen {
 
     fn activate_selected_item(&self) -> ScreenResult {
         match self.menu.get_selected_item() {
+            MenuItem::Continue => ScreenResult::ContinueGame,
             MenuItem::Play => ScreenResult::Play,
+            MenuItem::Practice => ScreenResult::PlayPractice,
+            MenuItem::Puzzles => ScreenResult::ShowPuzzles,
+            MenuItem::Versus => ScreenResult::PlayVersus,
+            MenuItem::Coop => ScreenResult::PlayCoop,
             MenuItem::HighScores => ScreenResult::ShowHighScores,
+            MenuItem::Statistics => ScreenResult::ShowStatistics,
+            MenuItem::History => ScreenResult::ShowHistory,
+            MenuItem::Replays => ScreenResult::ShowReplays,
+            MenuItem::BoardEditor => ScreenResult::EditBoard,
             MenuItem::Quit => ScreenResult::Quit,
         }
     }
 
+    /// Creates the main menu with CONTINUE selected, for when there is a saved game.
+    pub fn with_continue() -> Self {
+        Self {
+            menu: Menu::with_continue(),
+            menu_renderer: GraphicsMenuRenderer::new(),
+        }
+    }
+
     #[cfg(test)]
     pub fn get_menu(&self) -> &Menu {
         &self.menu
     }
 }
 
+impl Default for MenuScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Screen for MenuScreen {
     fn update(&mut self, _delta_time: Duration) {
         // Menu doesn't need time-based updates for now
@@ -68,6 +91,7 @@ impl Screen for MenuScreen {
                         return result;
                     }
                 }
+                InputEvent::KeyReleased(_) => {}
             }
         }
         ScreenResult::Continue
@@ -91,6 +115,18 @@ mod tests {
         assert_eq!(sut.get_menu().get_selected_index(), 0);
     }
 
+    #[test]
+    fn with_continue_enter_continues_saved_game() {
+        // Arrange
+        let mut sut = MenuScreen::with_continue();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ContinueGame);
+    }
+
     #[test]
     fn menu_screen_can_draw() {
         // Arrange
@@ -134,8 +170,8 @@ mod tests {
     }
 
     #[rstest]
-    #[case(Key::Up, 2)] // Should wrap to last item (Quit)
-    #[case(Key::Down, 1)] // Should advance to next item (HighScores)
+    #[case(Key::Up, 10)] // Should wrap to last item (Quit)
+    #[case(Key::Down, 1)] // Should advance to next item (Practice)
     fn handle_input_navigation_keys_change_selection(
         #[case] key: Key,
         #[case] expected_index: usize,
@@ -154,7 +190,15 @@ mod tests {
 
     #[rstest]
     #[case(MenuItem::Play, ScreenResult::Play)]
+    #[case(MenuItem::Practice, ScreenResult::PlayPractice)]
+    #[case(MenuItem::Puzzles, ScreenResult::ShowPuzzles)]
+    #[case(MenuItem::Versus, ScreenResult::PlayVersus)]
+    #[case(MenuItem::Coop, ScreenResult::PlayCoop)]
     #[case(MenuItem::HighScores, ScreenResult::ShowHighScores)]
+    #[case(MenuItem::Statistics, ScreenResult::ShowStatistics)]
+    #[case(MenuItem::History, ScreenResult::ShowHistory)]
+    #[case(MenuItem::Replays, ScreenResult::ShowReplays)]
+    #[case(MenuItem::BoardEditor, ScreenResult::EditBoard)]
     #[case(MenuItem::Quit, ScreenResult::Quit)]
     fn handle_input_enter_activates_selected_item(
         #[case] menu_item: MenuItem,
@@ -179,7 +223,15 @@ mod tests {
 
     #[rstest]
     #[case(MenuItem::Play, ScreenResult::Play)]
+    #[case(MenuItem::Practice, ScreenResult::PlayPractice)]
+    #[case(MenuItem::Puzzles, ScreenResult::ShowPuzzles)]
+    #[case(MenuItem::Versus, ScreenResult::PlayVersus)]
+    #[case(MenuItem::Coop, ScreenResult::PlayCoop)]
     #[case(MenuItem::HighScores, ScreenResult::ShowHighScores)]
+    #[case(MenuItem::Statistics, ScreenResult::ShowStatistics)]
+    #[case(MenuItem::History, ScreenResult::ShowHistory)]
+    #[case(MenuItem::Replays, ScreenResult::ShowReplays)]
+    #[case(MenuItem::BoardEditor, ScreenResult::EditBoard)]
     #[case(MenuItem::Quit, ScreenResult::Quit)]
     fn handle_input_space_activates_selected_item(
         #[case] menu_item: MenuItem,
@@ -235,7 +287,15 @@ mod tests {
         // Arrange
         let mut sut = MenuScreen::new();
         let input_events = vec![
+            InputEvent::KeyPressed(Key::Down), // Move to Practice
+            InputEvent::KeyPressed(Key::Down), // Move to Puzzles
+            InputEvent::KeyPressed(Key::Down), // Move to Versus
+            InputEvent::KeyPressed(Key::Down), // Move to Co-op
             InputEvent::KeyPressed(Key::Down), // Move to HighScores
+            InputEvent::KeyPressed(Key::Down), // Move to Statistics
+            InputEvent::KeyPressed(Key::Down), // Move to History
+            InputEvent::KeyPressed(Key::Down), // Move to Replays
+            InputEvent::KeyPressed(Key::Down), // Move to Board editor
             InputEvent::KeyPressed(Key::Down), // Move to Quit
         ];
 
@@ -1,13 +1,31 @@
+mod board_editor_screen;
 mod enter_high_score_screen;
 mod game_screen;
 mod high_scores_screen;
+mod history_screen;
 mod menu_screen;
+mod network_versus_screen;
+mod puzzle_screen;
+mod puzzle_select_screen;
+mod replay_list_screen;
+mod replay_screen;
 mod screen;
 mod screen_result;
+mod statistics_screen;
+mod versus_screen;
 
+pub use board_editor_screen::BoardEditorScreen;
 pub use enter_high_score_screen::EnterHighScoreScreen;
 pub use game_screen::GameScreen;
 pub use high_scores_screen::HighScoresScreen;
+pub use history_screen::HistoryScreen;
 pub use menu_screen::MenuScreen;
+pub use network_versus_screen::NetworkVersusScreen;
+pub use puzzle_screen::PuzzleScreen;
+pub use puzzle_select_screen::PuzzleSelectScreen;
+pub use replay_list_screen::ReplayListScreen;
+pub use replay_screen::ReplayScreen;
 pub use screen::Screen;
 pub use screen_result::ScreenResult;
+pub use statistics_screen::StatisticsScreen;
+pub use versus_screen::VersusScreen;
@@ -0,0 +1,337 @@
+use crate::common::Dimensions;
+use crate::constants::*;
+use crate::events::EventQueue;
+use crate::game_logic::Game;
+use crate::game_logic::Playfield;
+use crate::graphics::BoardLayout;
+use crate::graphics::Color;
+use crate::graphics::Display;
+use crate::graphics::GraphicsHudRenderer;
+use crate::graphics::GraphicsPlayfieldRenderer;
+use crate::gui::GameInput;
+use crate::high_scores::HighScoreManager;
+use crate::input::InputEvent;
+use crate::input::Key;
+use crate::network::{try_receive_seed, NetworkMessage, Transport};
+use crate::network::{NetworkVersus, NetworkVersusState, TcpTransport};
+use crate::screens::game_screen::translate_playing_key;
+use crate::screens::Screen;
+use crate::screens::ScreenResult;
+use crate::tetromino::RandomTetrominoGenerator;
+use std::collections::HashSet;
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::time::Duration;
+
+type OnlineVersus = NetworkVersus<
+    GraphicsPlayfieldRenderer,
+    GraphicsHudRenderer,
+    RandomTetrominoGenerator,
+    TcpTransport,
+>;
+
+enum Connection {
+    WaitingForPeer(TcpListener),
+    WaitingForSeed(TcpTransport),
+    Playing(Box<OnlineVersus>),
+    Failed(String),
+}
+
+/// Versus game against a peer over TCP. The host waits for a peer to connect and sends it the
+/// seed, so both players get the same tetrominoes.
+pub struct NetworkVersusScreen {
+    connection: Connection,
+    high_score_manager: Option<HighScoreManager>,
+    held_keys: HashSet<Key>,
+}
+
+impl NetworkVersusScreen {
+    pub fn host(port: u16, high_score_manager: HighScoreManager) -> Self {
+        let connection = match TcpListener::bind(("0.0.0.0", port)) {
+            Ok(listener) => match listener.set_nonblocking(true) {
+                Ok(()) => Connection::WaitingForPeer(listener),
+                Err(e) => Connection::Failed(format!("Failed to listen on port {port}: {e}")),
+            },
+            Err(e) => Connection::Failed(format!("Failed to listen on port {port}: {e}")),
+        };
+        Self::with_connection(connection, high_score_manager)
+    }
+
+    pub fn join(address: &str, high_score_manager: HighScoreManager) -> Self {
+        let connection = match TcpTransport::connect(address) {
+            Ok(transport) => Connection::WaitingForSeed(transport),
+            Err(e) => Connection::Failed(e),
+        };
+        Self::with_connection(connection, high_score_manager)
+    }
+
+    fn with_connection(connection: Connection, high_score_manager: HighScoreManager) -> Self {
+        if let Connection::Failed(error) = &connection {
+            eprintln!("{error}");
+        }
+
+        Self {
+            connection,
+            high_score_manager: Some(high_score_manager),
+            held_keys: HashSet::new(),
+        }
+    }
+
+    fn advance_connection(&mut self, connection: Connection) -> Connection {
+        let result = match connection {
+            Connection::WaitingForPeer(listener) => match TcpTransport::try_accept(&listener) {
+                Ok(Some(mut transport)) => {
+                    let seed = rand::random();
+                    transport
+                        .send(&NetworkMessage::Seed(seed))
+                        .map(|_| self.start_game(transport, seed))
+                }
+                Ok(None) => Ok(Connection::WaitingForPeer(listener)),
+                Err(e) => Err(e),
+            },
+            Connection::WaitingForSeed(mut transport) => match try_receive_seed(&mut transport) {
+                Ok(Some(seed)) => Ok(self.start_game(transport, seed)),
+                Ok(None) => Ok(Connection::WaitingForSeed(transport)),
+                Err(e) => Err(e),
+            },
+            other => Ok(other),
+        };
+
+        result.unwrap_or_else(Connection::Failed)
+    }
+
+    fn start_game(&mut self, transport: TcpTransport, seed: u64) -> Connection {
+        let high_score_manager = self
+            .high_score_manager
+            .take()
+            .expect("Network game can only be started once");
+
+        let playfield_dimensions = Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT);
+        let event_queue = Arc::new(EventQueue::new());
+        let playfield = Playfield::new(
+            playfield_dimensions,
+            RandomTetrominoGenerator::with_seed(seed),
+            event_queue.clone(),
+        );
+        let local_layout = BoardLayout::versus_left();
+        let mut game = Game::new(
+            playfield,
+            GraphicsPlayfieldRenderer::with_layout(local_layout),
+            GraphicsHudRenderer::with_layout(local_layout),
+            event_queue,
+            high_score_manager,
+        );
+        game.start_level(0);
+
+        let remote_layout = BoardLayout::versus_right();
+        Connection::Playing(Box::new(NetworkVersus::new(
+            game,
+            transport,
+            GraphicsPlayfieldRenderer::with_layout(remote_layout),
+            GraphicsHudRenderer::with_layout(remote_layout),
+            seed,
+        )))
+    }
+
+    fn update_held_inputs(&mut self) {
+        if let Connection::Playing(network_versus) = &mut self.connection {
+            let held_inputs: Vec<GameInput> = self
+                .held_keys
+                .iter()
+                .filter_map(|key| translate_playing_key(*key))
+                .collect();
+            network_versus.set_held_inputs(&held_inputs);
+        }
+    }
+
+    fn handle_key_pressed(&mut self, key: Key) -> ScreenResult {
+        match &mut self.connection {
+            Connection::Playing(network_versus)
+                if *network_versus.get_state() == NetworkVersusState::Playing =>
+            {
+                if let Some(game_input) = translate_playing_key(key) {
+                    network_versus.handle_input(game_input);
+                }
+                ScreenResult::Continue
+            }
+            Connection::Playing(_) | Connection::Failed(_) => match key {
+                Key::Space | Key::Enter => ScreenResult::ReturnToMainMenu,
+                _ => ScreenResult::Continue,
+            },
+            _ => ScreenResult::Continue,
+        }
+    }
+
+    fn get_status_text(&self) -> Option<String> {
+        match &self.connection {
+            Connection::WaitingForPeer(listener) => {
+                let port = listener.local_addr().map(|a| a.port()).unwrap_or_default();
+                Some(format!("WAITING FOR PLAYER ON PORT {port}"))
+            }
+            Connection::WaitingForSeed(_) => Some("CONNECTING...".to_string()),
+            Connection::Failed(_) => Some("CONNECTION FAILED".to_string()),
+            Connection::Playing(network_versus) => match network_versus.get_state() {
+                NetworkVersusState::Playing => None,
+                NetworkVersusState::Won => Some("YOU WIN".to_string()),
+                NetworkVersusState::Lost => Some("YOU LOSE".to_string()),
+                NetworkVersusState::Draw => Some("DRAW".to_string()),
+                NetworkVersusState::ConnectionLost(_) => Some("CONNECTION LOST".to_string()),
+            },
+        }
+    }
+
+    #[cfg(test)]
+    fn get_listening_port(&self) -> Option<u16> {
+        match &self.connection {
+            Connection::WaitingForPeer(listener) => Some(listener.local_addr().unwrap().port()),
+            _ => None,
+        }
+    }
+}
+
+impl Screen for NetworkVersusScreen {
+    fn update(&mut self, delta_time: Duration) {
+        let connection = std::mem::replace(&mut self.connection, Connection::Failed(String::new()));
+        self.connection = self.advance_connection(connection);
+
+        if let Connection::Playing(network_versus) = &mut self.connection {
+            network_versus.update(delta_time);
+        }
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+
+        if let Connection::Playing(network_versus) = &mut self.connection {
+            network_versus.draw_boards(display)?;
+        }
+
+        if let Some(text) = self.get_status_text() {
+            let text_width = text.len() as u32 * CHAR_WIDTH;
+            let x = (WINDOW_WIDTH_IN_BLOCKS * BLOCK_SIZE).saturating_sub(text_width) / 2;
+            display.draw_text(&text, x, VERSUS_RESULT_OFFSET_Y, Color::WHITE)?;
+        }
+
+        display.present()
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(Key::Escape) => return ScreenResult::ReturnToMainMenu,
+                InputEvent::KeyPressed(key) => {
+                    self.held_keys.insert(*key);
+                    self.update_held_inputs();
+                    let result = self.handle_key_pressed(*key);
+                    if result != ScreenResult::Continue {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(key) => {
+                    self.held_keys.remove(key);
+                    self.update_held_inputs();
+                }
+            }
+        }
+
+        ScreenResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::MockDisplay;
+    use crate::high_scores::MockHighScoresRepository;
+    use std::time::Instant;
+
+    #[test]
+    fn host_accepts_peer_and_sends_seed() {
+        // Arrange
+        let mut sut = NetworkVersusScreen::host(0, create_test_high_score_manager());
+        let port = sut.get_listening_port().unwrap();
+        let mut peer = TcpTransport::connect(&format!("127.0.0.1:{port}")).unwrap();
+
+        // Act
+        let start = Instant::now();
+        while !matches!(sut.connection, Connection::Playing(_)) {
+            assert!(start.elapsed() < Duration::from_secs(5), "Timed out");
+            sut.update(Duration::from_millis(1));
+        }
+
+        // Assert
+        let mut seed = None;
+        while seed.is_none() {
+            assert!(start.elapsed() < Duration::from_secs(5), "Timed out");
+            seed = try_receive_seed(&mut peer).unwrap();
+        }
+    }
+
+    #[test]
+    fn join_without_host_fails() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        drop(listener);
+
+        // Act
+        let sut = NetworkVersusScreen::join(&address, create_test_high_score_manager());
+
+        // Assert
+        assert!(matches!(sut.connection, Connection::Failed(_)));
+    }
+
+    #[test]
+    fn draw_when_connection_failed_shows_status() {
+        // Arrange
+        let mut sut = create_failed_screen();
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let status_drawn = display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text == "CONNECTION FAILED");
+        assert!(status_drawn);
+    }
+
+    #[test]
+    fn handle_input_enter_when_connection_failed_returns_to_main_menu() {
+        // Arrange
+        let mut sut = create_failed_screen();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn handle_input_escape_returns_to_main_menu() {
+        // Arrange
+        let mut sut = NetworkVersusScreen::host(0, create_test_high_score_manager());
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    fn create_failed_screen() -> NetworkVersusScreen {
+        NetworkVersusScreen::with_connection(
+            Connection::Failed("Test".to_string()),
+            create_test_high_score_manager(),
+        )
+    }
+
+    fn create_test_high_score_manager() -> HighScoreManager {
+        HighScoreManager::new(Box::new(MockHighScoresRepository::empty()))
+    }
+}
@@ -0,0 +1,407 @@
+use crate::common::DateTime;
+use crate::common::Dimensions;
+use crate::constants::*;
+use crate::events::EventQueue;
+use crate::game_logic::{Game, GameMode, GameState, Playfield};
+use crate::graphics::{
+    BoardLayout, Color, Display, GraphicsHudRenderer, GraphicsPlayfieldRenderer,
+};
+use crate::high_scores::{HighScoreManager, NullHighScoresRepository};
+use crate::input::{InputEvent, Key};
+use crate::puzzle::{Puzzle, PuzzleRepository};
+use crate::screens::game_screen::translate_playing_key;
+use crate::screens::{Screen, ScreenResult};
+use crate::statistics::{GameHistoryRepository, GameRecord, LifetimeStatisticsRepository};
+use crate::tetromino::SequenceTetrominoGenerator;
+use std::sync::Arc;
+use std::time::Duration;
+
+const PUZZLE_STATUS_Y: u32 = HUD_TEXT_OFFSET_Y + 5 * HUD_TEXT_LINE_HEIGHT;
+
+type PuzzleGame = Game<GraphicsPlayfieldRenderer, GraphicsHudRenderer, SequenceTetrominoGenerator>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PuzzleState {
+    Playing,
+    Solved,
+    Failed,
+}
+
+/// Plays a puzzle until its goal is reached or its pieces are used up. A solved puzzle is marked
+/// as solved in the repository, R starts the puzzle over. Every attempt that ends is added to the
+/// lifetime statistics.
+pub struct PuzzleScreen {
+    /// Where the puzzle is marked as solved, not set for puzzles that are tried out.
+    puzzle_repository: Option<Box<dyn PuzzleRepository>>,
+    /// Not set for puzzles that are tried out either.
+    lifetime_statistics_repository: Option<Box<dyn LifetimeStatisticsRepository>>,
+    game_history_repository: Option<Box<dyn GameHistoryRepository>>,
+    name: String,
+    puzzle: Puzzle,
+    game: PuzzleGame,
+    state: PuzzleState,
+    unsimulated_time: Duration,
+}
+
+impl PuzzleScreen {
+    pub fn new(
+        puzzle_repository: Box<dyn PuzzleRepository>,
+        lifetime_statistics_repository: Box<dyn LifetimeStatisticsRepository>,
+        game_history_repository: Box<dyn GameHistoryRepository>,
+        name: String,
+        puzzle: Puzzle,
+    ) -> Self {
+        let game = create_game(&puzzle);
+        Self {
+            puzzle_repository: Some(puzzle_repository),
+            lifetime_statistics_repository: Some(lifetime_statistics_repository),
+            game_history_repository: Some(game_history_repository),
+            name,
+            puzzle,
+            game,
+            state: PuzzleState::Playing,
+            unsimulated_time: Duration::ZERO,
+        }
+    }
+
+    /// Plays a puzzle that isn't in the repository, like one that is being edited.
+    pub fn try_out(puzzle: Puzzle) -> Self {
+        Self {
+            puzzle_repository: None,
+            lifetime_statistics_repository: None,
+            game_history_repository: None,
+            name: String::new(),
+            game: create_game(&puzzle),
+            puzzle,
+            state: PuzzleState::Playing,
+            unsimulated_time: Duration::ZERO,
+        }
+    }
+
+    fn restart(&mut self) {
+        self.game = create_game(&self.puzzle);
+        self.state = PuzzleState::Playing;
+        self.unsimulated_time = Duration::ZERO;
+    }
+
+    fn update_game_tick(&mut self) {
+        // Events of tetrominoes locked before this update are handled at the start of it, so
+        // only then it is known whether they reached the goal
+        let nr_locked_tetrominoes = self.game.get_nr_locked_tetrominoes() as usize;
+        let events = self.game.update(Duration::from_millis(GAME_TICK_MS));
+
+        if self
+            .puzzle
+            .goal
+            .is_reached(self.game.get_total_lines_cleared(), &events)
+        {
+            self.state = PuzzleState::Solved;
+            if let Some(puzzle_repository) = &self.puzzle_repository {
+                if let Err(e) = puzzle_repository.mark_solved(&self.name) {
+                    eprintln!("Failed to mark puzzle as solved: {e}");
+                }
+            }
+        } else if *self.game.get_game_state() == GameState::GameOver
+            || nr_locked_tetrominoes >= self.puzzle.pieces.len()
+        {
+            self.state = PuzzleState::Failed;
+        }
+
+        if self.state != PuzzleState::Playing {
+            self.add_to_lifetime_statistics();
+            self.add_to_game_history();
+        }
+    }
+
+    fn add_to_lifetime_statistics(&self) {
+        if let Some(lifetime_statistics_repository) = &self.lifetime_statistics_repository {
+            if let Err(e) = lifetime_statistics_repository.add_game(
+                GameMode::Puzzle,
+                self.game.get_score(),
+                self.game.get_statistics(),
+            ) {
+                eprintln!("Failed to update statistics: {e}");
+            }
+        }
+    }
+
+    fn add_to_game_history(&self) {
+        if let Some(game_history_repository) = &self.game_history_repository {
+            let game_record = GameRecord {
+                date_time: DateTime::now(),
+                game_mode: GameMode::Puzzle,
+                score: self.game.get_score(),
+                lines: self.game.get_total_lines_cleared(),
+                level: self.game.get_level(),
+                duration: self.game.get_statistics().play_time,
+                replay_name: None,
+            };
+            if let Err(e) = game_history_repository.append(&game_record) {
+                eprintln!("Failed to add game to history: {e}");
+            }
+        }
+    }
+
+    fn get_nr_pieces_left(&self) -> usize {
+        self.puzzle
+            .pieces
+            .len()
+            .saturating_sub(self.game.get_nr_locked_tetrominoes() as usize)
+    }
+
+    fn handle_key_press(&mut self, key: Key) -> Option<ScreenResult> {
+        match (key, self.state) {
+            (Key::Escape, _) => return Some(ScreenResult::ShowPuzzles),
+            (Key::Alphanumeric('R'), _) => self.restart(),
+            (Key::Enter | Key::Space, PuzzleState::Solved | PuzzleState::Failed) => {
+                return Some(ScreenResult::ShowPuzzles);
+            }
+            (_, PuzzleState::Playing) => {
+                if let Some(input) = translate_playing_key(key) {
+                    self.game.handle_input(input);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn get_status_lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            self.puzzle.title.clone(),
+            self.puzzle.goal.display_text(),
+            format!("PIECES {}", self.get_nr_pieces_left()),
+        ];
+        match self.state {
+            PuzzleState::Playing => lines.push("R RETRY".to_string()),
+            PuzzleState::Solved => lines.push("SOLVED!".to_string()),
+            PuzzleState::Failed => lines.push("FAILED, R RETRY".to_string()),
+        }
+        lines
+    }
+}
+
+impl Screen for PuzzleScreen {
+    fn update(&mut self, delta_time: Duration) {
+        let tick_duration = Duration::from_millis(GAME_TICK_MS);
+        self.unsimulated_time += delta_time;
+        while self.unsimulated_time >= tick_duration && self.state == PuzzleState::Playing {
+            self.unsimulated_time -= tick_duration;
+            self.update_game_tick();
+        }
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+        self.game.draw_board(display)?;
+
+        for (i, line) in self.get_status_lines().iter().enumerate() {
+            let y = PUZZLE_STATUS_Y + (i as u32) * HUD_TEXT_LINE_HEIGHT;
+            display.draw_text(line, HUD_TEXT_OFFSET_X, y, Color::WHITE)?;
+        }
+
+        display.present()
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(key) => {
+                    if let Some(result) = self.handle_key_press(*key) {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(_) => {}
+            }
+        }
+        ScreenResult::Continue
+    }
+}
+
+fn create_game(puzzle: &Puzzle) -> PuzzleGame {
+    let event_queue = Arc::new(EventQueue::new());
+    let mut playfield = Playfield::new(
+        Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT),
+        SequenceTetrominoGenerator::new(puzzle.pieces.clone()),
+        event_queue.clone(),
+    );
+    playfield.set_grid(puzzle.grid.clone());
+    let layout = BoardLayout {
+        show_statistics: false, // The status lines are drawn where the panel would be
+        ..BoardLayout::single_player()
+    };
+    let mut game = Game::new(
+        playfield,
+        GraphicsPlayfieldRenderer::with_layout(layout),
+        GraphicsHudRenderer::with_layout(layout),
+        event_queue,
+        HighScoreManager::new(Box::new(NullHighScoresRepository)),
+    );
+    game.start_level(0);
+    game
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::PlayfieldGrid;
+    use crate::puzzle::{MockPuzzleRepository, PuzzleGoal};
+    use crate::statistics::{MockGameHistoryRepository, MockLifetimeStatisticsRepository};
+    use crate::tetromino::TetrominoType;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn perfect_clear_solves_puzzle_and_marks_it_solved() {
+        // Arrange
+        let (mut sut, solved) = create_test_puzzle_screen(PuzzleGoal::PerfectClear);
+
+        // Act
+        press_keys(&mut sut, &[Key::Right, Key::Space]);
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Assert
+        assert_eq!(sut.state, PuzzleState::Solved);
+        assert_eq!(*solved.borrow(), vec!["test".to_string()]);
+    }
+
+    #[test]
+    fn using_all_pieces_without_reaching_goal_fails_puzzle() {
+        // Arrange
+        let (mut sut, solved) = create_test_puzzle_screen(PuzzleGoal::PerfectClear);
+
+        // Act
+        press_keys(&mut sut, &[Key::Left, Key::Space]);
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Assert
+        assert_eq!(sut.state, PuzzleState::Failed);
+        assert!(solved.borrow().is_empty());
+    }
+
+    #[test]
+    fn ended_puzzle_is_added_to_lifetime_statistics() {
+        // Arrange
+        let (mut sut, _) = create_test_puzzle_screen(PuzzleGoal::PerfectClear);
+        let lifetime_statistics_repository = MockLifetimeStatisticsRepository::new();
+        let lifetime_statistics = lifetime_statistics_repository.lifetime_statistics.clone();
+        sut.lifetime_statistics_repository = Some(Box::new(lifetime_statistics_repository));
+
+        // Act
+        press_keys(&mut sut, &[Key::Right, Key::Space]);
+        sut.update(Duration::from_millis(GAME_TICK_MS * 3));
+
+        // Assert
+        let lifetime_statistics = lifetime_statistics.borrow();
+        assert_eq!(lifetime_statistics.nr_games, 1);
+        assert_eq!(lifetime_statistics.nr_lines, 2);
+        assert!(lifetime_statistics
+            .personal_bests
+            .contains_key(&GameMode::Puzzle));
+    }
+
+    #[test]
+    fn ended_puzzle_is_added_to_game_history() {
+        // Arrange
+        let (mut sut, _) = create_test_puzzle_screen(PuzzleGoal::PerfectClear);
+        let game_history_repository = MockGameHistoryRepository::default();
+        let game_records = game_history_repository.game_records.clone();
+        sut.game_history_repository = Some(Box::new(game_history_repository));
+
+        // Act
+        press_keys(&mut sut, &[Key::Left, Key::Space]);
+        sut.update(Duration::from_millis(GAME_TICK_MS * 3));
+
+        // Assert
+        let game_records = game_records.borrow();
+        assert_eq!(game_records.len(), 1);
+        assert_eq!(game_records[0].game_mode, GameMode::Puzzle);
+        assert_eq!(game_records[0].lines, 0);
+    }
+
+    #[test]
+    fn t_spin_double_solves_puzzle() {
+        // Arrange
+        let rows = ["JJJJ......", "JJJ...JJJJ", "JJJJ.JJJJJ"];
+        let (mut sut, _) =
+            create_test_puzzle_screen_with(PuzzleGoal::TSpinDouble, TetrominoType::T, &rows);
+
+        // Act
+        press_keys(
+            &mut sut,
+            &[Key::Up, Key::Alphanumeric('S'), Key::Up, Key::Space],
+        );
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Assert
+        assert_eq!(sut.state, PuzzleState::Solved);
+    }
+
+    #[test]
+    fn r_restarts_failed_puzzle() {
+        // Arrange
+        let (mut sut, _) = create_test_puzzle_screen(PuzzleGoal::PerfectClear);
+        press_keys(&mut sut, &[Key::Left, Key::Space]);
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Act
+        press_keys(&mut sut, &[Key::Alphanumeric('R')]);
+
+        // Assert
+        assert_eq!(sut.state, PuzzleState::Playing);
+        assert_eq!(sut.get_nr_pieces_left(), 1);
+    }
+
+    #[test]
+    fn enter_after_puzzle_ends_shows_puzzles() {
+        // Arrange
+        let (mut sut, _) = create_test_puzzle_screen(PuzzleGoal::PerfectClear);
+        press_keys(&mut sut, &[Key::Right, Key::Space]);
+        sut.update(Duration::from_millis(GAME_TICK_MS));
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ShowPuzzles);
+    }
+
+    fn press_keys(sut: &mut PuzzleScreen, keys: &[Key]) {
+        for key in keys {
+            sut.handle_input(&[InputEvent::KeyPressed(*key)]);
+        }
+    }
+
+    /// A puzzle with one O piece that clears the two bottom rows when dropped one column to the
+    /// right of where it spawns.
+    fn create_test_puzzle_screen(goal: PuzzleGoal) -> (PuzzleScreen, Rc<RefCell<Vec<String>>>) {
+        create_test_puzzle_screen_with(goal, TetrominoType::O, &["IIIII..III", "IIIII..III"])
+    }
+
+    fn create_test_puzzle_screen_with(
+        goal: PuzzleGoal,
+        piece: TetrominoType,
+        bottom_rows: &[&str],
+    ) -> (PuzzleScreen, Rc<RefCell<Vec<String>>>) {
+        let empty_row = ".".repeat(PLAYFIELD_WIDTH as usize);
+        let mut rows = vec![empty_row.as_str(); PLAYFIELD_HEIGHT as usize - bottom_rows.len()];
+        rows.extend_from_slice(bottom_rows);
+        let puzzle = Puzzle {
+            title: "TEST".to_string(),
+            goal,
+            pieces: vec![piece],
+            grid: PlayfieldGrid::from_rows(&rows).unwrap(),
+        };
+        let puzzle_repository = MockPuzzleRepository::default();
+        let solved = puzzle_repository.solved.clone();
+        let sut = PuzzleScreen::new(
+            Box::new(puzzle_repository),
+            Box::new(MockLifetimeStatisticsRepository::new()),
+            Box::new(MockGameHistoryRepository::default()),
+            "test".to_string(),
+            puzzle,
+        );
+        (sut, solved)
+    }
+}
@@ -0,0 +1,245 @@
+use crate::common::Position;
+use crate::constants::*;
+use crate::graphics::{Color, Display};
+use crate::input::{InputEvent, Key};
+use crate::puzzle::{Puzzle, PuzzleRepository};
+use crate::screens::{Screen, ScreenResult};
+use crate::tetromino::TetrominoType;
+use std::time::Duration;
+
+/// A puzzle that can be selected, with the name it is stored under in the repository.
+struct PuzzleEntry {
+    name: String,
+    puzzle: Puzzle,
+    is_solved: bool,
+}
+
+/// Lists the puzzles by title and marks the ones that have been solved, so one can be picked to
+/// play.
+pub struct PuzzleSelectScreen {
+    entries: Vec<PuzzleEntry>,
+    selected_index: usize,
+}
+
+impl PuzzleSelectScreen {
+    pub fn new(puzzle_repository: &dyn PuzzleRepository) -> Self {
+        let names = puzzle_repository.list().unwrap_or_else(|e| {
+            eprintln!("Failed to list puzzles: {e}");
+            Vec::new()
+        });
+        let solved_names = puzzle_repository.get_solved().unwrap_or_else(|e| {
+            eprintln!("Failed to read solved puzzles: {e}");
+            Vec::new()
+        });
+        let entries = names
+            .into_iter()
+            .filter_map(|name| match puzzle_repository.load(&name) {
+                Ok(puzzle) => Some(PuzzleEntry {
+                    is_solved: solved_names.contains(&name),
+                    name,
+                    puzzle,
+                }),
+                Err(e) => {
+                    eprintln!("Failed to load puzzle: {e}");
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            entries,
+            selected_index: 0,
+        }
+    }
+
+    fn handle_key_press(&mut self, key: Key) -> Option<ScreenResult> {
+        match key {
+            Key::Up if self.selected_index > 0 => self.selected_index -= 1,
+            Key::Down if self.selected_index + 1 < self.entries.len() => self.selected_index += 1,
+            Key::Enter | Key::Space => return self.play_selected_puzzle(),
+            Key::Escape => return Some(ScreenResult::ReturnToMainMenu),
+            _ => {}
+        }
+        None
+    }
+
+    fn play_selected_puzzle(&self) -> Option<ScreenResult> {
+        let entry = self.entries.get(self.selected_index)?;
+        Some(ScreenResult::PlayPuzzle(
+            entry.name.clone(),
+            entry.puzzle.clone(),
+        ))
+    }
+
+    /// Returns the index of the first visible puzzle, scrolled so the selected one is visible.
+    fn get_first_visible_index(&self) -> usize {
+        (self.selected_index + 1).saturating_sub(PUZZLE_LIST_VISIBLE_ITEMS)
+    }
+
+    fn get_header_text(&self) -> String {
+        let nr_solved = self.entries.iter().filter(|entry| entry.is_solved).count();
+        format!("PUZZLES {nr_solved}/{} SOLVED", self.entries.len())
+    }
+}
+
+impl Screen for PuzzleSelectScreen {
+    fn update(&mut self, _delta_time: Duration) {
+        // No updates needed
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+        display.draw_text(
+            &self.get_header_text(),
+            HIGH_SCORES_X,
+            HIGH_SCORES_Y,
+            Color::WHITE,
+        )?;
+
+        if self.entries.is_empty() {
+            display.draw_text(
+                "No puzzles found",
+                HIGH_SCORES_X,
+                HIGH_SCORES_Y + 2 * HIGH_SCORES_LINE_HEIGHT,
+                Color::WHITE,
+            )?;
+        }
+
+        let first_visible_index = self.get_first_visible_index();
+        let visible_entries = self
+            .entries
+            .iter()
+            .enumerate()
+            .skip(first_visible_index)
+            .take(PUZZLE_LIST_VISIBLE_ITEMS);
+        for (row, (index, entry)) in visible_entries.enumerate() {
+            let y = HIGH_SCORES_Y + (row as u32 + 2) * HIGH_SCORES_LINE_HEIGHT;
+            if index == self.selected_index {
+                let block_position =
+                    Position::new((HIGH_SCORES_X - BLOCK_SIZE * 2) as i32, y as i32);
+                display.draw_block(block_position, TetrominoType::O)?;
+            }
+            display.draw_text(&entry.puzzle.title, HIGH_SCORES_X, y, Color::WHITE)?;
+            if entry.is_solved {
+                display.draw_text("SOLVED", PUZZLE_SOLVED_X, y, Color::WHITE)?;
+            }
+        }
+
+        display.draw_text("Press ESC to return", 150, 370, Color::WHITE)?;
+
+        display.present()?;
+        Ok(())
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(key) => {
+                    if let Some(result) = self.handle_key_press(*key) {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(_) => {}
+            }
+        }
+        ScreenResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::PlayfieldGrid;
+    use crate::graphics::MockDisplay;
+    use crate::puzzle::{MockPuzzleRepository, PuzzleGoal};
+
+    #[test]
+    fn enter_plays_selected_puzzle() {
+        // Arrange
+        let mut sut = create_test_puzzle_select_screen(&[]);
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Down)]);
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(
+            result,
+            ScreenResult::PlayPuzzle("b".to_string(), create_test_puzzle("B"))
+        );
+    }
+
+    #[test]
+    fn enter_without_puzzles_does_nothing() {
+        // Arrange
+        let mut sut = PuzzleSelectScreen::new(&MockPuzzleRepository::default());
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::Continue);
+    }
+
+    #[test]
+    fn escape_returns_to_main_menu() {
+        // Arrange
+        let mut sut = create_test_puzzle_select_screen(&[]);
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn draw_marks_solved_puzzles() {
+        // Arrange
+        let mut sut = create_test_puzzle_select_screen(&["b"]);
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let drawn_texts: Vec<(&str, u32)> = display
+            .drawn_text
+            .iter()
+            .map(|(text, _, y, _)| (text.as_str(), *y))
+            .collect();
+        assert!(drawn_texts.contains(&("PUZZLES 1/2 SOLVED", HIGH_SCORES_Y)));
+        let solved_y = HIGH_SCORES_Y + 3 * HIGH_SCORES_LINE_HEIGHT;
+        assert!(drawn_texts.contains(&("B", solved_y)));
+        assert!(drawn_texts.contains(&("SOLVED", solved_y)));
+        assert_eq!(
+            drawn_texts
+                .iter()
+                .filter(|(text, _)| *text == "SOLVED")
+                .count(),
+            1
+        );
+    }
+
+    fn create_test_puzzle(title: &str) -> Puzzle {
+        Puzzle {
+            title: title.to_string(),
+            goal: PuzzleGoal::PerfectClear,
+            pieces: vec![TetrominoType::O],
+            grid: PlayfieldGrid::from_rows(&["..", "II"]).unwrap(),
+        }
+    }
+
+    fn create_test_puzzle_select_screen(solved_names: &[&str]) -> PuzzleSelectScreen {
+        let puzzle_repository = MockPuzzleRepository::new(vec![
+            ("a".to_string(), create_test_puzzle("A")),
+            ("b".to_string(), create_test_puzzle("B")),
+        ]);
+        for name in solved_names {
+            puzzle_repository.mark_solved(name).unwrap();
+        }
+        PuzzleSelectScreen::new(&puzzle_repository)
+    }
+}
@@ -0,0 +1,208 @@
+use crate::common::Position;
+use crate::constants::*;
+use crate::graphics::{Color, Display};
+use crate::input::{InputEvent, Key};
+use crate::replay::ReplayRepository;
+use crate::screens::{Screen, ScreenResult};
+use crate::tetromino::TetrominoType;
+use std::time::Duration;
+
+/// Lists the saved replays, newest first, so one can be picked to watch.
+pub struct ReplayListScreen {
+    replay_repository: Box<dyn ReplayRepository>,
+    replay_names: Vec<String>,
+    selected_index: usize,
+}
+
+impl ReplayListScreen {
+    pub fn new(replay_repository: Box<dyn ReplayRepository>) -> Self {
+        let replay_names = replay_repository.list().unwrap_or_else(|e| {
+            eprintln!("Failed to list replays: {e}");
+            Vec::new()
+        });
+        Self {
+            replay_repository,
+            replay_names,
+            selected_index: 0,
+        }
+    }
+
+    fn handle_key_press(&mut self, key: Key) -> Option<ScreenResult> {
+        match key {
+            Key::Up if self.selected_index > 0 => self.selected_index -= 1,
+            Key::Down if self.selected_index + 1 < self.replay_names.len() => {
+                self.selected_index += 1
+            }
+            Key::Enter | Key::Space => return self.watch_selected_replay(),
+            Key::Escape => return Some(ScreenResult::ReturnToMainMenu),
+            _ => {}
+        }
+        None
+    }
+
+    fn watch_selected_replay(&self) -> Option<ScreenResult> {
+        let name = self.replay_names.get(self.selected_index)?;
+        match self.replay_repository.load(name) {
+            Ok(replay) => Some(ScreenResult::WatchReplay(replay)),
+            Err(e) => {
+                eprintln!("Failed to load replay: {e}");
+                None
+            }
+        }
+    }
+
+    /// Returns the index of the first visible replay, scrolled so the selected one is visible.
+    fn get_first_visible_index(&self) -> usize {
+        (self.selected_index + 1).saturating_sub(REPLAY_LIST_VISIBLE_ITEMS)
+    }
+}
+
+impl Screen for ReplayListScreen {
+    fn update(&mut self, _delta_time: Duration) {
+        // No updates needed
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+        display.draw_text("REPLAYS", HIGH_SCORES_X, HIGH_SCORES_Y, Color::WHITE)?;
+
+        if self.replay_names.is_empty() {
+            display.draw_text(
+                "No replays yet",
+                HIGH_SCORES_X,
+                HIGH_SCORES_Y + 2 * HIGH_SCORES_LINE_HEIGHT,
+                Color::WHITE,
+            )?;
+        }
+
+        let first_visible_index = self.get_first_visible_index();
+        let visible_names = self
+            .replay_names
+            .iter()
+            .enumerate()
+            .skip(first_visible_index)
+            .take(REPLAY_LIST_VISIBLE_ITEMS);
+        for (row, (index, name)) in visible_names.enumerate() {
+            let y = HIGH_SCORES_Y + (row as u32 + 2) * HIGH_SCORES_LINE_HEIGHT;
+            if index == self.selected_index {
+                let block_position =
+                    Position::new((HIGH_SCORES_X - BLOCK_SIZE * 2) as i32, y as i32);
+                display.draw_block(block_position, TetrominoType::O)?;
+            }
+            display.draw_text(name, HIGH_SCORES_X, y, Color::WHITE)?;
+        }
+
+        display.draw_text("Press ESC to return", 150, 370, Color::WHITE)?;
+
+        display.present()?;
+        Ok(())
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(key) => {
+                    if let Some(result) = self.handle_key_press(*key) {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(_) => {}
+            }
+        }
+        ScreenResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::GameRules;
+    use crate::graphics::MockDisplay;
+    use crate::replay::{MockReplayRepository, Replay};
+
+    #[test]
+    fn enter_watches_selected_replay() {
+        // Arrange
+        let mut sut = create_test_replay_list_screen(3);
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Down)]);
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        // The mock lists the newest replay first, so the second one listed is the second newest
+        assert_eq!(result, ScreenResult::WatchReplay(create_test_replay(1)));
+    }
+
+    #[test]
+    fn enter_without_replays_does_nothing() {
+        // Arrange
+        let mut sut = create_test_replay_list_screen(0);
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::Continue);
+    }
+
+    #[test]
+    fn selection_stays_within_list() {
+        // Arrange
+        let mut sut = create_test_replay_list_screen(2);
+
+        // Act
+        sut.handle_input(&[
+            InputEvent::KeyPressed(Key::Up),
+            InputEvent::KeyPressed(Key::Down),
+            InputEvent::KeyPressed(Key::Down),
+        ]);
+
+        // Assert
+        assert_eq!(sut.selected_index, 1);
+    }
+
+    #[test]
+    fn escape_returns_to_main_menu() {
+        // Arrange
+        let mut sut = create_test_replay_list_screen(1);
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn draw_scrolls_to_selected_replay() {
+        // Arrange
+        let nr_replays = REPLAY_LIST_VISIBLE_ITEMS + 2;
+        let mut sut = create_test_replay_list_screen(nr_replays);
+        sut.selected_index = nr_replays - 1;
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        let drawn_names: Vec<&str> = display
+            .drawn_text
+            .iter()
+            .map(|(text, _, _, _)| text.as_str())
+            .collect();
+        assert!(drawn_names.contains(&"0"));
+        assert!(!drawn_names.contains(&(nr_replays - 1).to_string().as_str()));
+    }
+
+    fn create_test_replay(seed: u64) -> Replay {
+        Replay::new(seed, GameRules::default(), 0)
+    }
+
+    fn create_test_replay_list_screen(nr_replays: usize) -> ReplayListScreen {
+        let replays = (0..nr_replays as u64).map(create_test_replay).collect();
+        ReplayListScreen::new(Box::new(MockReplayRepository::with_replays(replays)))
+    }
+}
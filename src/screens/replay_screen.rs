@@ -0,0 +1,226 @@
+use crate::constants::*;
+use crate::graphics::{Color, Display};
+use crate::input::{InputEvent, Key};
+use crate::replay::{Replay, ReplayPlayer};
+use crate::screens::{Screen, ScreenResult};
+use std::time::Duration;
+
+const REPLAY_STATUS_Y: u32 = HUD_TEXT_OFFSET_Y + 5 * HUD_TEXT_LINE_HEIGHT;
+const REPLAY_HELP_LINES: [&str; 4] = ["SPACE PAUSE", "1 2 4 SPEED", "F STEP", "<- -> SEEK"];
+
+/// Watches a replay with play/pause, 2x and 4x speed, frame stepping while paused and seeking.
+pub struct ReplayScreen {
+    player: ReplayPlayer,
+    paused: bool,
+    speed: u32,
+    /// Playback time that hasn't been turned into game ticks yet.
+    unplayed_time: Duration,
+}
+
+impl ReplayScreen {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            player: ReplayPlayer::new(replay),
+            paused: false,
+            speed: 1,
+            unplayed_time: Duration::ZERO,
+        }
+    }
+
+    fn handle_key_press(&mut self, key: Key) -> Option<ScreenResult> {
+        match key {
+            Key::Escape => return Some(ScreenResult::ShowReplays),
+            Key::Space => self.paused = !self.paused,
+            Key::Alphanumeric('1') => self.speed = 1,
+            Key::Alphanumeric('2') => self.speed = 2,
+            Key::Alphanumeric('4') => self.speed = 4,
+            Key::Alphanumeric('F') if self.paused => self.player.step(),
+            Key::Left => self
+                .player
+                .seek(self.player.get_tick().saturating_sub(REPLAY_SEEK_TICKS)),
+            Key::Right => self.player.seek(self.player.get_tick() + REPLAY_SEEK_TICKS),
+            _ => {}
+        }
+        None
+    }
+
+    fn get_status_text(&self) -> String {
+        let seconds = self.player.get_tick() * GAME_TICK_MS / 1000;
+        let state = if self.player.is_finished() {
+            "END"
+        } else if self.paused {
+            "PAUSED"
+        } else {
+            "PLAY"
+        };
+        format!(
+            "{state} {}X {}:{:02}",
+            self.speed,
+            seconds / 60,
+            seconds % 60
+        )
+    }
+}
+
+impl Screen for ReplayScreen {
+    fn update(&mut self, delta_time: Duration) {
+        if self.paused {
+            return;
+        }
+
+        let tick_duration = Duration::from_millis(GAME_TICK_MS);
+        self.unplayed_time += delta_time * self.speed;
+        while self.unplayed_time >= tick_duration {
+            self.unplayed_time -= tick_duration;
+            self.player.step();
+        }
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+        self.player.draw(display)?;
+
+        display.draw_text(
+            &self.get_status_text(),
+            HUD_TEXT_OFFSET_X,
+            REPLAY_STATUS_Y,
+            Color::WHITE,
+        )?;
+        for (i, line) in REPLAY_HELP_LINES.iter().enumerate() {
+            let y = REPLAY_STATUS_Y + (i as u32 + 2) * HUD_TEXT_LINE_HEIGHT;
+            display.draw_text(line, HUD_TEXT_OFFSET_X, y, Color::WHITE)?;
+        }
+
+        display.present()
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(key) => {
+                    if let Some(result) = self.handle_key_press(*key) {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(_) => {}
+            }
+        }
+        ScreenResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::GameRules;
+    use crate::graphics::MockDisplay;
+    use crate::gui::GameInput;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case('1', 10)]
+    #[case('2', 20)]
+    #[case('4', 40)]
+    fn update_plays_ticks_at_selected_speed(#[case] speed: char, #[case] expected_tick: u64) {
+        // Arrange
+        let mut sut = create_test_replay_screen();
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric(speed))]);
+
+        // Act
+        sut.update(Duration::from_millis(10 * GAME_TICK_MS));
+
+        // Assert
+        assert_eq!(sut.player.get_tick(), expected_tick);
+    }
+
+    #[test]
+    fn update_when_paused_does_not_play() {
+        // Arrange
+        let mut sut = create_test_replay_screen();
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+
+        // Act
+        sut.update(Duration::from_millis(10 * GAME_TICK_MS));
+
+        // Assert
+        assert_eq!(sut.player.get_tick(), 0);
+    }
+
+    #[test]
+    fn step_when_paused_plays_one_tick() {
+        // Arrange
+        let mut sut = create_test_replay_screen();
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Space)]);
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('F'))]);
+
+        // Assert
+        assert_eq!(sut.player.get_tick(), 1);
+    }
+
+    #[test]
+    fn step_when_playing_does_nothing() {
+        // Arrange
+        let mut sut = create_test_replay_screen();
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Alphanumeric('F'))]);
+
+        // Assert
+        assert_eq!(sut.player.get_tick(), 0);
+    }
+
+    #[test]
+    fn seek_forward_and_back_moves_by_seek_ticks() {
+        // Arrange
+        let mut sut = create_test_replay_screen();
+
+        // Act
+        sut.handle_input(&[
+            InputEvent::KeyPressed(Key::Right),
+            InputEvent::KeyPressed(Key::Right),
+            InputEvent::KeyPressed(Key::Left),
+        ]);
+
+        // Assert
+        assert_eq!(sut.player.get_tick(), REPLAY_SEEK_TICKS);
+    }
+
+    #[test]
+    fn escape_shows_replay_list() {
+        // Arrange
+        let mut sut = create_test_replay_screen();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ShowReplays);
+    }
+
+    #[test]
+    fn draw_shows_board_and_status() {
+        // Arrange
+        let mut sut = create_test_replay_screen();
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(display.presented);
+        assert!(display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text == "PLAY 1X 0:00"));
+    }
+
+    fn create_test_replay_screen() -> ReplayScreen {
+        let mut replay = Replay::new(5, GameRules::default(), 0);
+        replay.record_input(2 * REPLAY_SEEK_TICKS, GameInput::Drop);
+        ReplayScreen::new(replay)
+    }
+}
@@ -1,11 +1,27 @@
 use crate::game_logic::GameResult;
+use crate::high_scores::HighScoreTableKey;
+use crate::puzzle::Puzzle;
+use crate::replay::Replay;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ScreenResult {
     Continue,
     ReturnToMainMenu,
     Play,
+    PlayPractice,
+    ContinueGame,
+    PlayVersus,
+    PlayCoop,
     ShowHighScores,
-    EnterHighScore(GameResult),
+    ShowStatistics,
+    ShowHistory,
+    /// The table of the game, its result and the name of its replay, if it was saved.
+    EnterHighScore(HighScoreTableKey, GameResult, Option<String>),
+    ShowReplays,
+    WatchReplay(Replay),
+    ShowPuzzles,
+    /// The name of the puzzle in the repository and the puzzle itself.
+    PlayPuzzle(String, Puzzle),
+    EditBoard,
     Quit,
 }
@@ -0,0 +1,154 @@
+use crate::constants::*;
+use crate::graphics::{Color, Display};
+use crate::high_scores::HighScoresScreenBackground;
+use crate::input::{InputEvent, Key};
+use crate::screens::{Screen, ScreenResult};
+use crate::statistics::{LifetimeStatistics, LifetimeStatisticsRepository};
+use std::time::Duration;
+
+/// Shows the totals and personal bests of all games played to the end.
+pub struct StatisticsScreen {
+    lifetime_statistics: LifetimeStatistics,
+    background: HighScoresScreenBackground,
+}
+
+impl StatisticsScreen {
+    pub fn new(lifetime_statistics_repository: Box<dyn LifetimeStatisticsRepository>) -> Self {
+        let lifetime_statistics = lifetime_statistics_repository.load().unwrap_or_else(|e| {
+            eprintln!("Failed to load statistics: {e}");
+            LifetimeStatistics::new()
+        });
+        Self {
+            lifetime_statistics,
+            background: HighScoresScreenBackground::new(),
+        }
+    }
+
+    fn get_lines(&self) -> Vec<String> {
+        let statistics = &self.lifetime_statistics;
+        let mut lines = vec![
+            format!("GAMES         {}", statistics.nr_games),
+            format!("PLAY TIME     {}", format_play_time(statistics.play_time)),
+            format!("LINES         {}", statistics.nr_lines),
+            format!("TETRISES      {}", statistics.nr_tetrises),
+            format!("BEST COMBO    {}", statistics.best_combo),
+            format!("BEST STREAK   {}", statistics.best_tetris_streak),
+            String::new(),
+            "MODE       SCORE  LINES".to_string(),
+        ];
+        for (game_mode, personal_best) in &statistics.personal_bests {
+            lines.push(format!(
+                "{:8}  {:06}  {:5}",
+                game_mode.to_string().to_uppercase(),
+                personal_best.score,
+                personal_best.lines
+            ));
+        }
+        lines
+    }
+}
+
+/// Formats the play time as hours, minutes and seconds, for example 12:03:45.
+fn format_play_time(play_time: Duration) -> String {
+    let seconds = play_time.as_secs();
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+impl Screen for StatisticsScreen {
+    fn update(&mut self, _delta_time: Duration) {
+        // No updates needed
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+
+        self.background.draw(display)?;
+
+        for (i, line) in self.get_lines().iter().enumerate() {
+            let y = HIGH_SCORES_Y + (i as u32 * HIGH_SCORES_LINE_HEIGHT);
+            display.draw_text(line, HIGH_SCORES_X, y, Color::WHITE)?;
+        }
+
+        display.draw_text("Press ESC to return", 150, 320, Color::WHITE)?;
+
+        display.present()?;
+        Ok(())
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(Key::Escape) => return ScreenResult::ReturnToMainMenu,
+                _ => {}
+            }
+        }
+        ScreenResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{GameMode, GameStatistics};
+    use crate::graphics::MockDisplay;
+    use crate::statistics::MockLifetimeStatisticsRepository;
+    use rstest::rstest;
+
+    #[test]
+    fn draw_displays_totals_and_personal_bests() {
+        // Arrange
+        let repository = MockLifetimeStatisticsRepository::new();
+        let mut game_statistics = GameStatistics::new();
+        game_statistics.handle_tetromino_locked();
+        game_statistics.handle_lines_cleared(4);
+        game_statistics.update(Duration::from_secs(3725));
+        repository.lifetime_statistics.borrow_mut().add_game(
+            GameMode::Marathon,
+            12000,
+            &game_statistics,
+        );
+        let mut sut = StatisticsScreen::new(Box::new(repository));
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(display.cleared);
+        assert!(display.presented);
+        let texts: Vec<&str> = display
+            .drawn_text
+            .iter()
+            .map(|(text, _, _, _)| text.as_str())
+            .collect();
+        assert!(texts.contains(&"GAMES         1"));
+        assert!(texts.contains(&"PLAY TIME     1:02:05"));
+        assert!(texts.contains(&"TETRISES      1"));
+        assert!(texts.contains(&"MARATHON  012000      4"));
+    }
+
+    #[rstest]
+    #[case(InputEvent::KeyPressed(Key::Escape), ScreenResult::ReturnToMainMenu)]
+    #[case(InputEvent::Quit, ScreenResult::Quit)]
+    #[case(InputEvent::KeyPressed(Key::Space), ScreenResult::Continue)]
+    fn handle_input_returns_screen_result(
+        #[case] input_event: InputEvent,
+        #[case] expected_result: ScreenResult,
+    ) {
+        // Arrange
+        let mut sut = StatisticsScreen::new(Box::new(MockLifetimeStatisticsRepository::new()));
+
+        // Act
+        let result = sut.handle_input(&[input_event]);
+
+        // Assert
+        assert_eq!(result, expected_result);
+    }
+}
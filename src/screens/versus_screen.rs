@@ -0,0 +1,382 @@
+use crate::ai::{AiPlayer, HeuristicWeights};
+use crate::common::Dimensions;
+use crate::constants::*;
+use crate::events::EventQueue;
+use crate::game_logic::Game;
+use crate::game_logic::Playfield;
+use crate::game_logic::{VersusMatch, VersusState, NR_VERSUS_PLAYERS};
+use crate::graphics::BoardLayout;
+use crate::graphics::Color;
+use crate::graphics::Display;
+use crate::graphics::GraphicsHudRenderer;
+use crate::graphics::GraphicsPlayfieldRenderer;
+use crate::gui::GameInput;
+use crate::high_scores::HighScoreManager;
+use crate::input::InputEvent;
+use crate::input::Key;
+use crate::screens::game_screen::translate_playing_key;
+use crate::screens::Screen;
+use crate::screens::ScreenResult;
+use crate::tetromino::RandomTetrominoGenerator;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+type VersusGame = Game<GraphicsPlayfieldRenderer, GraphicsHudRenderer, RandomTetrominoGenerator>;
+
+const CPU_PLAYER: usize = 1;
+
+/// Two players on one keyboard. Both games use the same seed, so both players get the same
+/// tetrominoes.
+pub struct VersusScreen {
+    versus_match:
+        VersusMatch<GraphicsPlayfieldRenderer, GraphicsHudRenderer, RandomTetrominoGenerator>,
+    held_keys: HashSet<Key>,
+    cpu_player: Option<AiPlayer>,
+}
+
+impl VersusScreen {
+    pub fn new(seed: u64, high_score_managers: [HighScoreManager; NR_VERSUS_PLAYERS]) -> Self {
+        let [left_high_score_manager, right_high_score_manager] = high_score_managers;
+        let games = [
+            create_game(seed, BoardLayout::versus_left(), left_high_score_manager),
+            create_game(seed, BoardLayout::versus_right(), right_high_score_manager),
+        ];
+
+        Self {
+            versus_match: VersusMatch::new(games, seed),
+            held_keys: HashSet::new(),
+            cpu_player: None,
+        }
+    }
+
+    /// One player against the computer, which plays the right board. The human player uses the
+    /// single player keys.
+    pub fn new_against_cpu(
+        seed: u64,
+        high_score_managers: [HighScoreManager; NR_VERSUS_PLAYERS],
+    ) -> Self {
+        let mut screen = Self::new(seed, high_score_managers);
+        screen.cpu_player = Some(AiPlayer::new(
+            HeuristicWeights::default(),
+            Duration::from_millis(AI_INPUT_INTERVAL_MS),
+        ));
+        screen
+    }
+
+    /// Returns the player the key belongs to and the game input it maps to.
+    fn translate_key_to_player_input(&self, key: Key) -> Option<(usize, GameInput)> {
+        if self.cpu_player.is_some() {
+            translate_playing_key(key).map(|input| (0, input))
+        } else {
+            translate_two_player_key(key)
+        }
+    }
+
+    fn update_cpu_player(&mut self, delta_time: Duration) {
+        if let Some(cpu_player) = &mut self.cpu_player {
+            let playfield_view = self.versus_match.get_playfield_view(CPU_PLAYER);
+            if let Some(input) = cpu_player.update(delta_time, &playfield_view) {
+                self.versus_match.handle_input(CPU_PLAYER, input);
+            }
+        }
+    }
+
+    fn update_held_inputs(&mut self) {
+        for player in 0..NR_VERSUS_PLAYERS {
+            let held_inputs: Vec<GameInput> = self
+                .held_keys
+                .iter()
+                .filter_map(|key| self.translate_key_to_player_input(*key))
+                .filter(|(key_player, _)| *key_player == player)
+                .map(|(_, game_input)| game_input)
+                .collect();
+            self.versus_match.set_held_inputs(player, &held_inputs);
+        }
+    }
+
+    fn handle_key_pressed(&mut self, key: Key) -> ScreenResult {
+        if self.versus_match.get_state() != VersusState::Playing {
+            return match key {
+                Key::Space | Key::Enter => ScreenResult::ReturnToMainMenu,
+                _ => ScreenResult::Continue,
+            };
+        }
+
+        if let Some((player, game_input)) = self.translate_key_to_player_input(key) {
+            self.versus_match.handle_input(player, game_input);
+        }
+
+        ScreenResult::Continue
+    }
+
+    fn draw_result<D: Display + ?Sized>(&self, display: &mut D) -> Result<(), String> {
+        let text = match self.versus_match.get_state() {
+            VersusState::Playing => return Ok(()),
+            VersusState::Won(player) => format!("PLAYER {} WINS", player + 1),
+            VersusState::Draw => "DRAW".to_string(),
+        };
+
+        let text_width = text.len() as u32 * CHAR_WIDTH;
+        let x = (WINDOW_WIDTH_IN_BLOCKS * BLOCK_SIZE - text_width) / 2;
+        display.draw_text(&text, x, VERSUS_RESULT_OFFSET_Y, Color::WHITE)
+    }
+
+    #[cfg(test)]
+    pub fn get_versus_match(
+        &self,
+    ) -> &VersusMatch<GraphicsPlayfieldRenderer, GraphicsHudRenderer, RandomTetrominoGenerator>
+    {
+        &self.versus_match
+    }
+}
+
+fn create_game(seed: u64, layout: BoardLayout, high_score_manager: HighScoreManager) -> VersusGame {
+    let playfield_dimensions = Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT);
+    let event_queue = Arc::new(EventQueue::new());
+    let playfield = Playfield::new(
+        playfield_dimensions,
+        RandomTetrominoGenerator::with_seed(seed),
+        event_queue.clone(),
+    );
+    let mut game = Game::new(
+        playfield,
+        GraphicsPlayfieldRenderer::with_layout(layout),
+        GraphicsHudRenderer::with_layout(layout),
+        event_queue,
+        high_score_manager,
+    );
+    game.start_level(0);
+    game
+}
+
+/// Returns the player the key belongs to and the game input it maps to, for two players
+/// sharing the keyboard.
+pub fn translate_two_player_key(key: Key) -> Option<(usize, GameInput)> {
+    match key {
+        Key::Alphanumeric('A') => Some((0, GameInput::MoveLeft)),
+        Key::Alphanumeric('D') => Some((0, GameInput::MoveRight)),
+        Key::Alphanumeric('S') => Some((0, GameInput::MoveDown)),
+        Key::Alphanumeric('W') => Some((0, GameInput::RotateClockwise)),
+        Key::Alphanumeric('Q') => Some((0, GameInput::RotateCounterclockwise)),
+        Key::Space => Some((0, GameInput::Drop)),
+        Key::Left => Some((1, GameInput::MoveLeft)),
+        Key::Right => Some((1, GameInput::MoveRight)),
+        Key::Down => Some((1, GameInput::MoveDown)),
+        Key::Up => Some((1, GameInput::RotateClockwise)),
+        Key::Alphanumeric('P') => Some((1, GameInput::RotateCounterclockwise)),
+        Key::Enter => Some((1, GameInput::Drop)),
+        _ => None,
+    }
+}
+
+impl Screen for VersusScreen {
+    fn update(&mut self, delta_time: Duration) {
+        self.update_cpu_player(delta_time);
+        self.versus_match.update(delta_time);
+    }
+
+    fn draw(&mut self, display: &mut dyn Display) -> Result<(), String> {
+        display.clear()?;
+        self.versus_match.draw_boards(display)?;
+        self.draw_result(display)?;
+        display.present()
+    }
+
+    fn handle_input(&mut self, input_events: &[InputEvent]) -> ScreenResult {
+        for event in input_events {
+            match event {
+                InputEvent::Quit => return ScreenResult::Quit,
+                InputEvent::KeyPressed(Key::Escape) => return ScreenResult::ReturnToMainMenu,
+                InputEvent::KeyPressed(key) => {
+                    self.held_keys.insert(*key);
+                    self.update_held_inputs();
+                    let result = self.handle_key_pressed(*key);
+                    if result != ScreenResult::Continue {
+                        return result;
+                    }
+                }
+                InputEvent::KeyReleased(key) => {
+                    self.held_keys.remove(key);
+                    self.update_held_inputs();
+                }
+            }
+        }
+
+        ScreenResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Position;
+    use crate::game_logic::{PlayfieldGrid, PlayfieldState};
+    use crate::graphics::MockDisplay;
+    use crate::high_scores::MockHighScoresRepository;
+    use rstest::rstest;
+
+    #[test]
+    fn new_deals_same_tetrominoes_to_both_players() {
+        // Act
+        let sut = create_test_versus_screen();
+
+        // Assert
+        let versus_match = sut.get_versus_match();
+        let first_type = get_current_tetromino_type(&sut, 0);
+        assert_eq!(get_current_tetromino_type(&sut, 1), first_type);
+        assert_eq!(versus_match.get_state(), VersusState::Playing);
+    }
+
+    #[rstest]
+    #[case(Key::Alphanumeric('A'), 0)]
+    #[case(Key::Left, 1)]
+    fn handle_input_moves_tetromino_of_player_owning_the_key(
+        #[case] key: Key,
+        #[case] player: usize,
+    ) {
+        // Arrange
+        let mut sut = create_test_versus_screen();
+        let other_player = 1 - player;
+        let start_x = get_current_tetromino_position(&sut, player).x;
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(key)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::Continue);
+        assert_eq!(get_current_tetromino_position(&sut, player).x, start_x - 1);
+        assert_eq!(
+            get_current_tetromino_position(&sut, other_player).x,
+            start_x
+        );
+    }
+
+    #[test]
+    fn handle_input_escape_returns_to_main_menu() {
+        // Arrange
+        let mut sut = create_test_versus_screen();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Escape)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn handle_input_enter_after_match_returns_to_main_menu() {
+        // Arrange
+        let mut sut = create_finished_test_versus_screen();
+
+        // Act
+        let result = sut.handle_input(&[InputEvent::KeyPressed(Key::Enter)]);
+
+        // Assert
+        assert_eq!(result, ScreenResult::ReturnToMainMenu);
+    }
+
+    #[test]
+    fn draw_after_match_shows_winner() {
+        // Arrange
+        let mut sut = create_finished_test_versus_screen();
+        let mut display = MockDisplay::new();
+
+        // Act
+        let result = sut.draw(&mut display);
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(display.cleared);
+        assert!(display.presented);
+        let winner_text_drawn = display
+            .drawn_text
+            .iter()
+            .any(|(text, _, _, _)| text == "PLAYER 2 WINS");
+        assert!(winner_text_drawn);
+    }
+
+    #[test]
+    fn handle_input_against_cpu_moves_tetromino_of_human_player_with_single_player_keys() {
+        // Arrange
+        let mut sut = create_test_versus_screen_against_cpu();
+        let start_x = get_current_tetromino_position(&sut, 0).x;
+
+        // Act
+        sut.handle_input(&[InputEvent::KeyPressed(Key::Left)]);
+
+        // Assert
+        assert_eq!(get_current_tetromino_position(&sut, 0).x, start_x - 1);
+        assert_eq!(get_current_tetromino_position(&sut, CPU_PLAYER).x, start_x);
+    }
+
+    #[test]
+    fn update_against_cpu_lets_cpu_play() {
+        // Arrange
+        let mut sut = create_test_versus_screen_against_cpu();
+        let start_position = get_current_tetromino_position(&sut, CPU_PLAYER);
+
+        // Act
+        for _ in 0..10 {
+            sut.update(Duration::from_millis(AI_INPUT_INTERVAL_MS));
+        }
+
+        // Assert
+        let cpu_playfield_view = sut.get_versus_match().get_playfield_view(CPU_PLAYER);
+        let has_moved = get_current_tetromino_position(&sut, CPU_PLAYER) != start_position;
+        let has_locked =
+            cpu_playfield_view.grid != &PlayfieldGrid::new(cpu_playfield_view.dimensions);
+        assert!(has_moved || has_locked);
+    }
+
+    fn create_test_versus_screen_against_cpu() -> VersusScreen {
+        VersusScreen::new_against_cpu(
+            42,
+            [
+                HighScoreManager::new(Box::new(MockHighScoresRepository::empty())),
+                HighScoreManager::new(Box::new(MockHighScoresRepository::empty())),
+            ],
+        )
+    }
+
+    fn create_test_versus_screen() -> VersusScreen {
+        VersusScreen::new(
+            42,
+            [
+                HighScoreManager::new(Box::new(MockHighScoresRepository::empty())),
+                HighScoreManager::new(Box::new(MockHighScoresRepository::empty())),
+            ],
+        )
+    }
+
+    fn create_finished_test_versus_screen() -> VersusScreen {
+        let mut sut = create_test_versus_screen();
+        sut.versus_match
+            .get_game_mut(0)
+            .get_playfield_mut()
+            .set_state(PlayfieldState::GameOver);
+        sut.update(Duration::from_millis(1));
+        sut
+    }
+
+    fn get_current_tetromino_position(sut: &VersusScreen, player: usize) -> Position {
+        sut.get_versus_match()
+            .get_game(player)
+            .get_playfield()
+            .get_current_tetromino()
+            .unwrap()
+            .get_position()
+    }
+
+    fn get_current_tetromino_type(
+        sut: &VersusScreen,
+        player: usize,
+    ) -> crate::tetromino::TetrominoType {
+        sut.get_versus_match()
+            .get_game(player)
+            .get_playfield()
+            .get_current_tetromino()
+            .unwrap()
+            .get_type()
+    }
+}
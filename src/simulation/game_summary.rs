@@ -0,0 +1,102 @@
+use std::fmt;
+
+/// The outcome of a single simulated game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameSummary {
+    pub score: u32,
+    pub lines: u32,
+    pub level: u32,
+    pub pieces: u32,
+    pub topped_out: bool,
+}
+
+/// Totals over many simulated games.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimulationSummary {
+    nr_games: u32,
+    nr_topped_out: u32,
+    total_score: u64,
+    max_score: u32,
+    total_lines: u64,
+    total_pieces: u64,
+}
+
+impl SimulationSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, game_summary: &GameSummary) {
+        self.nr_games += 1;
+        self.nr_topped_out += game_summary.topped_out as u32;
+        self.total_score += game_summary.score as u64;
+        self.max_score = self.max_score.max(game_summary.score);
+        self.total_lines += game_summary.lines as u64;
+        self.total_pieces += game_summary.pieces as u64;
+    }
+
+    fn average(&self, total: u64) -> f64 {
+        if self.nr_games == 0 {
+            0.0
+        } else {
+            total as f64 / self.nr_games as f64
+        }
+    }
+}
+
+impl fmt::Display for SimulationSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Games:      {}", self.nr_games)?;
+        writeln!(f, "Topped out: {}", self.nr_topped_out)?;
+        writeln!(
+            f,
+            "Score:      avg {:.1}, max {}",
+            self.average(self.total_score),
+            self.max_score
+        )?;
+        writeln!(f, "Lines:      avg {:.1}", self.average(self.total_lines))?;
+        write!(f, "Pieces:     avg {:.1}", self.average(self.total_pieces))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_accumulates_games() {
+        // Arrange
+        let mut sut = SimulationSummary::new();
+
+        // Act
+        sut.add(&create_game_summary(100, 2, 10, true));
+        sut.add(&create_game_summary(300, 4, 20, false));
+
+        // Assert
+        let text = sut.to_string();
+        assert!(text.contains("Games:      2"));
+        assert!(text.contains("Topped out: 1"));
+        assert!(text.contains("Score:      avg 200.0, max 300"));
+        assert!(text.contains("Lines:      avg 3.0"));
+        assert!(text.contains("Pieces:     avg 15.0"));
+    }
+
+    #[test]
+    fn display_without_games_shows_zero_averages() {
+        // Act
+        let text = SimulationSummary::new().to_string();
+
+        // Assert
+        assert!(text.contains("Score:      avg 0.0, max 0"));
+    }
+
+    fn create_game_summary(score: u32, lines: u32, pieces: u32, topped_out: bool) -> GameSummary {
+        GameSummary {
+            score,
+            lines,
+            level: 0,
+            pieces,
+            topped_out,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::gui::GameInput;
+
+/// Game inputs at fixed simulation ticks. In the text format every line holds a tick and the
+/// name of a game input, e.g. "12 MoveLeft". Empty lines and lines starting with '#' are
+/// skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputScript {
+    inputs: Vec<(u64, GameInput)>,
+}
+
+impl InputScript {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut inputs = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let input = parse_line(line)
+                .ok_or_else(|| format!("Invalid input on line {}: {line}", index + 1))?;
+            inputs.push(input);
+        }
+        inputs.sort_by_key(|(tick, _)| *tick);
+
+        Ok(Self { inputs })
+    }
+
+    /// Returns the inputs for the given tick, in the order of the script.
+    pub fn get_inputs_at(&self, tick: u64) -> impl Iterator<Item = GameInput> + '_ {
+        self.inputs
+            .iter()
+            .filter(move |(input_tick, _)| *input_tick == tick)
+            .map(|(_, input)| *input)
+    }
+}
+
+fn parse_line(line: &str) -> Option<(u64, GameInput)> {
+    let (tick, input) = line.split_once(char::is_whitespace)?;
+    Some((tick.parse().ok()?, input.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn parse_reads_inputs_per_tick() {
+        // Arrange
+        let text = "# opening\n0 MoveLeft\n\n3 RotateClockwise\n3 Drop\n";
+
+        // Act
+        let result = InputScript::parse(text).unwrap();
+
+        // Assert
+        assert_eq!(
+            result.get_inputs_at(0).collect::<Vec<_>>(),
+            vec![GameInput::MoveLeft]
+        );
+        assert_eq!(result.get_inputs_at(1).count(), 0);
+        assert_eq!(
+            result.get_inputs_at(3).collect::<Vec<_>>(),
+            vec![GameInput::RotateClockwise, GameInput::Drop]
+        );
+    }
+
+    #[rstest]
+    #[case("MoveLeft")]
+    #[case("x MoveLeft")]
+    #[case("3 Jump")]
+    fn parse_rejects_invalid_line(#[case] line: &str) {
+        // Act
+        let result = InputScript::parse(line);
+
+        // Assert
+        assert_eq!(result, Err(format!("Invalid input on line 1: {line}")));
+    }
+}
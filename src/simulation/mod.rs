@@ -0,0 +1,7 @@
+mod game_summary;
+mod input_script;
+mod simulator;
+
+pub use game_summary::*;
+pub use input_script::InputScript;
+pub use simulator::*;
@@ -0,0 +1,161 @@
+use crate::ai::{AiPlayer, HeuristicWeights};
+use crate::common::Dimensions;
+use crate::constants::*;
+use crate::events::EventQueue;
+use crate::game_logic::{Game, GameState, Playfield};
+use crate::graphics::{BoardLayout, GraphicsHudRenderer, GraphicsPlayfieldRenderer, NullDisplay};
+use crate::gui::GameInput;
+use crate::high_scores::{HighScoreManager, NullHighScoresRepository};
+use crate::simulation::{GameSummary, InputScript};
+use crate::tetromino::RandomTetrominoGenerator;
+use std::sync::Arc;
+use std::time::Duration;
+
+type SimulatedGame = Game<GraphicsPlayfieldRenderer, GraphicsHudRenderer, RandomTetrominoGenerator>;
+
+/// Where the inputs of a simulated game come from.
+pub enum InputSource {
+    Ai(HeuristicWeights),
+    Script(InputScript),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulationSettings {
+    pub start_level: u32,
+    /// Games that don't top out end after this many pieces, so a good AI can't play forever.
+    pub max_pieces: u32,
+}
+
+/// Plays a complete game without a window, as fast as possible, in ticks of one frame.
+pub fn simulate_game(
+    seed: u64,
+    input_source: &InputSource,
+    settings: &SimulationSettings,
+) -> GameSummary {
+    let tick_duration = Duration::from_millis(GAME_TICK_MS);
+    let mut game = create_game(seed, settings.start_level);
+    let mut ai_player = match input_source {
+        InputSource::Ai(weights) => Some(AiPlayer::new(*weights, tick_duration)),
+        InputSource::Script(_) => None,
+    };
+    let mut display = NullDisplay::new();
+
+    let mut tick = 0;
+    while *game.get_game_state() == GameState::Playing
+        && game.get_nr_locked_tetrominoes() < settings.max_pieces
+    {
+        let inputs: Vec<GameInput> = match (&mut ai_player, input_source) {
+            (Some(ai_player), _) => ai_player
+                .update(tick_duration, &game.get_playfield_view())
+                .into_iter()
+                .collect(),
+            (None, InputSource::Script(script)) => script.get_inputs_at(tick).collect(),
+            (None, InputSource::Ai(_)) => vec![],
+        };
+        for input in inputs {
+            game.handle_input(input);
+        }
+
+        game.update(tick_duration);
+        tick += 1;
+    }
+
+    // Drawing only the final board keeps the render path exercised without slowing down every
+    // tick.
+    let _ = game.draw(&mut display);
+
+    let hud_view = game.get_hud_view();
+    GameSummary {
+        score: hud_view.score,
+        lines: hud_view.total_lines_cleared,
+        level: hud_view.current_level,
+        pieces: game.get_nr_locked_tetrominoes(),
+        topped_out: *game.get_game_state() == GameState::GameOver,
+    }
+}
+
+fn create_game(seed: u64, start_level: u32) -> SimulatedGame {
+    let playfield_dimensions = Dimensions::new(PLAYFIELD_WIDTH, PLAYFIELD_HEIGHT);
+    let event_queue = Arc::new(EventQueue::new());
+    let playfield = Playfield::new(
+        playfield_dimensions,
+        RandomTetrominoGenerator::with_seed(seed),
+        event_queue.clone(),
+    );
+    let layout = BoardLayout::single_player();
+    let mut game = Game::new(
+        playfield,
+        GraphicsPlayfieldRenderer::with_layout(layout),
+        GraphicsHudRenderer::with_layout(layout),
+        event_queue,
+        HighScoreManager::new(Box::new(NullHighScoresRepository)),
+    );
+    game.start_level(start_level);
+    game
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulate_game_with_ai_stops_at_max_pieces() {
+        // Arrange
+        let settings = create_settings(0, 20);
+
+        // Act
+        let result = simulate_game(1, &InputSource::Ai(HeuristicWeights::default()), &settings);
+
+        // Assert
+        assert_eq!(result.pieces, 20);
+        assert!(!result.topped_out);
+    }
+
+    #[test]
+    fn simulate_game_with_same_seed_gives_same_result() {
+        // Arrange
+        let settings = create_settings(0, 30);
+        let input_source = InputSource::Ai(HeuristicWeights::default());
+
+        // Act
+        let first_result = simulate_game(7, &input_source, &settings);
+        let second_result = simulate_game(7, &input_source, &settings);
+
+        // Assert
+        assert_eq!(first_result, second_result);
+    }
+
+    #[test]
+    fn simulate_game_with_script_applies_inputs_at_their_tick() {
+        // Arrange
+        let script = InputScript::parse("0 Drop\n1 Drop\n2 Drop").unwrap();
+        let settings = create_settings(0, 3);
+
+        // Act
+        let result = simulate_game(1, &InputSource::Script(script), &settings);
+
+        // Assert
+        assert_eq!(result.pieces, 3);
+    }
+
+    #[test]
+    fn simulate_game_with_empty_script_tops_out_by_gravity() {
+        // Arrange
+        let script = InputScript::parse("").unwrap();
+        let settings = create_settings(20, 1000);
+
+        // Act
+        let result = simulate_game(1, &InputSource::Script(script), &settings);
+
+        // Assert
+        assert!(result.topped_out);
+        assert_eq!(result.score, 0);
+    }
+
+    fn create_settings(start_level: u32, max_pieces: u32) -> SimulationSettings {
+        SimulationSettings {
+            start_level,
+            max_pieces,
+        }
+    }
+}
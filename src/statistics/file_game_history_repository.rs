@@ -0,0 +1,165 @@
+use crate::statistics::{encode_csv, GameHistory, GameHistoryRepository, GameRecord};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+const GAME_HISTORY_HEADER: &str = "SAMTRIS-HISTORY";
+const GAME_HISTORY_FORMAT_VERSION: u32 = 1;
+
+/// Stores the history in a file with a header line and one line per game, a game is added by
+/// appending its line so earlier games are never rewritten. The CSV export goes to its own file.
+pub struct FileGameHistoryRepository {
+    file_path: PathBuf,
+    csv_file_path: PathBuf,
+}
+
+impl FileGameHistoryRepository {
+    pub fn new(file_path: impl Into<PathBuf>, csv_file_path: impl Into<PathBuf>) -> Self {
+        Self {
+            file_path: file_path.into(),
+            csv_file_path: csv_file_path.into(),
+        }
+    }
+}
+
+impl GameHistoryRepository for FileGameHistoryRepository {
+    fn load(&self) -> Result<GameHistory, String> {
+        if !self.file_path.exists() {
+            return Ok(GameHistory::default());
+        }
+
+        let text = fs::read_to_string(&self.file_path)
+            .map_err(|e| format!("Failed to read history: {e}"))?;
+        decode_game_history(&text)
+    }
+
+    fn append(&self, game_record: &GameRecord) -> Result<(), String> {
+        let is_new = fs::metadata(&self.file_path).map_or(true, |metadata| metadata.len() == 0);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file_path)
+            .map_err(|e| format!("Failed to open history: {e}"))?;
+
+        let mut text = String::new();
+        if is_new {
+            text.push_str(&format!(
+                "{GAME_HISTORY_HEADER} {GAME_HISTORY_FORMAT_VERSION}\n"
+            ));
+        }
+        text.push_str(&game_record.encode());
+        text.push('\n');
+        file.write_all(text.as_bytes())
+            .map_err(|e| format!("Failed to write history: {e}"))
+    }
+
+    fn export_csv(&self, game_records: &[&GameRecord]) -> Result<String, String> {
+        fs::write(&self.csv_file_path, encode_csv(game_records))
+            .map_err(|e| format!("Failed to write CSV: {e}"))?;
+        Ok(self.csv_file_path.display().to_string())
+    }
+}
+
+fn decode_game_history(text: &str) -> Result<GameHistory, String> {
+    let mut lines = text.lines();
+
+    let header = lines.next().unwrap_or_default();
+    let version = header
+        .strip_prefix(GAME_HISTORY_HEADER)
+        .ok_or(format!("Not a history file: '{header}'"))?;
+    if version.trim() != GAME_HISTORY_FORMAT_VERSION.to_string() {
+        return Err(format!("Unsupported history version '{}'", version.trim()));
+    }
+
+    let game_records = lines
+        .filter(|line| !line.is_empty())
+        .map(GameRecord::decode)
+        .collect::<Result<_, _>>()?;
+    Ok(GameHistory::new(game_records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::DateTime;
+    use crate::game_logic::GameMode;
+    use crate::statistics::HistorySort;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[test]
+    fn append_adds_games_that_can_be_loaded_in_order() {
+        // Arrange
+        let test_file = std::env::temp_dir().join("samtris_test_history.dat");
+        let _ = fs::remove_file(&test_file);
+        let sut = FileGameHistoryRepository::new(&test_file, "unused.csv");
+
+        // Act
+        sut.append(&create_test_game_record(100)).unwrap();
+        sut.append(&create_test_game_record(200)).unwrap();
+
+        // Assert
+        let game_history = sut.load().unwrap();
+        let scores: Vec<u32> = game_history
+            .select(None, HistorySort::Date)
+            .iter()
+            .map(|game_record| game_record.score)
+            .collect();
+        assert_eq!(scores, vec![200, 100]);
+        let text = fs::read_to_string(&test_file).unwrap();
+        assert!(text.starts_with("SAMTRIS-HISTORY 1\n"));
+        let _ = fs::remove_file(&test_file);
+    }
+
+    #[test]
+    fn load_without_file_returns_empty_history() {
+        // Arrange
+        let sut = FileGameHistoryRepository::new("does_not_exist/history.dat", "unused.csv");
+
+        // Act
+        let result = sut.load();
+
+        // Assert
+        assert_eq!(result, Ok(GameHistory::default()));
+    }
+
+    #[test]
+    fn export_csv_writes_csv_file() {
+        // Arrange
+        let test_file = std::env::temp_dir().join("samtris_test_history.csv");
+        let sut = FileGameHistoryRepository::new("unused.dat", &test_file);
+        let game_record = create_test_game_record(100);
+
+        // Act
+        let result = sut.export_csv(&[&game_record]);
+
+        // Assert
+        assert_eq!(result, Ok(test_file.display().to_string()));
+        let text = fs::read_to_string(&test_file).unwrap();
+        assert_eq!(text, encode_csv(&[&game_record]));
+        let _ = fs::remove_file(&test_file);
+    }
+
+    #[rstest]
+    #[case("SAMTRIS-SAVE 1", "Not a history file: 'SAMTRIS-SAVE 1'")]
+    #[case("SAMTRIS-HISTORY 2", "Unsupported history version '2'")]
+    fn decode_game_history_rejects_other_files(#[case] text: &str, #[case] expected_error: &str) {
+        // Act
+        let result = decode_game_history(text);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
+
+    fn create_test_game_record(score: u32) -> GameRecord {
+        GameRecord {
+            date_time: DateTime::from_unix_timestamp(1_792_327_385),
+            game_mode: GameMode::Marathon,
+            score,
+            lines: 10,
+            level: 1,
+            duration: Duration::from_secs(60),
+            replay_name: None,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::statistics::{LifetimeStatistics, LifetimeStatisticsRepository};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct FileLifetimeStatisticsRepository {
+    file_path: PathBuf,
+}
+
+impl FileLifetimeStatisticsRepository {
+    pub fn new(file_path: impl Into<PathBuf>) -> Self {
+        Self {
+            file_path: file_path.into(),
+        }
+    }
+}
+
+impl LifetimeStatisticsRepository for FileLifetimeStatisticsRepository {
+    fn load(&self) -> Result<LifetimeStatistics, String> {
+        if !Path::new(&self.file_path).exists() {
+            return Ok(LifetimeStatistics::new());
+        }
+
+        let text = fs::read_to_string(&self.file_path)
+            .map_err(|e| format!("Failed to read statistics: {e}"))?;
+        LifetimeStatistics::decode(&text)
+    }
+
+    fn save(&self, lifetime_statistics: &LifetimeStatistics) -> Result<(), String> {
+        fs::write(&self.file_path, lifetime_statistics.encode())
+            .map_err(|e| format!("Failed to write statistics: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::{GameMode, GameStatistics};
+
+    #[test]
+    fn add_game_saves_statistics_that_can_be_loaded() {
+        // Arrange
+        let test_file = std::env::temp_dir().join("samtris_test_statistics.dat");
+        let _ = fs::remove_file(&test_file);
+        let sut = FileLifetimeStatisticsRepository::new(&test_file);
+        let mut statistics = GameStatistics::new();
+        statistics.handle_tetromino_locked();
+        statistics.handle_lines_cleared(4);
+
+        // Act
+        let result = sut.add_game(GameMode::Marathon, 1200, &statistics);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        let loaded = sut.load().unwrap();
+        assert_eq!(loaded.nr_games, 1);
+        assert_eq!(loaded.nr_tetrises, 1);
+        let _ = fs::remove_file(&test_file);
+    }
+
+    #[test]
+    fn load_without_file_returns_empty_statistics() {
+        // Arrange
+        let sut = FileLifetimeStatisticsRepository::new("does_not_exist/statistics.dat");
+
+        // Act
+        let result = sut.load();
+
+        // Assert
+        assert_eq!(result, Ok(LifetimeStatistics::new()));
+    }
+}
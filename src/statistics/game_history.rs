@@ -0,0 +1,200 @@
+use crate::game_logic::GameMode;
+use crate::statistics::game_record::CSV_HEADER;
+use crate::statistics::GameRecord;
+use std::cmp::Reverse;
+
+/// The order in which the history lists the games, each of them best or newest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistorySort {
+    Date,
+    Score,
+    Lines,
+    Duration,
+}
+
+impl HistorySort {
+    pub fn display_text(&self) -> &'static str {
+        match self {
+            HistorySort::Date => "DATE",
+            HistorySort::Score => "SCORE",
+            HistorySort::Lines => "LINES",
+            HistorySort::Duration => "TIME",
+        }
+    }
+
+    /// Returns the next order, after the last one it starts over with the first one.
+    pub fn next(&self) -> Self {
+        match self {
+            HistorySort::Date => HistorySort::Score,
+            HistorySort::Score => HistorySort::Lines,
+            HistorySort::Lines => HistorySort::Duration,
+            HistorySort::Duration => HistorySort::Date,
+        }
+    }
+}
+
+/// All games that were played to the end, in the order they were played.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameHistory {
+    game_records: Vec<GameRecord>,
+}
+
+impl GameHistory {
+    pub fn new(game_records: Vec<GameRecord>) -> Self {
+        Self { game_records }
+    }
+
+    /// Returns the games of the given mode, or of all modes when it's not set, in the given order.
+    /// Games that are equal in that order stay newest first.
+    pub fn select(&self, game_mode: Option<GameMode>, sort: HistorySort) -> Vec<&GameRecord> {
+        let mut game_records: Vec<&GameRecord> = self
+            .game_records
+            .iter()
+            .rev()
+            .filter(|game_record| game_mode.is_none_or(|mode| game_record.game_mode == mode))
+            .collect();
+        match sort {
+            HistorySort::Date => {}
+            HistorySort::Score => {
+                game_records.sort_by_key(|game_record| Reverse(game_record.score))
+            }
+            HistorySort::Lines => {
+                game_records.sort_by_key(|game_record| Reverse(game_record.lines))
+            }
+            HistorySort::Duration => {
+                game_records.sort_by_key(|game_record| Reverse(game_record.duration))
+            }
+        }
+        game_records
+    }
+}
+
+/// Encodes the games as CSV with a header row, so they can be charted in a spreadsheet.
+pub fn encode_csv(game_records: &[&GameRecord]) -> String {
+    let mut lines = vec![CSV_HEADER.to_string()];
+    lines.extend(
+        game_records
+            .iter()
+            .map(|game_record| game_record.to_csv_row()),
+    );
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::DateTime;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[rstest]
+    #[case(HistorySort::Date, vec![4, 3, 2, 1])]
+    #[case(HistorySort::Score, vec![3, 2, 4, 1])]
+    #[case(HistorySort::Lines, vec![1, 4, 2, 3])]
+    #[case(HistorySort::Duration, vec![2, 1, 4, 3])]
+    fn select_sorts_games_best_or_newest_first(
+        #[case] sort: HistorySort,
+        #[case] expected_games: Vec<u64>,
+    ) {
+        // Arrange
+        let sut = create_test_game_history();
+
+        // Act
+        let result = sut.select(None, sort);
+
+        // Assert
+        let games: Vec<u64> = result
+            .iter()
+            .map(|record| get_game_number(record))
+            .collect();
+        assert_eq!(games, expected_games);
+    }
+
+    #[test]
+    fn select_with_game_mode_only_returns_games_of_that_mode() {
+        // Arrange
+        let sut = create_test_game_history();
+
+        // Act
+        let result = sut.select(Some(GameMode::Coop), HistorySort::Date);
+
+        // Assert
+        let games: Vec<u64> = result
+            .iter()
+            .map(|record| get_game_number(record))
+            .collect();
+        assert_eq!(games, vec![4, 2]);
+    }
+
+    #[test]
+    fn next_cycles_through_all_orders() {
+        // Arrange
+        let mut sut = HistorySort::Date;
+
+        // Act
+        let mut result = Vec::new();
+        for _ in 0..4 {
+            sut = sut.next();
+            result.push(sut);
+        }
+
+        // Assert
+        assert_eq!(
+            result,
+            vec![
+                HistorySort::Score,
+                HistorySort::Lines,
+                HistorySort::Duration,
+                HistorySort::Date
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_csv_writes_header_and_one_row_per_game() {
+        // Arrange
+        let game_history = create_test_game_history();
+        let game_records = game_history.select(Some(GameMode::Coop), HistorySort::Date);
+
+        // Act
+        let result = encode_csv(&game_records);
+
+        // Assert
+        assert_eq!(
+            result,
+            "date,mode,score,lines,level,duration_seconds,replay\n\
+             1970-01-01 00:00:04,coop,200,20,1,50.000,\n\
+             1970-01-01 00:00:02,coop,300,10,1,90.000,\n"
+        );
+    }
+
+    /// Four games, played one second apart, the number of each game is its timestamp.
+    fn create_test_game_history() -> GameHistory {
+        let games = [
+            (GameMode::Marathon, 100, 40, 60),
+            (GameMode::Coop, 300, 10, 90),
+            (GameMode::Marathon, 500, 0, 30),
+            (GameMode::Coop, 200, 20, 50),
+        ];
+        let game_records = games
+            .iter()
+            .zip(1..)
+            .map(
+                |(&(game_mode, score, lines, seconds), game_number)| GameRecord {
+                    date_time: DateTime::from_unix_timestamp(game_number),
+                    game_mode,
+                    score,
+                    lines,
+                    level: 0,
+                    duration: Duration::from_secs(seconds),
+                    replay_name: None,
+                },
+            )
+            .collect();
+        GameHistory::new(game_records)
+    }
+
+    fn get_game_number(game_record: &GameRecord) -> u64 {
+        game_record.date_time.second as u64
+    }
+}
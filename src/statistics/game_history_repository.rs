@@ -0,0 +1,10 @@
+use crate::statistics::{GameHistory, GameRecord};
+
+/// Keeps every game that was played to the end, games are only ever added.
+pub trait GameHistoryRepository {
+    /// Returns the history, empty when no game was added yet.
+    fn load(&self) -> Result<GameHistory, String>;
+    fn append(&self, game_record: &GameRecord) -> Result<(), String>;
+    /// Writes the games as CSV and returns where they were written.
+    fn export_csv(&self, game_records: &[&GameRecord]) -> Result<String, String>;
+}
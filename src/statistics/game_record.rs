@@ -0,0 +1,182 @@
+use crate::common::DateTime;
+use crate::game_logic::GameMode;
+use std::str::FromStr;
+use std::time::Duration;
+
+const FIELD_SEPARATOR: char = '|';
+pub const CSV_HEADER: &str = "date,mode,score,lines,level,duration_seconds,replay";
+
+/// A game that was played to the end, as it's kept in the game history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    /// When the game ended, in UTC.
+    pub date_time: DateTime,
+    pub game_mode: GameMode,
+    pub score: u32,
+    pub lines: u32,
+    pub level: u32,
+    pub duration: Duration,
+    /// Name of the replay of the game, if it was saved.
+    pub replay_name: Option<String>,
+}
+
+impl GameRecord {
+    /// Encodes the game as one line of the history file: date, mode, score, lines, level,
+    /// duration in milliseconds and replay name, separated by '|'.
+    pub fn encode(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}",
+            self.date_time,
+            self.game_mode,
+            self.score,
+            self.lines,
+            self.level,
+            self.duration.as_millis(),
+            self.replay_name.as_deref().unwrap_or_default()
+        )
+    }
+
+    pub fn decode(line: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = line.split(FIELD_SEPARATOR).collect();
+        let [date_time, game_mode, score, lines, level, duration_ms, replay_name] = fields[..]
+        else {
+            return Err(format!("Invalid game in history: '{line}'"));
+        };
+
+        Ok(Self {
+            date_time: DateTime::from_str(date_time)?,
+            game_mode: GameMode::from_str(game_mode)
+                .map_err(|_| format!("Unknown game mode in history: '{game_mode}'"))?,
+            score: parse_number(score)?,
+            lines: parse_number(lines)?,
+            level: parse_number(level)?,
+            duration: Duration::from_millis(parse_number(duration_ms)?),
+            replay_name: Some(replay_name.to_string()).filter(|name| !name.is_empty()),
+        })
+    }
+
+    /// Encodes the game as a row below CSV_HEADER. The level is the one shown in the game, which
+    /// starts at 1.
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{:.3},{}",
+            self.date_time,
+            self.game_mode,
+            self.score,
+            self.lines,
+            self.level + 1,
+            self.duration.as_secs_f64(),
+            escape_csv_field(self.replay_name.as_deref().unwrap_or_default())
+        )
+    }
+}
+
+fn parse_number<N: FromStr>(text: &str) -> Result<N, String> {
+    text.parse()
+        .map_err(|_| format!("Invalid number in history: '{text}'"))
+}
+
+/// Quotes the field when it contains characters that have a meaning in CSV.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        // Arrange
+        let game_record = create_test_game_record(Some("2026-10-18_12-43-05"));
+
+        // Act
+        let result = GameRecord::decode(&game_record.encode());
+
+        // Assert
+        assert_eq!(result, Ok(game_record));
+    }
+
+    #[test]
+    fn decode_without_replay_name_has_no_replay() {
+        // Act
+        let result = GameRecord::decode("2026-10-18 12:43:05|puzzle|40|2|0|8000|");
+
+        // Assert
+        assert_eq!(result, Ok(create_test_puzzle_game_record()));
+    }
+
+    #[rstest]
+    #[case(
+        "2026-10-18 12:43:05|marathon|1",
+        "Invalid game in history: '2026-10-18 12:43:05|marathon|1'"
+    )]
+    #[case(
+        "2026-10-18 12:43:05|sprint|1|2|3|4|",
+        "Unknown game mode in history: 'sprint'"
+    )]
+    #[case(
+        "2026-10-18 12:43:05|marathon|x|2|3|4|",
+        "Invalid number in history: 'x'"
+    )]
+    #[case("yesterday|marathon|1|2|3|4|", "Invalid date and time: 'yesterday'")]
+    fn decode_rejects_invalid_game(#[case] line: &str, #[case] expected_error: &str) {
+        // Act
+        let result = GameRecord::decode(line);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
+
+    #[rstest]
+    #[case(
+        Some("2026-10-18_12-43-05"),
+        "2026-10-18 12:43:05,marathon,12000,120,13,754.250,2026-10-18_12-43-05"
+    )]
+    #[case(
+        Some("my, \"best\""),
+        "2026-10-18 12:43:05,marathon,12000,120,13,754.250,\"my, \"\"best\"\"\""
+    )]
+    #[case(None, "2026-10-18 12:43:05,marathon,12000,120,13,754.250,")]
+    fn to_csv_row_writes_fields_in_header_order(
+        #[case] replay_name: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        // Arrange
+        let sut = create_test_game_record(replay_name);
+
+        // Act
+        let result = sut.to_csv_row();
+
+        // Assert
+        assert_eq!(result, expected);
+    }
+
+    fn create_test_game_record(replay_name: Option<&str>) -> GameRecord {
+        GameRecord {
+            date_time: DateTime::from_unix_timestamp(1_792_327_385),
+            game_mode: GameMode::Marathon,
+            score: 12000,
+            lines: 120,
+            level: 12,
+            duration: Duration::from_millis(754_250),
+            replay_name: replay_name.map(str::to_string),
+        }
+    }
+
+    fn create_test_puzzle_game_record() -> GameRecord {
+        GameRecord {
+            game_mode: GameMode::Puzzle,
+            score: 40,
+            lines: 2,
+            level: 0,
+            duration: Duration::from_secs(8),
+            ..create_test_game_record(None)
+        }
+    }
+}
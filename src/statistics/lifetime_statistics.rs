@@ -0,0 +1,225 @@
+use crate::game_logic::{GameMode, GameStatistics};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+const STATISTICS_HEADER: &str = "SAMTRIS-STATISTICS";
+const STATISTICS_FORMAT_VERSION: u32 = 1;
+const PERSONAL_BEST_KEY: &str = "best";
+
+/// The best results of all games played in one mode, each of them can come from another game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PersonalBest {
+    pub score: u32,
+    pub lines: u32,
+}
+
+/// The totals and bests over all games that were played to the end.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LifetimeStatistics {
+    pub nr_games: u32,
+    pub play_time: Duration,
+    pub nr_lines: u32,
+    pub nr_tetrises: u32,
+    pub best_combo: u32,
+    pub best_tetris_streak: u32,
+    pub personal_bests: BTreeMap<GameMode, PersonalBest>,
+}
+
+impl LifetimeStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_game(&mut self, game_mode: GameMode, score: u32, statistics: &GameStatistics) {
+        let nr_lines = statistics.get_nr_lines_cleared();
+        self.nr_games += 1;
+        self.play_time += statistics.play_time;
+        self.nr_lines += nr_lines;
+        self.nr_tetrises += statistics.get_nr_line_clears(4);
+        self.best_combo = self.best_combo.max(statistics.max_combo);
+        self.best_tetris_streak = self.best_tetris_streak.max(statistics.max_tetris_streak);
+
+        let personal_best = self.personal_bests.entry(game_mode).or_default();
+        personal_best.score = personal_best.score.max(score);
+        personal_best.lines = personal_best.lines.max(nr_lines);
+    }
+
+    pub fn encode(&self) -> String {
+        let mut lines = vec![
+            format!("{STATISTICS_HEADER} {STATISTICS_FORMAT_VERSION}"),
+            format!("games {}", self.nr_games),
+            format!("play_time_ns {}", self.play_time.as_nanos()),
+            format!("lines {}", self.nr_lines),
+            format!("tetrises {}", self.nr_tetrises),
+            format!("best_combo {}", self.best_combo),
+            format!("best_tetris_streak {}", self.best_tetris_streak),
+        ];
+        for (game_mode, personal_best) in &self.personal_bests {
+            lines.push(format!(
+                "{PERSONAL_BEST_KEY} {game_mode} {} {}",
+                personal_best.score, personal_best.lines
+            ));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+
+        let header = lines.next().ok_or("Statistics file is empty")?;
+        let version = header
+            .strip_prefix(STATISTICS_HEADER)
+            .ok_or(format!("Not a statistics file: '{header}'"))?;
+        let version: u32 = parse_number(version)?;
+        if version != STATISTICS_FORMAT_VERSION {
+            return Err(format!("Unsupported statistics version {version}"));
+        }
+
+        let mut lifetime_statistics = Self {
+            nr_games: parse_value(&read_values(&mut lines, "games")?, 0)?,
+            play_time: Duration::from_nanos(parse_value(
+                &read_values(&mut lines, "play_time_ns")?,
+                0,
+            )?),
+            nr_lines: parse_value(&read_values(&mut lines, "lines")?, 0)?,
+            nr_tetrises: parse_value(&read_values(&mut lines, "tetrises")?, 0)?,
+            best_combo: parse_value(&read_values(&mut lines, "best_combo")?, 0)?,
+            best_tetris_streak: parse_value(&read_values(&mut lines, "best_tetris_streak")?, 0)?,
+            personal_bests: BTreeMap::new(),
+        };
+        while let Some(line) = lines.next().filter(|line| !line.is_empty()) {
+            let values = read_values(&mut std::iter::once(line), PERSONAL_BEST_KEY)?;
+            let game_mode_name = values.first().copied().unwrap_or_default();
+            let game_mode = GameMode::from_str(game_mode_name)
+                .map_err(|_| format!("Unknown game mode in statistics: '{game_mode_name}'"))?;
+            let personal_best = PersonalBest {
+                score: parse_value(&values, 1)?,
+                lines: parse_value(&values, 2)?,
+            };
+            lifetime_statistics
+                .personal_bests
+                .insert(game_mode, personal_best);
+        }
+
+        Ok(lifetime_statistics)
+    }
+}
+
+/// Reads the next line, which must start with the given key, and returns the values after it.
+fn read_values<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    key: &str,
+) -> Result<Vec<&'a str>, String> {
+    let line = lines.next().unwrap_or_default();
+    let mut parts = line.split_whitespace();
+    if parts.next() != Some(key) {
+        return Err(format!("Statistics file is missing '{key}'"));
+    }
+    Ok(parts.collect())
+}
+
+fn parse_value<N: FromStr>(values: &[&str], index: usize) -> Result<N, String> {
+    parse_number(values.get(index).copied().unwrap_or_default())
+}
+
+fn parse_number<N: FromStr>(text: &str) -> Result<N, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("Invalid number in statistics: '{}'", text.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn add_game_adds_totals_and_keeps_bests() {
+        // Arrange
+        let mut sut = LifetimeStatistics::new();
+        let first_game = create_test_game_statistics(&[4, 4, 1], 60);
+        let second_game = create_test_game_statistics(&[2], 30);
+
+        // Act
+        sut.add_game(GameMode::Marathon, 3000, &first_game);
+        sut.add_game(GameMode::Marathon, 5000, &second_game);
+
+        // Assert
+        assert_eq!(sut.nr_games, 2);
+        assert_eq!(sut.play_time, Duration::from_secs(90));
+        assert_eq!(sut.nr_lines, 11);
+        assert_eq!(sut.nr_tetrises, 2);
+        assert_eq!(sut.best_combo, 3);
+        assert_eq!(sut.best_tetris_streak, 2);
+        assert_eq!(
+            sut.personal_bests[&GameMode::Marathon],
+            PersonalBest {
+                score: 5000,
+                lines: 9
+            }
+        );
+    }
+
+    #[test]
+    fn add_game_keeps_personal_bests_per_mode() {
+        // Arrange
+        let mut sut = LifetimeStatistics::new();
+
+        // Act
+        sut.add_game(GameMode::Marathon, 3000, &GameStatistics::new());
+        sut.add_game(GameMode::Coop, 100, &GameStatistics::new());
+
+        // Assert
+        assert_eq!(sut.personal_bests[&GameMode::Marathon].score, 3000);
+        assert_eq!(sut.personal_bests[&GameMode::Coop].score, 100);
+        assert!(!sut.personal_bests.contains_key(&GameMode::Puzzle));
+    }
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        // Arrange
+        let mut lifetime_statistics = LifetimeStatistics::new();
+        lifetime_statistics.add_game(
+            GameMode::Marathon,
+            12345,
+            &create_test_game_statistics(&[4, 1], 75),
+        );
+        lifetime_statistics.add_game(GameMode::Puzzle, 40, &create_test_game_statistics(&[1], 8));
+
+        // Act
+        let result = LifetimeStatistics::decode(&lifetime_statistics.encode());
+
+        // Assert
+        assert_eq!(result, Ok(lifetime_statistics));
+    }
+
+    #[rstest]
+    #[case("", "Statistics file is empty")]
+    #[case("SAMTRIS-STATISTICS 2", "Unsupported statistics version 2")]
+    #[case("SAMTRIS-SAVE 1", "Not a statistics file: 'SAMTRIS-SAVE 1'")]
+    #[case("SAMTRIS-STATISTICS 1\nlines 3", "Statistics file is missing 'games'")]
+    #[case(
+        "SAMTRIS-STATISTICS 1\ngames 1\nplay_time_ns 0\nlines 0\ntetrises 0\nbest_combo 0\nbest_tetris_streak 0\nbest sprint 1 2",
+        "Unknown game mode in statistics: 'sprint'"
+    )]
+    fn decode_rejects_invalid_statistics(#[case] text: &str, #[case] expected_error: &str) {
+        // Act
+        let result = LifetimeStatistics::decode(text);
+
+        // Assert
+        assert_eq!(result, Err(expected_error.to_string()));
+    }
+
+    /// Creates the statistics of a game in which consecutive pieces cleared the given lines.
+    fn create_test_game_statistics(line_clears: &[u32], seconds: u64) -> GameStatistics {
+        let mut statistics = GameStatistics::new();
+        for nr_lines in line_clears {
+            statistics.handle_tetromino_locked();
+            statistics.handle_lines_cleared(*nr_lines);
+        }
+        statistics.update(Duration::from_secs(seconds));
+        statistics
+    }
+}
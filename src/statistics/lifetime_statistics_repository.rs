@@ -0,0 +1,21 @@
+use crate::game_logic::{GameMode, GameStatistics};
+use crate::statistics::LifetimeStatistics;
+
+/// Keeps the statistics of all games played.
+pub trait LifetimeStatisticsRepository {
+    /// Returns the statistics, empty when nothing was saved yet.
+    fn load(&self) -> Result<LifetimeStatistics, String>;
+    fn save(&self, lifetime_statistics: &LifetimeStatistics) -> Result<(), String>;
+
+    /// Adds a game that has ended to the saved statistics.
+    fn add_game(
+        &self,
+        game_mode: GameMode,
+        score: u32,
+        statistics: &GameStatistics,
+    ) -> Result<(), String> {
+        let mut lifetime_statistics = self.load()?;
+        lifetime_statistics.add_game(game_mode, score, statistics);
+        self.save(&lifetime_statistics)
+    }
+}
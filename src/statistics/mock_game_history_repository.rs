@@ -0,0 +1,37 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::statistics::{encode_csv, GameHistory, GameHistoryRepository, GameRecord};
+
+/// Keeps the games in memory, clone `game_records` or `exported_csv` before boxing to inspect
+/// which games were added or exported.
+#[derive(Default)]
+pub struct MockGameHistoryRepository {
+    pub game_records: Rc<RefCell<Vec<GameRecord>>>,
+    pub exported_csv: Rc<RefCell<Option<String>>>,
+}
+
+impl MockGameHistoryRepository {
+    pub fn new(game_records: Vec<GameRecord>) -> Self {
+        Self {
+            game_records: Rc::new(RefCell::new(game_records)),
+            exported_csv: Rc::default(),
+        }
+    }
+}
+
+impl GameHistoryRepository for MockGameHistoryRepository {
+    fn load(&self) -> Result<GameHistory, String> {
+        Ok(GameHistory::new(self.game_records.borrow().clone()))
+    }
+
+    fn append(&self, game_record: &GameRecord) -> Result<(), String> {
+        self.game_records.borrow_mut().push(game_record.clone());
+        Ok(())
+    }
+
+    fn export_csv(&self, game_records: &[&GameRecord]) -> Result<String, String> {
+        *self.exported_csv.borrow_mut() = Some(encode_csv(game_records));
+        Ok("history.csv".to_string())
+    }
+}
@@ -0,0 +1,27 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::statistics::{LifetimeStatistics, LifetimeStatisticsRepository};
+
+/// Keeps the statistics in memory, clone `lifetime_statistics` before boxing to inspect them.
+#[derive(Default)]
+pub struct MockLifetimeStatisticsRepository {
+    pub lifetime_statistics: Rc<RefCell<LifetimeStatistics>>,
+}
+
+impl MockLifetimeStatisticsRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LifetimeStatisticsRepository for MockLifetimeStatisticsRepository {
+    fn load(&self) -> Result<LifetimeStatistics, String> {
+        Ok(self.lifetime_statistics.borrow().clone())
+    }
+
+    fn save(&self, lifetime_statistics: &LifetimeStatistics) -> Result<(), String> {
+        *self.lifetime_statistics.borrow_mut() = lifetime_statistics.clone();
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+mod file_game_history_repository;
+mod file_lifetime_statistics_repository;
+mod game_history;
+mod game_history_repository;
+mod game_record;
+mod lifetime_statistics;
+mod lifetime_statistics_repository;
+#[cfg(test)]
+mod mock_game_history_repository;
+#[cfg(test)]
+mod mock_lifetime_statistics_repository;
+
+pub use file_game_history_repository::FileGameHistoryRepository;
+pub use file_lifetime_statistics_repository::FileLifetimeStatisticsRepository;
+pub use game_history::{encode_csv, GameHistory, HistorySort};
+pub use game_history_repository::GameHistoryRepository;
+pub use game_record::GameRecord;
+pub use lifetime_statistics::{LifetimeStatistics, PersonalBest};
+pub use lifetime_statistics_repository::LifetimeStatisticsRepository;
+#[cfg(test)]
+pub use mock_game_history_repository::MockGameHistoryRepository;
+#[cfg(test)]
+pub use mock_lifetime_statistics_repository::MockLifetimeStatisticsRepository;
@@ -4,6 +4,7 @@ use crate::common::{Dimensions, Position};
 use crate::constants::*;
 use crate::events::EventQueue;
 use crate::game_logic::Playfield;
+use crate::game_logic::PlayfieldGrid;
 use crate::game_logic::{Game, GameResult};
 use crate::graphics::{MockHudRenderer, MockPlayfieldRenderer};
 use crate::high_scores::{HighScore, HighScoreManager, HighScores, MockHighScoresRepository};
@@ -71,6 +72,20 @@ pub fn create_test_playfield_with_specific_type(
     )
 }
 
+/// Creates a grid from rows of text, top row first, where '#' is an occupied cell.
+pub fn create_grid_from_rows(rows: &[&str]) -> PlayfieldGrid {
+    let dimensions = Dimensions::new(rows[0].len() as u32, rows.len() as u32);
+    let mut grid = PlayfieldGrid::new(dimensions);
+    for (y, row) in rows.iter().enumerate() {
+        for (x, cell) in row.chars().enumerate() {
+            if cell == '#' {
+                grid.set(Position::new(x as i32, y as i32), Some(TetrominoType::I));
+            }
+        }
+    }
+    grid
+}
+
 pub fn get_tetromino_start_position() -> Position {
     Position::new(TETRIS_SPAWN_X, TETRIS_SPAWN_Y)
 }
@@ -112,6 +127,7 @@ pub fn create_test_high_score_manager_with_full_very_high_scores() -> HighScoreM
             GameResult {
                 score: 100000 - (i * 1000),
                 level: 20,
+                ..Default::default()
             },
         ));
     }
@@ -3,6 +3,7 @@ use crate::tetromino::{
     TetrominoDefinitions, TetrominoGenerator, TetrominoInstance, TetrominoType,
 };
 
+#[derive(Clone)]
 pub struct FixedTetrominoGenerator {
     tetromino_type: TetrominoType,
     tetromino_definitions: TetrominoDefinitions,
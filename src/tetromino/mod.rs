@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod fixed_tetromino_generator;
 mod random_tetromino_generator;
+mod sequence_tetromino_generator;
 mod tetromino_definition;
 mod tetromino_definitions;
 mod tetromino_generator;
@@ -10,7 +11,8 @@ mod tetromino_type;
 #[cfg(test)]
 pub use fixed_tetromino_generator::FixedTetrominoGenerator;
 
-pub use random_tetromino_generator::RandomTetrominoGenerator;
+pub use random_tetromino_generator::{RandomGeneratorState, RandomTetrominoGenerator};
+pub use sequence_tetromino_generator::SequenceTetrominoGenerator;
 pub use tetromino_definitions::TetrominoDefinitions;
 pub use tetromino_generator::*;
 pub use tetromino_instance::TetrominoInstance;
@@ -1,41 +1,107 @@
-use crate::common::Position;
+use crate::common::{Position, RngAlgorithm, SeededRng};
 use crate::tetromino::{
     TetrominoDefinitions, TetrominoGenerator, TetrominoInstance, TetrominoType,
 };
-use rand::Rng;
+use std::collections::VecDeque;
 use strum::IntoEnumIterator;
 
+/// The state of a random tetromino generator: the seed and the number of tetromino types drawn
+/// from it, including the one that is next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomGeneratorState {
+    pub seed: u64,
+    pub nr_draws: u64,
+}
+
+#[derive(Clone)]
 pub struct RandomTetrominoGenerator {
-    rng: rand::rngs::ThreadRng,
+    rng: SeededRng,
+    state: RandomGeneratorState,
     tetromino_definitions: TetrominoDefinitions,
     next_tetromino_type: TetrominoType,
+    /// Tetromino types dealt before the random ones, they are not part of the state.
+    queued_types: VecDeque<TetrominoType>,
 }
 
 impl RandomTetrominoGenerator {
     pub fn new() -> Self {
-        let mut rng = rand::rng();
-        let next_tetromino_type = Self::select_random_type_from_rnd(&mut rng);
+        Self::with_seed(rand::random())
+    }
+
+    /// Creates a generator that always produces the same sequence of tetrominoes for the same
+    /// seed, so several games can be dealt identical pieces.
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_state(RandomGeneratorState { seed, nr_draws: 1 })
+    }
+
+    /// Creates a generator like with_seed that draws the tetromino types with the given
+    /// algorithm, to deal the same tetrominoes as a game played with it.
+    pub fn with_rng_algorithm(seed: u64, algorithm: RngAlgorithm) -> Self {
+        Self::from_state_with_algorithm(RandomGeneratorState { seed, nr_draws: 1 }, algorithm)
+    }
+
+    /// Creates a generator that deals the given tetromino types first and random ones after.
+    pub fn with_queue(queued_types: Vec<TetrominoType>) -> Self {
+        let mut generator = Self::new();
+        let mut queued_types = VecDeque::from(queued_types);
+        if let Some(tetromino_type) = queued_types.pop_front() {
+            generator.next_tetromino_type = tetromino_type;
+        }
+        generator.queued_types = queued_types;
+        generator
+    }
+
+    /// Creates a generator that continues where the generator with the given state was.
+    pub fn from_state(state: RandomGeneratorState) -> Self {
+        Self::from_state_with_algorithm(state, RngAlgorithm::default())
+    }
+
+    fn from_state_with_algorithm(state: RandomGeneratorState, algorithm: RngAlgorithm) -> Self {
+        let mut rng = SeededRng::with_algorithm(state.seed, algorithm);
+        let mut next_tetromino_type = Self::select_random_type_from_rnd(&mut rng);
+        for _ in 1..state.nr_draws {
+            next_tetromino_type = Self::select_random_type_from_rnd(&mut rng);
+        }
         Self {
             rng,
+            state: RandomGeneratorState {
+                seed: state.seed,
+                nr_draws: state.nr_draws.max(1),
+            },
             tetromino_definitions: TetrominoDefinitions::new(),
             next_tetromino_type,
+            queued_types: VecDeque::new(),
         }
     }
 
+    pub fn get_state(&self) -> RandomGeneratorState {
+        self.state
+    }
+
     fn select_random_type(&mut self) -> TetrominoType {
+        self.state.nr_draws += 1;
         Self::select_random_type_from_rnd(&mut self.rng)
     }
 
-    fn select_random_type_from_rnd(rand: &mut rand::rngs::ThreadRng) -> TetrominoType {
+    fn select_random_type_from_rnd(rand: &mut SeededRng) -> TetrominoType {
         let types: Vec<TetrominoType> = TetrominoType::iter().collect();
-        types[rand.random_range(0..types.len())]
+        types[rand.next_index(types.len() as u32) as usize]
+    }
+}
+
+impl Default for RandomTetrominoGenerator {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl TetrominoGenerator for RandomTetrominoGenerator {
     fn generate(&mut self, position: Position) -> TetrominoInstance {
         let tetromino_type = self.next_tetromino_type;
-        self.next_tetromino_type = self.select_random_type();
+        self.next_tetromino_type = match self.queued_types.pop_front() {
+            Some(queued_type) => queued_type,
+            None => self.select_random_type(),
+        };
         TetrominoInstance::new(tetromino_type, position, &self.tetromino_definitions)
     }
 
@@ -98,4 +164,80 @@ mod tests {
         assert_eq!(generated.get_type(), peeked_type);
         assert_eq!(generated.get_position(), position);
     }
+
+    #[test]
+    fn with_queue_deals_queued_types_first() {
+        // Arrange
+        let mut sut =
+            RandomTetrominoGenerator::with_queue(vec![TetrominoType::S, TetrominoType::Z]);
+        let position = Position::new(0, 0);
+
+        // Act
+        let results = [sut.generate(position), sut.generate(position)];
+
+        // Assert
+        assert_eq!(results[0].get_type(), TetrominoType::S);
+        assert_eq!(results[1].get_type(), TetrominoType::Z);
+    }
+
+    #[test]
+    fn with_seed_generates_same_sequence_for_same_seed() {
+        // Arrange
+        let mut sut = RandomTetrominoGenerator::with_seed(42);
+        let mut other = RandomTetrominoGenerator::with_seed(42);
+        let position = Position::new(0, 0);
+
+        // Act
+        let result: Vec<TetrominoType> =
+            (0..50).map(|_| sut.generate(position).get_type()).collect();
+
+        // Assert
+        let expected: Vec<TetrominoType> = (0..50)
+            .map(|_| other.generate(position).get_type())
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn with_seed_generates_pinned_sequence() {
+        // Arrange
+        let mut sut = RandomTetrominoGenerator::with_seed(42);
+        let position = Position::new(0, 0);
+
+        // Act
+        let result: Vec<TetrominoType> =
+            (0..10).map(|_| sut.generate(position).get_type()).collect();
+
+        // Assert
+        use TetrominoType::*;
+        assert_eq!(result, vec![J, I, O, J, S, S, Z, O, J, T]);
+    }
+
+    #[test]
+    fn from_state_continues_sequence() {
+        // Arrange
+        let mut original = RandomTetrominoGenerator::with_seed(42);
+        let position = Position::new(0, 0);
+        for _ in 0..10 {
+            original.generate(position);
+        }
+
+        // Act
+        let mut sut = RandomTetrominoGenerator::from_state(original.get_state());
+
+        // Assert
+        assert_eq!(
+            sut.get_state(),
+            RandomGeneratorState {
+                seed: 42,
+                nr_draws: 11
+            }
+        );
+        let result: Vec<TetrominoType> =
+            (0..20).map(|_| sut.generate(position).get_type()).collect();
+        let expected: Vec<TetrominoType> = (0..20)
+            .map(|_| original.generate(position).get_type())
+            .collect();
+        assert_eq!(result, expected);
+    }
 }
@@ -0,0 +1,75 @@
+use crate::common::Position;
+use crate::tetromino::{
+    TetrominoDefinitions, TetrominoGenerator, TetrominoInstance, TetrominoType,
+};
+
+/// Generates the tetromino types of a fixed list in order, starting over at the end of the list.
+#[derive(Clone)]
+pub struct SequenceTetrominoGenerator {
+    tetromino_types: Vec<TetrominoType>,
+    next_index: usize,
+    tetromino_definitions: TetrominoDefinitions,
+}
+
+impl SequenceTetrominoGenerator {
+    /// The list of tetromino types must not be empty.
+    pub fn new(tetromino_types: Vec<TetrominoType>) -> Self {
+        assert!(
+            !tetromino_types.is_empty(),
+            "A tetromino sequence needs at least one tetromino"
+        );
+        Self {
+            tetromino_types,
+            next_index: 0,
+            tetromino_definitions: TetrominoDefinitions::new(),
+        }
+    }
+}
+
+impl TetrominoGenerator for SequenceTetrominoGenerator {
+    fn generate(&mut self, position: Position) -> TetrominoInstance {
+        let tetromino_type = self.peek_next_type();
+        self.next_index = (self.next_index + 1) % self.tetromino_types.len();
+        TetrominoInstance::new(tetromino_type, position, &self.tetromino_definitions)
+    }
+
+    fn peek_next_type(&self) -> TetrominoType {
+        self.tetromino_types[self.next_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_returns_tetrominoes_in_sequence_order() {
+        // Arrange
+        let mut sut = SequenceTetrominoGenerator::new(vec![TetrominoType::T, TetrominoType::I]);
+        let position = Position::new(3, 0);
+
+        // Act
+        let first = sut.generate(position);
+        let second = sut.generate(position);
+
+        // Assert
+        assert_eq!(first.get_type(), TetrominoType::T);
+        assert_eq!(second.get_type(), TetrominoType::I);
+        assert_eq!(second.get_position(), position);
+    }
+
+    #[test]
+    fn generate_starts_over_at_end_of_sequence() {
+        // Arrange
+        let mut sut = SequenceTetrominoGenerator::new(vec![TetrominoType::O, TetrominoType::S]);
+        let position = Position::new(0, 0);
+        sut.generate(position);
+        sut.generate(position);
+
+        // Act
+        let result = sut.peek_next_type();
+
+        // Assert
+        assert_eq!(result, TetrominoType::O);
+    }
+}
@@ -1,18 +1,19 @@
 use crate::common::Position;
 use crate::common::RotationIndex;
 use crate::tetromino::tetromino_type::TetrominoType;
+use std::sync::Arc;
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TetrominoDefinition {
     tetromino_type: TetrominoType,
-    rotations: Vec<Vec<Vec<u8>>>,
+    rotations: Arc<Vec<Vec<Vec<u8>>>>, // Shared, so cloning a tetromino is cheap
 }
 
 impl TetrominoDefinition {
     pub fn new(tetromino_type: TetrominoType, rotations: Vec<Vec<Vec<u8>>>) -> Self {
         Self {
             tetromino_type,
-            rotations,
+            rotations: Arc::new(rotations),
         }
     }
 
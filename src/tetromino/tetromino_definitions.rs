@@ -2,6 +2,7 @@ use crate::tetromino::tetromino_definition::TetrominoDefinition;
 use crate::tetromino::tetromino_type::TetrominoType;
 use std::collections::HashMap;
 
+#[derive(Clone)]
 pub struct TetrominoDefinitions {
     definitions: HashMap<TetrominoType, TetrominoDefinition>,
 }
@@ -25,6 +26,12 @@ impl TetrominoDefinitions {
     }
 }
 
+impl Default for TetrominoDefinitions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
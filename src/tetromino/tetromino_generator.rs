@@ -2,7 +2,8 @@ use crate::common::Position;
 use crate::tetromino::TetrominoInstance;
 use crate::tetromino::TetrominoType;
 
-pub trait TetrominoGenerator {
+/// Deals the tetrominoes of a game. Generators are cloned to take snapshots of a game.
+pub trait TetrominoGenerator: Clone {
     fn generate(&mut self, position: Position) -> TetrominoInstance;
     fn peek_next_type(&self) -> TetrominoType;
 }
@@ -4,7 +4,7 @@ use crate::tetromino::tetromino_definition::TetrominoDefinition;
 use crate::tetromino::tetromino_definitions::TetrominoDefinitions;
 use crate::tetromino::tetromino_type::TetrominoType;
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TetrominoInstance {
     tetromino_type: TetrominoType,
     definition: TetrominoDefinition,
@@ -32,12 +32,10 @@ impl TetrominoInstance {
         self.tetromino_type
     }
 
-    #[cfg(test)]
     pub fn get_position(&self) -> Position {
         self.position
     }
 
-    #[cfg(test)]
     pub fn get_rotation_index(&self) -> RotationIndex {
         self.rotation_index
     }
@@ -54,6 +52,10 @@ impl TetrominoInstance {
         self.position = self.position.translate(0, 1);
     }
 
+    pub fn move_up(&mut self) {
+        self.position = self.position.translate(0, -1);
+    }
+
     pub fn move_left(&mut self) {
         self.position = self.position.translate(-1, 0);
     }
@@ -69,6 +71,10 @@ impl TetrominoInstance {
     pub fn rotate_counterclockwise(&mut self) {
         self.rotation_index.rotate_counterclockwise();
     }
+
+    pub fn rotate_180(&mut self) {
+        self.rotation_index.rotate_180();
+    }
 }
 
 #[cfg(test)]
@@ -126,6 +132,21 @@ mod tests {
         assert_eq!(sut.get_rotation_index(), RotationIndex::new(0, 4));
     }
 
+    #[test]
+    fn move_up_decreases_y_coordinate() {
+        // Arrange
+        let position = Position::new(5, 5);
+        let definitions = TetrominoDefinitions::new();
+        let mut sut = TetrominoInstance::new(TetrominoType::T, position, &definitions);
+
+        // Act
+        sut.move_up();
+
+        // Assert
+        assert_eq!(sut.get_position(), Position::new(5, 4));
+        assert_eq!(sut.get_rotation_index(), RotationIndex::new(0, 4));
+    }
+
     #[test]
     fn move_left_decreases_x_coordinate() {
         // Arrange
@@ -171,6 +192,21 @@ mod tests {
         assert_eq!(sut.get_position(), Position::new(5, 5));
     }
 
+    #[test]
+    fn rotate_180_advances_rotation_index_by_two() {
+        // Arrange
+        let position = Position::new(5, 5);
+        let definitions = TetrominoDefinitions::new();
+        let mut sut = TetrominoInstance::new(TetrominoType::T, position, &definitions);
+
+        // Act
+        sut.rotate_180();
+
+        // Assert
+        assert_eq!(sut.get_rotation_index(), RotationIndex::new(2, 4));
+        assert_eq!(sut.get_position(), Position::new(5, 5));
+    }
+
     #[test]
     fn rotate_counterclockwise_decreases_rotation_index() {
         // Arrange
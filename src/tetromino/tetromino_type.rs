@@ -8,3 +8,57 @@ pub enum TetrominoType {
     J,
     L,
 }
+
+impl TetrominoType {
+    /// The letter the tetromino is named after, used in text formats.
+    pub fn to_char(self) -> char {
+        match self {
+            TetrominoType::I => 'I',
+            TetrominoType::O => 'O',
+            TetrominoType::T => 'T',
+            TetrominoType::Z => 'Z',
+            TetrominoType::S => 'S',
+            TetrominoType::J => 'J',
+            TetrominoType::L => 'L',
+        }
+    }
+
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'I' => Some(TetrominoType::I),
+            'O' => Some(TetrominoType::O),
+            'T' => Some(TetrominoType::T),
+            'Z' => Some(TetrominoType::Z),
+            'S' => Some(TetrominoType::S),
+            'J' => Some(TetrominoType::J),
+            'L' => Some(TetrominoType::L),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn from_char_returns_type_of_to_char() {
+        for tetromino_type in TetrominoType::iter() {
+            // Act
+            let result = TetrominoType::from_char(tetromino_type.to_char());
+
+            // Assert
+            assert_eq!(result, Some(tetromino_type));
+        }
+    }
+
+    #[test]
+    fn from_char_returns_none_for_unknown_char() {
+        // Act
+        let result = TetrominoType::from_char('X');
+
+        // Assert
+        assert_eq!(result, None);
+    }
+}